@@ -1,13 +1,20 @@
 use core::fmt;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting", feature = "std"))]
 use std::io;
 
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
+
+#[cfg(all(feature = "formatting", feature = "std"))]
+use crate::formatting::output::IoOutput;
 #[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::formatting::output::{FmtOutput, SliceOutput, StrOutput};
+#[cfg(feature = "formatting")]
+use crate::formatting::{Display, Formattable};
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{Parsable, Parsed};
 use crate::util::{days_in_year, days_in_year_month, is_leap_year, weeks_in_year};
 use crate::{error, Duration, Month, PrimitiveDateTime, Time, Weekday};
 
@@ -661,12 +668,102 @@ impl Date {
     /// Format the `Date` using the provided format description. The formatted value will be output
     /// to the provided writer. The format description will typically be parsed by using
     /// [`format_description::parse`](crate::format_description::parse()).
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(all(feature = "formatting", feature = "std"))))]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
         format: &impl Formattable,
     ) -> Result<usize, error::Format> {
-        format.format_into(output, Some(self), None, None)
+        format.format_into(&mut IoOutput(output), Some(self), None, None)
+    }
+
+    /// Format the `Date` using the provided format description. The formatted value will be
+    /// output to the provided [`fmt::Write`](core::fmt::Write) implementor, returning the number
+    /// of bytes written. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::date};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let mut output = String::new();
+    /// date!(2020 - 01 - 02).format_into_fmt(&mut output, &format)?;
+    /// assert_eq!(output, "2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut FmtOutput(output), Some(self), None, None)
+    }
+
+    /// Format the `Date` using the provided format description. The formatted value will be
+    /// written to the start of the provided buffer, returning the number of bytes written. If the
+    /// buffer is not large enough to hold the formatted value,
+    /// [`error::Format::InsufficientBufferSize`](crate::error::Format::InsufficientBufferSize) is
+    /// returned. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::date};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let mut buf = [0; 32];
+    /// let len = date!(2020 - 01 - 02).format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], "2020-01-02".as_bytes());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut SliceOutput::new(output), Some(self), None, None)
+    }
+
+    /// Format the `Date` into the provided buffer using the provided format description,
+    /// returning the formatted value as a string slice. A buffer that is always large enough can be
+    /// sized at compile time using [`max_formatted_len`](crate::formatting::max_formatted_len).
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::date};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let mut buf = [0; 32];
+    /// assert_eq!(date!(2020 - 01 - 02).format_to_buf(&mut buf, &format)?, "2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_buf<'a>(
+        self,
+        buf: &'a mut [u8],
+        format: &impl Formattable,
+    ) -> Result<&'a str, error::Format> {
+        let mut output = StrOutput::new(buf);
+        format.format_into(&mut output, Some(self), None, None)?;
+        Ok(output.into_str())
+    }
+
+    /// Obtain a value that formats the `Date` using the provided format description when
+    /// displayed, such as via `write!` or `format_args!`. No allocation is performed. If the value
+    /// cannot be formatted, [`fmt::Error`] is returned when displaying it.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::date};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// assert_eq!(date!(2020 - 01 - 02).display(&format).to_string(), "2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    pub fn display<F: Formattable + ?Sized>(self, format: &F) -> Display<'_, F> {
+        Display::new(format, Some(self), None, None)
+    }
+
+    /// Obtain a value that formats the `Date` using the provided format description when
+    /// displayed. Unlike [`display`](Self::display), an error is returned immediately if the value
+    /// cannot be formatted.
+    pub fn try_display<F: Formattable + ?Sized>(
+        self,
+        format: &F,
+    ) -> Result<Display<'_, F>, error::Format> {
+        self.display(format).validate()
     }
 
     /// Format the `Date` using the provided format description. The format description will
@@ -679,6 +776,11 @@ impl Date {
     /// assert_eq!(date!(2020 - 01 - 02).format(&format)?, "2020-01-02");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(
+        __time_03_docs,
+        doc(cfg(all(feature = "formatting", feature = "alloc")))
+    )]
     pub fn format(self, format: &impl Formattable) -> Result<String, error::Format> {
         format.format(Some(self), None, None)
     }
@@ -700,6 +802,50 @@ impl Date {
     pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
         description.parse_date(input.as_bytes())
     }
+
+    /// Parse a `Date` from the start of the input using the provided format description,
+    /// returning it along with the remaining input. Unlike [`parse`](Self::parse), characters
+    /// following the parsed value are not an error.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::date, Date};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// assert_eq!(
+    ///     Date::parse_prefix("2020-01-02 rest", &format)?,
+    ///     (date!(2020 - 01 - 02), " rest")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &impl Parsable,
+    ) -> Result<(Self, &'a str), error::Parse> {
+        description.parse_prefix(input)
+    }
+
+    /// Parse a `Date` from the input using the provided format description, using the provided
+    /// defaults for any values that are not present in the input. See
+    /// [`Parsed::with_defaults`](crate::parsing::Parsed::with_defaults) for details on how the
+    /// defaults are applied.
+    ///
+    /// ```rust
+    /// # use core::num::NonZeroU8;
+    /// # use time::{format_description, macros::date, parsing::Parsed, Date};
+    /// let format = format_description::parse("[year]-[month]")?;
+    /// let defaults = Parsed::new().with_day(NonZeroU8::new(1).unwrap());
+    /// assert_eq!(
+    ///     Date::parse_with_defaults("2021-05", &format, defaults)?,
+    ///     date!(2021 - 05 - 01)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_defaults(
+        input: &str,
+        description: &impl Parsable,
+        defaults: Parsed,
+    ) -> Result<Self, error::Parse> {
+        description.parse_with_defaults(input.as_bytes(), defaults)
+    }
 }
 
 impl fmt::Display for Date {
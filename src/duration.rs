@@ -2,6 +2,8 @@ use core::cmp::Ordering;
 use core::convert::{TryFrom, TryInto};
 use core::fmt;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+#[cfg(feature = "parsing")]
+use core::str::FromStr;
 use core::time::Duration as StdDuration;
 
 use const_fn::const_fn;
@@ -9,6 +11,7 @@ use const_fn::const_fn;
 use standback::prelude::*;
 
 use crate::error;
+use crate::humanize::DurationFormat;
 #[cfg(feature = "std")]
 use crate::Instant;
 
@@ -730,6 +733,40 @@ impl Duration {
 }
 
 // region: trait impls
+impl fmt::Display for Duration {
+    /// Format the duration in a compact, human-friendly form, such as `1h30m` or `-250ms`. See
+    /// [`DurationFormat`] for details and other formats.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!((1.hours() + 30.minutes()).to_string(), "1h30m");
+    /// assert_eq!((-250).milliseconds().to_string(), "-250ms");
+    /// assert_eq!(0.seconds().to_string(), "0s");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DurationFormat::new().display(*self).fmt(f)
+    }
+}
+
+#[cfg(feature = "parsing")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+impl FromStr for Duration {
+    type Err = error::Parse;
+
+    /// Parse a duration such as `1h30m`, `250ms`, or `2 days 4 hours`, as produced by the
+    /// `Display` implementation. See [`DurationFormat::parse`] for the accepted syntax.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, Duration};
+    /// assert_eq!("1h30m".parse::<Duration>()?, 90.minutes());
+    /// assert_eq!("2d 4h".parse::<Duration>()?, 52.hours());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        DurationFormat::new().parse(input)
+    }
+}
+
 impl TryFrom<StdDuration> for Duration {
     type Error = error::ConversionRange;
 
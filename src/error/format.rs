@@ -1,6 +1,7 @@
 //! Error formatting a struct
 
 use core::fmt;
+#[cfg(feature = "std")]
 use std::io;
 
 /// An error occurred when formatting.
@@ -14,9 +15,17 @@ pub enum Format {
     InsufficientTypeInformation,
     /// The component named has a value that cannot be formatted into the requested format.
     ///
-    /// This variant is only returned when using well-known formats.
+    /// This variant is only returned when using well-known formats, when a time zone abbreviation
+    /// or name is longer than can be formatted, or when a century has more digits than its width.
     InvalidComponent(&'static str),
+    /// The buffer provided was not large enough to hold the formatted value.
+    #[non_exhaustive]
+    InsufficientBufferSize,
+    /// A value of `core::fmt::Error` was returned internally.
+    Fmt(fmt::Error),
     /// A value of `std::io::Error` was returned internally.
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
     StdIo(io::Error),
 }
 
@@ -32,11 +41,24 @@ impl fmt::Display for Format {
                 "The {} component cannot be formatted into the requested format.",
                 component
             ),
+            Self::InsufficientBufferSize => {
+                f.write_str("The buffer provided was not large enough to hold the formatted value.")
+            }
+            Self::Fmt(err) => err.fmt(f),
+            #[cfg(feature = "std")]
             Self::StdIo(err) => err.fmt(f),
         }
     }
 }
 
+impl From<fmt::Error> for Format {
+    fn from(err: fmt::Error) -> Self {
+        Self::Fmt(err)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
 impl From<io::Error> for Format {
     fn from(err: io::Error) -> Self {
         Self::StdIo(err)
@@ -48,7 +70,10 @@ impl From<io::Error> for Format {
 impl std::error::Error for Format {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match *self {
-            Self::InsufficientTypeInformation | Self::InvalidComponent(_) => None,
+            Self::InsufficientTypeInformation
+            | Self::InvalidComponent(_)
+            | Self::InsufficientBufferSize => None,
+            Self::Fmt(ref err) => Some(err),
             Self::StdIo(ref err) => Some(err),
         }
     }
@@ -29,11 +29,32 @@ pub enum InvalidFormatDescription {
         /// The zero-based index the modifier starts at.
         index: usize,
     },
+    /// There was a closing bracket without a corresponding opening bracket.
+    UnexpectedClosingBracket {
+        /// The zero-based index of the closing bracket.
+        index: usize,
+    },
+    /// Something was expected but not present.
+    Expected {
+        /// What was expected.
+        what: &'static str,
+        /// The zero-based index the item was expected at.
+        index: usize,
+    },
     /// A component name is missing.
     MissingComponentName {
         /// The zero-based index where the component name should start.
         index: usize,
     },
+    /// Something was present that is not supported in the given context.
+    NotSupported {
+        /// What is not supported.
+        what: &'static str,
+        /// The context in which it is not supported.
+        context: &'static str,
+        /// The zero-based index the unsupported item starts at.
+        index: usize,
+    },
 }
 
 #[cfg_attr(
@@ -61,9 +82,22 @@ impl fmt::Display for InvalidFormatDescription {
             InvalidModifier { value, index } => {
                 write!(f, "invalid modifier `{}` at byte index {}", value, index)
             }
+            UnexpectedClosingBracket { index } => {
+                write!(f, "unexpected closing bracket at byte index {}", index)
+            }
+            Expected { what, index } => write!(f, "expected {} at byte index {}", what, index),
             MissingComponentName { index } => {
                 write!(f, "missing component name at byte index {}", index)
             }
+            NotSupported {
+                what,
+                context,
+                index,
+            } => write!(
+                f,
+                "{} is not supported in {} at byte index {}",
+                what, context, index
+            ),
         }
     }
 }
@@ -0,0 +1,42 @@
+//! Invalid leap second table
+
+use core::fmt;
+
+/// The leap second table provided was not valid.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidLeapSecondTable {
+    /// An entry does not take effect strictly after the entry preceding it.
+    Unsorted {
+        /// The zero-based index of the entry.
+        index: usize,
+    },
+    /// A line of a `leap-seconds.list` file could not be parsed.
+    InvalidLine {
+        /// The one-based number of the line.
+        line: usize,
+    },
+}
+
+impl fmt::Display for InvalidLeapSecondTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsorted { index } => write!(
+                f,
+                "leap second entry at index {} does not follow the previous entry",
+                index
+            ),
+            Self::InvalidLine { line } => write!(f, "invalid leap second entry on line {}", line),
+        }
+    }
+}
+
+impl From<InvalidLeapSecondTable> for crate::Error {
+    fn from(original: InvalidLeapSecondTable) -> Self {
+        Self::InvalidLeapSecondTable(original)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+impl std::error::Error for InvalidLeapSecondTable {}
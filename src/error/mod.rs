@@ -6,6 +6,7 @@ mod format;
 mod indeterminate_offset;
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 mod invalid_format_description;
+mod invalid_leap_second_table;
 #[cfg(feature = "parsing")]
 mod parse;
 #[cfg(feature = "parsing")]
@@ -23,8 +24,9 @@ pub use format::Format;
 pub use indeterminate_offset::IndeterminateOffset;
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 pub use invalid_format_description::InvalidFormatDescription;
+pub use invalid_leap_second_table::InvalidLeapSecondTable;
 #[cfg(feature = "parsing")]
-pub use parse::Parse;
+pub use parse::{Diagnostic, Parse};
 #[cfg(feature = "parsing")]
 pub use parse_from_description::ParseFromDescription;
 #[cfg(feature = "parsing")]
@@ -47,24 +49,34 @@ pub enum Error {
     #[cfg(feature = "formatting")]
     #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
     Format(Format),
+    /// An item could not be parsed at an unknown position. Errors returned by parsing methods
+    /// include the position, so they are [`Error::Parse`] instead.
     #[cfg(feature = "parsing")]
     #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
     ParseFromDescription(ParseFromDescription),
+    /// This is no longer returned. Trailing characters are reported as [`Error::Parse`].
     #[cfg(feature = "parsing")]
     #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+    #[deprecated(since = "0.3.0", note = "trailing characters are reported as `Error::Parse`")]
     #[non_exhaustive]
     UnexpectedTrailingCharacters,
     #[cfg(feature = "parsing")]
     #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
     TryFromParsed(TryFromParsed),
+    /// An error occurred at a known position while parsing.
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+    Parse(Parse),
     #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
     #[cfg_attr(
         __time_03_docs,
         doc(cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc")))
     )]
     InvalidFormatDescription(InvalidFormatDescription),
+    InvalidLeapSecondTable(InvalidLeapSecondTable),
 }
 
+#[allow(deprecated)]
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -80,14 +92,18 @@ impl fmt::Display for Error {
             Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(e) => e.fmt(f),
+            #[cfg(feature = "parsing")]
+            Self::Parse(e) => e.fmt(f),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(e) => e.fmt(f),
+            Self::InvalidLeapSecondTable(e) => e.fmt(f),
         }
     }
 }
 
 #[cfg(feature = "std")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+#[allow(deprecated)]
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
@@ -103,8 +119,11 @@ impl std::error::Error for Error {
             Self::UnexpectedTrailingCharacters => None,
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(err) => Some(err),
+            #[cfg(feature = "parsing")]
+            Self::Parse(err) => err.source(),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(err) => Some(err),
+            Self::InvalidLeapSecondTable(err) => Some(err),
         }
     }
 }
@@ -15,16 +15,112 @@ pub enum Parse {
     TryFromParsed(TryFromParsed),
     #[allow(clippy::missing_docs_in_private_items)]
     ParseFromDescription(ParseFromDescription),
+    /// An item of the format description could not be parsed at a known position in the input.
+    Located {
+        /// The underlying error, indicating the component or literal that was expected.
+        error: ParseFromDescription,
+        /// The zero-based byte offset in the input at which the item starts.
+        position: usize,
+        /// The zero-based index of the format item that could not be parsed, if known. For nested
+        /// items, this is the index of the outermost item.
+        item_index: Option<usize>,
+    },
     /// The input should have ended, but there were characters remaining.
+    ///
+    /// This is no longer returned. Trailing characters are reported as [`Parse::Located`], with the
+    /// error being [`ParseFromDescription::UnexpectedTrailingCharacters`].
+    #[deprecated(since = "0.3.0", note = "trailing characters are reported as `Parse::Located`")]
     #[non_exhaustive]
     UnexpectedTrailingCharacters,
 }
 
+#[allow(deprecated)]
+impl Parse {
+    /// The zero-based byte offset in the input at which the error occurred, if known.
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            Self::Located { position, .. } => Some(*position),
+            Self::TryFromParsed(_)
+            | Self::ParseFromDescription(_)
+            | Self::UnexpectedTrailingCharacters => None,
+        }
+    }
+
+    /// The zero-based index of the format item that could not be parsed, if known.
+    pub const fn item_index(&self) -> Option<usize> {
+        match self {
+            Self::Located { item_index, .. } => *item_index,
+            _ => None,
+        }
+    }
+
+    /// The component or literal that was expected, if parsing failed while parsing a component or
+    /// literal.
+    pub const fn expected(&self) -> Option<ParseFromDescription> {
+        match self {
+            Self::ParseFromDescription(error) | Self::Located { error, .. } => Some(*error),
+            _ => None,
+        }
+    }
+
+    /// Obtain a value that, when displayed, renders the input with a caret pointing at the
+    /// position where the error occurred, followed by a description of the error. If the position
+    /// is not known, only the description is displayed.
+    ///
+    /// The provided input should be the same as the one that was parsed.
+    ///
+    /// ```rust
+    /// # use time::{format_description, Date};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let err = Date::parse("2021-13-01", &format).unwrap_err();
+    /// assert_eq!(
+    ///     err.diagnostic("2021-13-01").to_string(),
+    ///     "2021-13-01\n     ^ the 'month' component could not be parsed"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn diagnostic<'a>(&self, input: &'a str) -> Diagnostic<'a> {
+        Diagnostic {
+            input,
+            error: *self,
+        }
+    }
+
+    /// Attach the location of the format item being parsed to the error. The position is relative
+    /// to the input passed to the item, so it is offset by `offset` bytes.
+    pub(crate) const fn with_location(self, offset: usize, item_index: Option<usize>) -> Self {
+        match self {
+            Self::ParseFromDescription(error) => Self::Located {
+                error,
+                position: offset,
+                item_index,
+            },
+            Self::Located {
+                error,
+                position,
+                item_index: inner_index,
+            } => Self::Located {
+                error,
+                position: offset + position,
+                item_index: match item_index {
+                    Some(index) => Some(index),
+                    None => inner_index,
+                },
+            },
+            Self::TryFromParsed(_) | Self::UnexpectedTrailingCharacters => self,
+        }
+    }
+}
+
+#[allow(deprecated)]
 impl fmt::Display for Parse {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::TryFromParsed(err) => err.fmt(f),
             Self::ParseFromDescription(err) => err.fmt(f),
+            Self::Located {
+                error, position, ..
+            } => write!(f, "{} at byte index {}", error, position),
             Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
         }
     }
@@ -32,11 +128,12 @@ impl fmt::Display for Parse {
 
 #[cfg(feature = "std")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+#[allow(deprecated)]
 impl std::error::Error for Parse {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::TryFromParsed(err) => Some(err),
-            Self::ParseFromDescription(err) => Some(err),
+            Self::ParseFromDescription(err) | Self::Located { error: err, .. } => Some(err),
             Self::UnexpectedTrailingCharacters => None,
         }
     }
@@ -54,17 +151,21 @@ impl From<ParseFromDescription> for Parse {
     }
 }
 
+#[allow(deprecated)]
 impl From<Parse> for crate::Error {
     fn from(err: Parse) -> Self {
         match err {
             Parse::TryFromParsed(err) => Self::TryFromParsed(err),
             Parse::ParseFromDescription(err) => Self::ParseFromDescription(err),
             Parse::UnexpectedTrailingCharacters => Self::UnexpectedTrailingCharacters,
+            // Keep the location of the error.
+            Parse::Located { .. } => Self::Parse(err),
         }
     }
 }
 
 #[cfg(feature = "serde-human-readable")]
+#[allow(deprecated)]
 impl Parse {
     /// Obtain an error type for the deserializer.
     pub(crate) fn to_invalid_serde_value<'a, D: serde::Deserializer<'a>>(self) -> D::Error {
@@ -77,22 +178,68 @@ impl Parse {
             Self::TryFromParsed(TryFromParsed::InsufficientInformation) => unreachable!(
                 "The deserializing format contains all information needed to construct a `Time`."
             ),
+            Self::TryFromParsed(TryFromParsed::InconsistentInformation { .. }) => {
+                unreachable!("Deserialization does not perform strict consistency checks.")
+            }
             Self::TryFromParsed(TryFromParsed::ComponentRange(err)) => {
                 err.to_invalid_serde_value::<D>()
             }
             Self::ParseFromDescription(ParseFromDescription::InvalidLiteral) => {
                 D::Error::invalid_value(serde::de::Unexpected::Other("literal"), &"valid format")
             }
+            Self::ParseFromDescription(ParseFromDescription::UnexpectedTrailingCharacters)
+            | Self::UnexpectedTrailingCharacters => D::Error::invalid_value(
+                serde::de::Unexpected::Other("literal"),
+                &"no extraneous characters",
+            ),
             Self::ParseFromDescription(ParseFromDescription::InvalidComponent(component)) => {
                 D::Error::invalid_value(
                     serde::de::Unexpected::Other(component),
                     &&*format!("valid {}", component),
                 )
             }
-            Self::UnexpectedTrailingCharacters => D::Error::invalid_value(
-                serde::de::Unexpected::Other("literal"),
-                &"no extraneous characters",
-            ),
+            Self::Located { error, .. } => {
+                Self::ParseFromDescription(error).to_invalid_serde_value::<D>()
+            }
+        }
+    }
+}
+
+/// A parse error rendered alongside the input, with a caret pointing at the position where the
+/// error occurred.
+///
+/// This is obtained via [`Parse::diagnostic`].
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+#[derive(Debug, Clone, Copy)]
+pub struct Diagnostic<'a> {
+    /// The input that was parsed.
+    input: &'a str,
+    /// The error that occurred.
+    error: Parse,
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error {
+            Parse::Located {
+                error, position, ..
+            } => {
+                // The caret is placed under the character at the position, so the column is the
+                // number of characters preceding it.
+                let column = self
+                    .input
+                    .get(..position)
+                    .map_or(position, |prefix| prefix.chars().count());
+                write!(
+                    f,
+                    "{}\n{:column$}^ {}",
+                    self.input,
+                    "",
+                    error,
+                    column = column
+                )
+            }
+            _ => self.error.fmt(f),
         }
     }
 }
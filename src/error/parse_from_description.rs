@@ -12,6 +12,9 @@ pub enum ParseFromDescription {
     InvalidLiteral,
     /// A dynamic component was not valid.
     InvalidComponent(&'static str),
+    /// The input should have ended, but there were characters remaining.
+    #[non_exhaustive]
+    UnexpectedTrailingCharacters,
 }
 
 impl fmt::Display for ParseFromDescription {
@@ -21,6 +24,7 @@ impl fmt::Display for ParseFromDescription {
             Self::InvalidComponent(name) => {
                 write!(f, "the '{}' component could not be parsed", name)
             }
+            Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
         }
     }
 }
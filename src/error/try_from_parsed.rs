@@ -14,6 +14,12 @@ pub enum TryFromParsed {
     InsufficientInformation,
     /// Some component contained an invalid value for the type.
     ComponentRange(error::ComponentRange),
+    /// The [`Parsed`](crate::parsing::Parsed) contained a value that is inconsistent with the
+    /// others. This is only returned when strict checking is enabled.
+    InconsistentInformation {
+        /// The name of the component with the inconsistent value.
+        component: &'static str,
+    },
 }
 
 impl fmt::Display for TryFromParsed {
@@ -23,6 +29,11 @@ impl fmt::Display for TryFromParsed {
                 "the `Parsed` struct did not include enough information to construct the type",
             ),
             Self::ComponentRange(err) => err.fmt(f),
+            Self::InconsistentInformation { component } => write!(
+                f,
+                "the '{}' component is inconsistent with the other components",
+                component
+            ),
         }
     }
 }
@@ -38,7 +49,7 @@ impl From<error::ComponentRange> for TryFromParsed {
 impl std::error::Error for TryFromParsed {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Self::InsufficientInformation => None,
+            Self::InsufficientInformation | Self::InconsistentInformation { .. } => None,
             Self::ComponentRange(err) => Some(err),
         }
     }
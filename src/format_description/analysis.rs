@@ -0,0 +1,473 @@
+//! Analysis of the information a format description provides and requires.
+
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::format_description::{modifier, Component, FormatItem, OwnedFormatItem};
+
+/// A type that a format description can be used to format or parse.
+#[non_exhaustive]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetType {
+    /// [`Date`](crate::Date)
+    Date,
+    /// [`Time`](crate::Time)
+    Time,
+    /// [`UtcOffset`](crate::UtcOffset)
+    UtcOffset,
+    /// [`PrimitiveDateTime`](crate::PrimitiveDateTime)
+    PrimitiveDateTime,
+    /// [`OffsetDateTime`](crate::OffsetDateTime)
+    OffsetDateTime,
+    /// `ZonedDateTime`, which can be formatted but not parsed.
+    ZonedDateTime,
+}
+
+/// The values that are set when parsing a component. Each corresponds to a field of
+/// [`Parsed`](crate::parsing::Parsed).
+mod field {
+    /// The calendar year.
+    pub(super) const YEAR: u32 = 1 << 0;
+    /// The last two digits of the calendar year.
+    pub(super) const YEAR_LAST_TWO: u32 = 1 << 1;
+    /// The century of the calendar year.
+    pub(super) const CENTURY: u32 = 1 << 2;
+    /// The year within the era.
+    pub(super) const YEAR_OF_ERA: u32 = 1 << 3;
+    /// The era, which determines the sign of the year of the era.
+    pub(super) const ERA: u32 = 1 << 4;
+    /// The ISO week-based year.
+    pub(super) const ISO_YEAR: u32 = 1 << 5;
+    /// The last two digits of the ISO week-based year.
+    pub(super) const ISO_YEAR_LAST_TWO: u32 = 1 << 6;
+    /// The month of the year.
+    pub(super) const MONTH: u32 = 1 << 7;
+    /// The quarter of the year.
+    pub(super) const QUARTER: u32 = 1 << 8;
+    /// The week number, where week 1 begins on the first Sunday of the year.
+    pub(super) const SUNDAY_WEEK_NUMBER: u32 = 1 << 9;
+    /// The week number, where week 1 begins on the first Monday of the year.
+    pub(super) const MONDAY_WEEK_NUMBER: u32 = 1 << 10;
+    /// The ISO week number.
+    pub(super) const ISO_WEEK_NUMBER: u32 = 1 << 11;
+    /// The day of the week.
+    pub(super) const WEEKDAY: u32 = 1 << 12;
+    /// The day of the year.
+    pub(super) const ORDINAL: u32 = 1 << 13;
+    /// The day of the month.
+    pub(super) const DAY: u32 = 1 << 14;
+    /// The hour on a 24-hour clock.
+    pub(super) const HOUR_24: u32 = 1 << 15;
+    /// The hour on a 12-hour clock.
+    pub(super) const HOUR_12: u32 = 1 << 16;
+    /// Whether the hour is before or after noon.
+    pub(super) const PERIOD: u32 = 1 << 17;
+    /// The minute within the hour.
+    pub(super) const MINUTE: u32 = 1 << 18;
+    /// The second within the minute.
+    pub(super) const SECOND: u32 = 1 << 19;
+    /// The fractional part of the second.
+    pub(super) const SUBSECOND: u32 = 1 << 20;
+    /// The whole hours of the UTC offset.
+    pub(super) const OFFSET_HOUR: u32 = 1 << 21;
+    /// The minutes within the hour of the UTC offset.
+    pub(super) const OFFSET_MINUTE: u32 = 1 << 22;
+    /// The seconds within the minute of the UTC offset.
+    pub(super) const OFFSET_SECOND: u32 = 1 << 23;
+    /// The abbreviation of the time zone.
+    pub(super) const TIME_ZONE_ABBREVIATION: u32 = 1 << 24;
+    /// The name of the time zone.
+    pub(super) const TIME_ZONE_NAME: u32 = 1 << 25;
+
+    /// All values that are used to construct a `Date`.
+    pub(super) const DATE: u32 = YEAR
+        | YEAR_LAST_TWO
+        | CENTURY
+        | YEAR_OF_ERA
+        | ERA
+        | ISO_YEAR
+        | ISO_YEAR_LAST_TWO
+        | MONTH
+        | QUARTER
+        | SUNDAY_WEEK_NUMBER
+        | MONDAY_WEEK_NUMBER
+        | ISO_WEEK_NUMBER
+        | WEEKDAY
+        | ORDINAL
+        | DAY;
+    /// All values that are used to construct a `Time`.
+    pub(super) const TIME: u32 = HOUR_24 | HOUR_12 | PERIOD | MINUTE | SECOND | SUBSECOND;
+    /// All values that are used to construct a `UtcOffset`.
+    pub(super) const OFFSET: u32 =
+        OFFSET_HOUR | OFFSET_MINUTE | OFFSET_SECOND | TIME_ZONE_ABBREVIATION | TIME_ZONE_NAME;
+}
+
+/// The information that is needed to format a component.
+mod info {
+    /// A date is needed, such as for the year or month.
+    pub(super) const DATE: u8 = 1 << 0;
+    /// A time is needed, such as for the hour or minute.
+    pub(super) const TIME: u8 = 1 << 1;
+    /// A UTC offset is needed.
+    pub(super) const OFFSET: u8 = 1 << 2;
+    /// A time zone name or abbreviation is needed.
+    pub(super) const ZONE: u8 = 1 << 3;
+}
+
+/// The values that are set when parsing the component.
+const fn fields(component: Component) -> u32 {
+    match component {
+        Component::Day(_) => field::DAY,
+        Component::Month(_) => field::MONTH,
+        Component::Ordinal(_) => field::ORDINAL,
+        Component::Weekday(_) => field::WEEKDAY,
+        Component::WeekNumber(modifier::WeekNumber { repr, .. }) => match repr {
+            modifier::WeekNumberRepr::Iso => field::ISO_WEEK_NUMBER,
+            modifier::WeekNumberRepr::Sunday => field::SUNDAY_WEEK_NUMBER,
+            modifier::WeekNumberRepr::Monday => field::MONDAY_WEEK_NUMBER,
+        },
+        Component::Year(modifier::Year {
+            repr,
+            iso_week_based,
+            ..
+        }) => match (iso_week_based, repr) {
+            (false, modifier::YearRepr::Full) => field::YEAR,
+            (false, modifier::YearRepr::LastTwo) => field::YEAR_LAST_TWO,
+            (true, modifier::YearRepr::Full) => field::ISO_YEAR,
+            (true, modifier::YearRepr::LastTwo) => field::ISO_YEAR_LAST_TWO,
+            (_, modifier::YearRepr::OfEra) => field::YEAR_OF_ERA,
+        },
+        Component::Quarter(_) => field::QUARTER,
+        Component::Century(_) => field::CENTURY,
+        Component::Era(_) => field::ERA,
+        Component::Hour(modifier::Hour {
+            is_12_hour_clock, ..
+        }) => {
+            if is_12_hour_clock {
+                field::HOUR_12
+            } else {
+                field::HOUR_24
+            }
+        }
+        Component::Minute(_) => field::MINUTE,
+        Component::Period(_) => field::PERIOD,
+        Component::Second(_) => field::SECOND,
+        Component::Subsecond(_) => field::SUBSECOND,
+        Component::OffsetHour(_) => field::OFFSET_HOUR,
+        Component::OffsetMinute(_) => field::OFFSET_MINUTE,
+        Component::OffsetSecond(_) => field::OFFSET_SECOND,
+        Component::Offset(_) => field::OFFSET_HOUR | field::OFFSET_MINUTE | field::OFFSET_SECOND,
+        Component::TimeZone(modifier::TimeZone { repr }) => match repr {
+            modifier::TimeZoneRepr::Abbreviation => field::TIME_ZONE_ABBREVIATION,
+            modifier::TimeZoneRepr::Name => field::TIME_ZONE_NAME,
+        },
+        Component::Whitespace(_) | Component::Ignore(_) | Component::End(_) => 0,
+    }
+}
+
+/// The information needed to format the component.
+const fn required_info(component: Component) -> u8 {
+    match component {
+        Component::Day(_)
+        | Component::Month(_)
+        | Component::Ordinal(_)
+        | Component::Weekday(_)
+        | Component::WeekNumber(_)
+        | Component::Year(_)
+        | Component::Quarter(_)
+        | Component::Century(_)
+        | Component::Era(_) => info::DATE,
+        Component::Hour(_)
+        | Component::Minute(_)
+        | Component::Period(_)
+        | Component::Second(_)
+        | Component::Subsecond(_) => info::TIME,
+        Component::OffsetHour(_)
+        | Component::OffsetMinute(_)
+        | Component::OffsetSecond(_)
+        | Component::Offset(_) => info::OFFSET,
+        Component::TimeZone(_) => info::ZONE,
+        Component::Whitespace(_) | Component::Ignore(_) | Component::End(_) => 0,
+    }
+}
+
+/// The values used to construct a `Date`, if the provided values are sufficient. This mirrors the
+/// conversion from `Parsed`.
+const fn date_fields(values: u32) -> Option<u32> {
+    /// Whether all of the values are present.
+    const fn has(values: u32, expected: u32) -> bool {
+        values & expected == expected
+    }
+
+    let year = if has(values, field::YEAR) {
+        field::YEAR
+    } else if has(values, field::YEAR_OF_ERA | field::ERA) {
+        field::YEAR_OF_ERA | field::ERA
+    } else if has(values, field::CENTURY | field::YEAR_LAST_TWO) {
+        field::CENTURY | field::YEAR_LAST_TWO
+    } else {
+        0
+    };
+
+    if year != 0 && has(values, field::ORDINAL) {
+        Some(year | field::ORDINAL)
+    } else if year != 0 && has(values, field::MONTH | field::DAY) {
+        Some(year | field::MONTH | field::DAY)
+    } else if has(
+        values,
+        field::ISO_YEAR | field::ISO_WEEK_NUMBER | field::WEEKDAY,
+    ) {
+        Some(field::ISO_YEAR | field::ISO_WEEK_NUMBER | field::WEEKDAY)
+    } else if year != 0 && has(values, field::SUNDAY_WEEK_NUMBER | field::WEEKDAY) {
+        Some(year | field::SUNDAY_WEEK_NUMBER | field::WEEKDAY)
+    } else if year != 0 && has(values, field::MONDAY_WEEK_NUMBER | field::WEEKDAY) {
+        Some(year | field::MONDAY_WEEK_NUMBER | field::WEEKDAY)
+    } else {
+        None
+    }
+}
+
+/// The values used to construct a `Time`, if the provided values are sufficient.
+const fn time_fields(values: u32) -> Option<u32> {
+    let hour = if values & field::HOUR_24 != 0 {
+        field::HOUR_24
+    } else if values & (field::HOUR_12 | field::PERIOD) == field::HOUR_12 | field::PERIOD {
+        field::HOUR_12 | field::PERIOD
+    } else {
+        return None;
+    };
+    if values & field::MINUTE == 0 {
+        return None;
+    }
+    Some(hour | field::MINUTE | field::SECOND | field::SUBSECOND)
+}
+
+/// The values used to construct a `UtcOffset`, if the provided values are sufficient. A time zone
+/// alone is not sufficient, as only some of them correspond to a known offset.
+const fn offset_fields(values: u32) -> Option<u32> {
+    if values & field::OFFSET_HOUR == 0 {
+        return None;
+    }
+    Some(field::OFFSET_HOUR | field::OFFSET_MINUTE | field::OFFSET_SECOND)
+}
+
+/// What a format description provides when parsing and requires when formatting.
+///
+/// ```rust
+/// # use time::format_description::{self, TargetType};
+/// let format = format_description::parse("[year]-[month]-[day] [minute]")?;
+/// let analysis = format_description::analyze(&format);
+/// assert!(analysis.can_parse(TargetType::Date));
+/// assert!(!analysis.can_parse(TargetType::PrimitiveDateTime));
+/// assert!(analysis.can_format(TargetType::PrimitiveDateTime));
+/// assert!(!analysis.can_format(TargetType::Date));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    /// The values that are set by each distinct way the input can be parsed.
+    paths: Vec<u32>,
+    /// The information needed to format a value.
+    required: u8,
+    /// All components, in the order they appear.
+    components: Vec<Component>,
+    /// Components setting a value that may already have been set by an earlier component.
+    conflicting: Vec<Component>,
+}
+
+impl Analysis {
+    /// An analysis of a format description without any items.
+    fn new() -> Self {
+        Self {
+            paths: alloc::vec![0],
+            required: 0,
+            components: Vec::new(),
+            conflicting: Vec::new(),
+        }
+    }
+
+    /// Record a component that is present.
+    fn component(&mut self, component: Component) {
+        let fields = fields(component);
+        if self.paths.iter().any(|&path| path & fields != 0) {
+            self.conflicting.push(component);
+        }
+        for path in &mut self.paths {
+            *path |= fields;
+        }
+        self.paths.sort_unstable();
+        self.paths.dedup();
+        self.required |= required_info(component);
+        self.components.push(component);
+    }
+
+    /// Record an item that may not be present when parsing.
+    fn optional(&mut self, analyze: impl FnOnce(&mut Self)) {
+        let paths = self.paths.clone();
+        analyze(self);
+        self.paths.extend(paths);
+        self.paths.sort_unstable();
+        self.paths.dedup();
+    }
+
+    /// Record a number of alternatives. Only the first is used when formatting, while any of them
+    /// may be used when parsing.
+    fn first<T>(&mut self, items: &[T], analyze: impl Fn(&T, &mut Self)) {
+        if items.is_empty() {
+            return;
+        }
+
+        let paths = mem::take(&mut self.paths);
+        let mut all_paths = Vec::new();
+        let mut required = self.required;
+
+        for (index, item) in items.iter().enumerate() {
+            self.paths.clone_from(&paths);
+            analyze(item, self);
+            all_paths.append(&mut self.paths);
+            if index == 0 {
+                required = self.required;
+            }
+        }
+
+        all_paths.sort_unstable();
+        all_paths.dedup();
+        self.paths = all_paths;
+        self.required = required;
+    }
+
+    /// Whether a value of the provided type can be formatted using the format description.
+    pub const fn can_format(&self, target: TargetType) -> bool {
+        let available = match target {
+            TargetType::Date => info::DATE,
+            TargetType::Time => info::TIME,
+            TargetType::UtcOffset => info::OFFSET,
+            TargetType::PrimitiveDateTime => info::DATE | info::TIME,
+            TargetType::OffsetDateTime => info::DATE | info::TIME | info::OFFSET,
+            TargetType::ZonedDateTime => info::DATE | info::TIME | info::OFFSET | info::ZONE,
+        };
+        self.required & !available == 0
+    }
+
+    /// Whether a value of the provided type can be parsed using the format description. This is
+    /// the case when every input that matches the format description contains enough information
+    /// to construct the value.
+    ///
+    /// Years with only their last two digits present are not sufficient, as they are only resolved
+    /// to a full year when configured on [`Parsed`](crate::parsing::Parsed).
+    pub fn can_parse(&self, target: TargetType) -> bool {
+        self.paths.iter().all(|&values| match target {
+            TargetType::Date => date_fields(values).is_some(),
+            TargetType::Time => time_fields(values).is_some(),
+            TargetType::UtcOffset => offset_fields(values).is_some(),
+            TargetType::PrimitiveDateTime => {
+                date_fields(values).is_some() && time_fields(values).is_some()
+            }
+            TargetType::OffsetDateTime => {
+                date_fields(values).is_some()
+                    && time_fields(values).is_some()
+                    && offset_fields(values).is_some()
+            }
+            TargetType::ZonedDateTime => false,
+        })
+    }
+
+    /// Components that set a value that may already have been set by an earlier component, in the
+    /// order they appear. When parsing, the earlier value is replaced without any indication.
+    pub fn conflicting(&self) -> &[Component] {
+        &self.conflicting
+    }
+
+    /// Components that are not used to construct a value of the provided type when parsing, in the
+    /// order they appear. Such components are either ignored or only checked for consistency
+    /// with the remainder of the value.
+    ///
+    /// If the format description does not contain enough information to parse part of the type,
+    /// components for that part are not considered redundant.
+    pub fn redundant(&self, target: TargetType) -> Vec<Component> {
+        let used = self.paths.iter().fold(0, |used, &values| {
+            let date = date_fields(values).unwrap_or(field::DATE);
+            let time = time_fields(values).unwrap_or(field::TIME);
+            let offset = offset_fields(values).unwrap_or(field::OFFSET);
+            used | match target {
+                TargetType::Date => date,
+                TargetType::Time => time,
+                TargetType::UtcOffset => offset,
+                TargetType::PrimitiveDateTime => date | time,
+                TargetType::OffsetDateTime | TargetType::ZonedDateTime => date | time | offset,
+            }
+        });
+
+        self.components
+            .iter()
+            .copied()
+            .filter(|&component| {
+                let fields = fields(component);
+                fields != 0 && fields & used == 0
+            })
+            .collect()
+    }
+}
+
+/// Analyze the provided items, determining what types they can be used to format and parse.
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn analyze(items: &[FormatItem<'_>]) -> Analysis {
+    let mut analysis = Analysis::new();
+    for item in items {
+        analyze_item(item, &mut analysis);
+    }
+    analysis
+}
+
+/// Record a single borrowed item.
+fn analyze_item(item: &FormatItem<'_>, analysis: &mut Analysis) {
+    match *item {
+        FormatItem::Literal(_) | FormatItem::CaseInsensitiveLiteral(_) => {}
+        FormatItem::Component(component) => analysis.component(component),
+        FormatItem::Compound(items) => {
+            for item in items {
+                analyze_item(item, analysis);
+            }
+        }
+        FormatItem::Optional(item) => analysis.optional(|analysis| analyze_item(item, analysis)),
+        FormatItem::First(items) => analysis.first(items, analyze_item),
+    }
+}
+
+/// Record a single owned item.
+fn analyze_owned_item(item: &OwnedFormatItem, analysis: &mut Analysis) {
+    match item {
+        OwnedFormatItem::Literal(_) | OwnedFormatItem::CaseInsensitiveLiteral(_) => {}
+        OwnedFormatItem::Component(component) => analysis.component(*component),
+        OwnedFormatItem::Compound(items) => {
+            for item in &**items {
+                analyze_owned_item(item, analysis);
+            }
+        }
+        OwnedFormatItem::Optional(item) => {
+            analysis.optional(|analysis| analyze_owned_item(item, analysis));
+        }
+        OwnedFormatItem::First(items) => analysis.first(items, analyze_owned_item),
+    }
+}
+
+impl FormatItem<'_> {
+    /// Analyze the item, determining what types it can be used to format and parse.
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn analyze(&self) -> Analysis {
+        let mut analysis = Analysis::new();
+        analyze_item(self, &mut analysis);
+        analysis
+    }
+}
+
+impl OwnedFormatItem {
+    /// Analyze the item, determining what types it can be used to format and parse.
+    pub fn analyze(&self) -> Analysis {
+        let mut analysis = Analysis::new();
+        analyze_owned_item(self, &mut analysis);
+        analysis
+    }
+}
@@ -2,12 +2,20 @@
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
+use core::fmt;
 
-use crate::format_description::modifier;
+use crate::format_description::modifier::{
+    self, EraRepr, MonthRepr, Padding, Presence, QuarterRepr, SubsecondDigits, SubsecondRounding,
+    TimeZoneRepr, WeekNumberRepr, WeekdayRepr, YearRepr,
+};
 #[cfg(feature = "alloc")]
 use crate::{error::InvalidFormatDescription, format_description::modifier::Modifiers};
 
 /// A component of a larger format description.
+///
+/// The [`Display`](fmt::Display) implementation writes the component as it would appear in a
+/// format description, such as `[hour repr:12]`. Only modifiers that differ from their default
+/// are present.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Component {
@@ -23,6 +31,12 @@ pub enum Component {
     WeekNumber(modifier::WeekNumber),
     /// Year of the date.
     Year(modifier::Year),
+    /// Quarter of the year.
+    Quarter(modifier::Quarter),
+    /// Century of the year.
+    Century(modifier::Century),
+    /// Era of the year (BC or AD).
+    Era(modifier::Era),
     /// Hour of the day.
     Hour(modifier::Hour),
     /// Minute within the hour.
@@ -39,6 +53,260 @@ pub enum Component {
     OffsetMinute(modifier::OffsetMinute),
     /// Second within the minute of the UTC offset.
     OffsetSecond(modifier::OffsetSecond),
+    /// The entire UTC offset, optionally represented as `Z` for UTC.
+    Offset(modifier::Offset),
+    /// Time zone of the value, either its abbreviation or IANA name.
+    TimeZone(modifier::TimeZone),
+    /// One or more whitespace characters.
+    Whitespace(modifier::Whitespace),
+    /// Bytes that are skipped when parsing.
+    Ignore(modifier::Ignore),
+    /// The end of the input.
+    End(modifier::End),
+}
+
+impl Component {
+    /// The name of the component in a format description.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Day(_) => "day",
+            Self::Month(_) => "month",
+            Self::Ordinal(_) => "ordinal",
+            Self::Weekday(_) => "weekday",
+            Self::WeekNumber(_) => "week_number",
+            Self::Year(_) => "year",
+            Self::Quarter(_) => "quarter",
+            Self::Century(_) => "century",
+            Self::Era(_) => "era",
+            Self::Hour(_) => "hour",
+            Self::Minute(_) => "minute",
+            Self::Period(_) => "period",
+            Self::Second(_) => "second",
+            Self::Subsecond(_) => "subsecond",
+            Self::OffsetHour(_) => "offset_hour",
+            Self::OffsetMinute(_) => "offset_minute",
+            Self::OffsetSecond(_) => "offset_second",
+            Self::Offset(_) => "offset",
+            Self::TimeZone(_) => "time_zone",
+            Self::Whitespace(_) => "whitespace",
+            Self::Ignore(_) => "ignore",
+            Self::End(_) => "end",
+        }
+    }
+}
+
+/// Write the padding modifier if it is not the default.
+fn write_padding(f: &mut fmt::Formatter<'_>, padding: Padding) -> fmt::Result {
+    match padding {
+        Padding::Zero => Ok(()),
+        Padding::Space => f.write_str(" padding:space"),
+        Padding::None => f.write_str(" padding:none"),
+    }
+}
+
+/// Write the modifier if the condition holds.
+fn write_if(f: &mut fmt::Formatter<'_>, condition: bool, modifier: &str) -> fmt::Result {
+    if condition {
+        f.write_str(modifier)
+    } else {
+        Ok(())
+    }
+}
+
+/// Write the locale modifier if it is not the default.
+fn write_locale(f: &mut fmt::Formatter<'_>, locale: modifier::Locale) -> fmt::Result {
+    if locale == modifier::Locale::En {
+        Ok(())
+    } else {
+        write!(f, " locale:{}", locale.code())
+    }
+}
+
+/// Write the width modifier if present.
+fn write_width(f: &mut fmt::Formatter<'_>, width: Option<u8>) -> fmt::Result {
+    width.map_or(Ok(()), |width| write!(f, " width:{}", width))
+}
+
+impl fmt::Display for Component {
+    #[allow(clippy::too_many_lines)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}", self.name())?;
+
+        match *self {
+            Self::Day(modifier::Day {
+                padding,
+                ordinal_suffix,
+            }) => {
+                write_padding(f, padding)?;
+                write_if(f, ordinal_suffix, " ordinal_suffix:true")?;
+            }
+            Self::Month(modifier::Month {
+                padding,
+                repr,
+                case_sensitive,
+                locale,
+                standalone,
+            }) => {
+                write_padding(f, padding)?;
+                f.write_str(match repr {
+                    MonthRepr::Numerical => "",
+                    MonthRepr::Long => " repr:long",
+                    MonthRepr::Short => " repr:short",
+                })?;
+                write_if(f, !case_sensitive, " case_sensitive:false")?;
+                write_locale(f, locale)?;
+                write_if(f, standalone, " standalone:true")?;
+            }
+            Self::Ordinal(modifier::Ordinal { padding })
+            | Self::Minute(modifier::Minute { padding })
+            | Self::Second(modifier::Second { padding })
+            | Self::OffsetMinute(modifier::OffsetMinute { padding })
+            | Self::OffsetSecond(modifier::OffsetSecond { padding }) => write_padding(f, padding)?,
+            Self::Weekday(modifier::Weekday {
+                repr,
+                one_indexed,
+                case_sensitive,
+                locale,
+            }) => {
+                f.write_str(match repr {
+                    WeekdayRepr::Long => "",
+                    WeekdayRepr::Short => " repr:short",
+                    WeekdayRepr::Sunday => " repr:sunday",
+                    WeekdayRepr::Monday => " repr:monday",
+                })?;
+                write_if(f, !one_indexed, " one_indexed:false")?;
+                write_if(f, !case_sensitive, " case_sensitive:false")?;
+                write_locale(f, locale)?;
+            }
+            Self::WeekNumber(modifier::WeekNumber { padding, repr }) => {
+                write_padding(f, padding)?;
+                f.write_str(match repr {
+                    WeekNumberRepr::Iso => "",
+                    WeekNumberRepr::Sunday => " repr:sunday",
+                    WeekNumberRepr::Monday => " repr:monday",
+                })?;
+            }
+            Self::Year(modifier::Year {
+                padding,
+                repr,
+                iso_week_based,
+                sign_is_mandatory,
+                width,
+            }) => {
+                write_padding(f, padding)?;
+                f.write_str(match repr {
+                    YearRepr::Full => "",
+                    YearRepr::LastTwo => " repr:last_two",
+                    YearRepr::OfEra => " repr:of_era",
+                })?;
+                write_if(f, iso_week_based, " base:iso_week")?;
+                write_if(f, sign_is_mandatory, " sign:mandatory")?;
+                write_width(f, width)?;
+            }
+            Self::Quarter(modifier::Quarter { repr }) => {
+                write_if(f, repr == QuarterRepr::Short, " repr:short")?;
+            }
+            Self::Century(modifier::Century {
+                padding,
+                sign_is_mandatory,
+                width,
+            }) => {
+                write_padding(f, padding)?;
+                write_if(f, sign_is_mandatory, " sign:mandatory")?;
+                write_width(f, width)?;
+            }
+            Self::Era(modifier::Era {
+                repr,
+                case_sensitive,
+            }) => {
+                write_if(f, repr == EraRepr::Ce, " repr:ce")?;
+                write_if(f, !case_sensitive, " case_sensitive:false")?;
+            }
+            Self::Hour(modifier::Hour {
+                padding,
+                is_12_hour_clock,
+            }) => {
+                write_padding(f, padding)?;
+                write_if(f, is_12_hour_clock, " repr:12")?;
+            }
+            Self::Period(modifier::Period {
+                is_uppercase,
+                case_sensitive,
+                locale,
+            }) => {
+                write_if(f, !is_uppercase, " case:lower")?;
+                write_if(f, !case_sensitive, " case_sensitive:false")?;
+                write_locale(f, locale)?;
+            }
+            Self::Subsecond(modifier::Subsecond {
+                digits,
+                trim_trailing_zeros,
+                rounding,
+            }) => {
+                f.write_str(match digits {
+                    SubsecondDigits::One => " digits:1",
+                    SubsecondDigits::Two => " digits:2",
+                    SubsecondDigits::Three => " digits:3",
+                    SubsecondDigits::Four => " digits:4",
+                    SubsecondDigits::Five => " digits:5",
+                    SubsecondDigits::Six => " digits:6",
+                    SubsecondDigits::Seven => " digits:7",
+                    SubsecondDigits::Eight => " digits:8",
+                    SubsecondDigits::Nine => " digits:9",
+                    SubsecondDigits::OneOrMore => "",
+                })?;
+                write_if(f, trim_trailing_zeros, " trim_trailing_zeros:true")?;
+                write_if(
+                    f,
+                    rounding == SubsecondRounding::NearestSaturating,
+                    " rounding:nearest_saturating",
+                )?;
+            }
+            // Unlike the other components, the sign is mandatory by default for the offset hour
+            // when constructed directly, but not when parsed from a format description.
+            Self::OffsetHour(modifier::OffsetHour {
+                sign_is_mandatory,
+                padding,
+                z_for_utc,
+                width,
+            }) => {
+                write_if(f, sign_is_mandatory, " sign:mandatory")?;
+                write_padding(f, padding)?;
+                write_if(f, z_for_utc, " z_for_utc:true")?;
+                write_width(f, width)?;
+            }
+            Self::Offset(modifier::Offset {
+                z_for_utc,
+                colon_separated,
+                minute,
+                second,
+            }) => {
+                write_if(f, !z_for_utc, " z_for_utc:false")?;
+                write_if(f, !colon_separated, " colon:false")?;
+                f.write_str(match minute {
+                    Presence::Mandatory => "",
+                    Presence::Optional => " minute:optional",
+                    Presence::Omitted => " minute:omitted",
+                })?;
+                f.write_str(match second {
+                    Presence::Mandatory => " second:mandatory",
+                    Presence::Optional => "",
+                    Presence::Omitted => " second:omitted",
+                })?;
+            }
+            Self::TimeZone(modifier::TimeZone { repr }) => {
+                write_if(f, repr == TimeZoneRepr::Name, " repr:name")?;
+            }
+            Self::Ignore(modifier::Ignore { count }) => {
+                if let Some(count) = count {
+                    write!(f, " count:{}", count)?;
+                }
+            }
+            Self::Whitespace(_) | Self::End(_) => {}
+        }
+
+        f.write_str("]")
+    }
 }
 
 /// A component with no modifiers present.
@@ -57,6 +325,12 @@ pub(crate) enum NakedComponent {
     WeekNumber,
     /// Year of the date.
     Year,
+    /// Quarter of the year.
+    Quarter,
+    /// Century of the year.
+    Century,
+    /// Era of the year (BC or AD).
+    Era,
     /// Hour of the day.
     Hour,
     /// Minute within the hour.
@@ -73,6 +347,16 @@ pub(crate) enum NakedComponent {
     OffsetMinute,
     /// Second within the minute of the UTC offset.
     OffsetSecond,
+    /// The entire UTC offset, optionally represented as `Z` for UTC.
+    Offset,
+    /// Time zone of the value, either its abbreviation or IANA name.
+    TimeZone,
+    /// One or more whitespace characters.
+    Whitespace,
+    /// Bytes that are skipped when parsing.
+    Ignore,
+    /// The end of the input.
+    End,
 }
 
 #[cfg(feature = "alloc")]
@@ -90,6 +374,9 @@ impl NakedComponent {
             b"weekday" => Ok(Self::Weekday),
             b"week_number" => Ok(Self::WeekNumber),
             b"year" => Ok(Self::Year),
+            b"quarter" => Ok(Self::Quarter),
+            b"century" => Ok(Self::Century),
+            b"era" => Ok(Self::Era),
             b"hour" => Ok(Self::Hour),
             b"minute" => Ok(Self::Minute),
             b"period" => Ok(Self::Period),
@@ -98,6 +385,11 @@ impl NakedComponent {
             b"offset_hour" => Ok(Self::OffsetHour),
             b"offset_minute" => Ok(Self::OffsetMinute),
             b"offset_second" => Ok(Self::OffsetSecond),
+            b"offset" => Ok(Self::Offset),
+            b"time_zone" => Ok(Self::TimeZone),
+            b"whitespace" => Ok(Self::Whitespace),
+            b"ignore" => Ok(Self::Ignore),
+            b"end" => Ok(Self::End),
             b"" => Err(InvalidFormatDescription::MissingComponentName {
                 index: component_index,
             }),
@@ -113,11 +405,14 @@ impl NakedComponent {
         match self {
             Self::Day => Component::Day(modifier::Day {
                 padding: modifiers.padding.unwrap_or_default(),
+                ordinal_suffix: modifiers.day_has_ordinal_suffix.unwrap_or_default(),
             }),
             Self::Month => Component::Month(modifier::Month {
                 padding: modifiers.padding.unwrap_or_default(),
                 repr: modifiers.month_repr.unwrap_or_default(),
                 case_sensitive: modifiers.case_sensitive.unwrap_or(true),
+                locale: modifiers.locale.unwrap_or(modifier::Locale::En),
+                standalone: modifiers.month_is_standalone.unwrap_or_default(),
             }),
             Self::Ordinal => Component::Ordinal(modifier::Ordinal {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -126,6 +421,7 @@ impl NakedComponent {
                 repr: modifiers.weekday_repr.unwrap_or_default(),
                 one_indexed: modifiers.weekday_is_one_indexed.unwrap_or(true),
                 case_sensitive: modifiers.case_sensitive.unwrap_or(true),
+                locale: modifiers.locale.unwrap_or(modifier::Locale::En),
             }),
             Self::WeekNumber => Component::WeekNumber(modifier::WeekNumber {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -136,6 +432,19 @@ impl NakedComponent {
                 repr: modifiers.year_repr.unwrap_or_default(),
                 iso_week_based: modifiers.year_is_iso_week_based.unwrap_or_default(),
                 sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+                width: modifiers.width,
+            }),
+            Self::Quarter => Component::Quarter(modifier::Quarter {
+                repr: modifiers.quarter_repr.unwrap_or_default(),
+            }),
+            Self::Century => Component::Century(modifier::Century {
+                padding: modifiers.padding.unwrap_or_default(),
+                sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+                width: modifiers.width,
+            }),
+            Self::Era => Component::Era(modifier::Era {
+                repr: modifiers.era_repr.unwrap_or_default(),
+                case_sensitive: modifiers.case_sensitive.unwrap_or(true),
             }),
             Self::Hour => Component::Hour(modifier::Hour {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -147,16 +456,21 @@ impl NakedComponent {
             Self::Period => Component::Period(modifier::Period {
                 is_uppercase: modifiers.period_is_uppercase.unwrap_or(true),
                 case_sensitive: modifiers.case_sensitive.unwrap_or(true),
+                locale: modifiers.locale.unwrap_or(modifier::Locale::En),
             }),
             Self::Second => Component::Second(modifier::Second {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
             Self::Subsecond => Component::Subsecond(modifier::Subsecond {
                 digits: modifiers.subsecond_digits.unwrap_or_default(),
+                trim_trailing_zeros: modifiers.subsecond_trim_trailing_zeros.unwrap_or_default(),
+                rounding: modifiers.subsecond_rounding.unwrap_or_default(),
             }),
             Self::OffsetHour => Component::OffsetHour(modifier::OffsetHour {
                 sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
                 padding: modifiers.padding.unwrap_or_default(),
+                z_for_utc: modifiers.z_for_utc.unwrap_or_default(),
+                width: modifiers.width,
             }),
             Self::OffsetMinute => Component::OffsetMinute(modifier::OffsetMinute {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -164,6 +478,20 @@ impl NakedComponent {
             Self::OffsetSecond => Component::OffsetSecond(modifier::OffsetSecond {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
+            Self::Offset => Component::Offset(modifier::Offset {
+                z_for_utc: modifiers.z_for_utc.unwrap_or(true),
+                colon_separated: modifiers.offset_colon_separated.unwrap_or(true),
+                minute: modifiers.offset_minute.unwrap_or_default(),
+                second: modifiers.offset_second.unwrap_or(Presence::Optional),
+            }),
+            Self::TimeZone => Component::TimeZone(modifier::TimeZone {
+                repr: modifiers.time_zone_repr.unwrap_or_default(),
+            }),
+            Self::Whitespace => Component::Whitespace(modifier::Whitespace),
+            Self::Ignore => Component::Ignore(modifier::Ignore {
+                count: modifiers.ignore_count,
+            }),
+            Self::End => Component::End(modifier::End),
         }
     }
 }
@@ -0,0 +1,667 @@
+//! A format description with a fixed capacity, which can be parsed in a const context.
+
+use core::fmt;
+use core::ops::Deref;
+
+use const_fn::const_fn;
+
+use crate::format_description::modifier::{
+    self, EraRepr, Locale, MonthRepr, Padding, Presence, QuarterRepr, SubsecondDigits,
+    SubsecondRounding, TimeZoneRepr, WeekNumberRepr, WeekdayRepr, YearRepr,
+};
+use crate::format_description::{Component, FormatItem};
+
+/// The maximum number of items in a [`FixedFormatDescription`].
+const CAPACITY: usize = 64;
+
+/// A format description of at most [`CAPACITY`](Self::CAPACITY) items, as returned by
+/// [`parse_fixed`].
+///
+/// The items are accessed by dereferencing to a slice. This also allows the description to be
+/// used anywhere a `[FormatItem]` can be.
+#[derive(Clone, Copy)]
+pub struct FixedFormatDescription<'a> {
+    /// The items, of which only the first `len` are present.
+    items: [FormatItem<'a>; CAPACITY],
+    /// The number of items present.
+    len: usize,
+}
+
+impl FixedFormatDescription<'_> {
+    /// The maximum number of items in the format description.
+    pub const CAPACITY: usize = CAPACITY;
+}
+
+impl<'a> Deref for FixedFormatDescription<'a> {
+    type Target = [FormatItem<'a>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items[..self.len]
+    }
+}
+
+impl PartialEq for FixedFormatDescription<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for FixedFormatDescription<'_> {}
+
+impl fmt::Debug for FixedFormatDescription<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl fmt::Display for FixedFormatDescription<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        FormatItem::Compound(self).fmt(f)
+    }
+}
+
+/// Parse a sequence of items from the format description in a const context.
+///
+/// This uses [version 1](crate::format_description::Version::V1) of the syntax, as does
+/// [`format_description!`](crate::macros::format_description) by default. Unlike the macro, the
+/// format description need not be a string literal, so it can be shared as a `const`. When the
+/// result is assigned to a `const` or `static`, an invalid format description is a compile error.
+///
+/// ```rust
+/// # use time::format_description::{parse_fixed, FixedFormatDescription};
+/// # use time::{Date, Month};
+/// const DATE: &str = "[year]-[month]-[day]";
+/// const FORMAT: FixedFormatDescription<'_> = parse_fixed(DATE);
+/// assert_eq!(
+///     Date::from_calendar_date(2021, Month::January, 2)?.format(&FORMAT)?,
+///     "2021-01-02"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// ```rust,compile_fail
+/// # use time::format_description::{parse_fixed, FixedFormatDescription};
+/// const FORMAT: FixedFormatDescription<'_> = parse_fixed("[year]-[month]-[dya]");
+/// # let _ = FORMAT;
+/// ```
+///
+/// This function is `const fn` when using rustc >= 1.57.
+///
+/// # Panics
+///
+/// This panics if the format description is invalid or has more than
+/// [`FixedFormatDescription::CAPACITY`] items.
+#[const_fn("1.57")]
+pub const fn parse_fixed(s: &str) -> FixedFormatDescription<'_> {
+    let mut items = [FormatItem::Literal(b""); CAPACITY];
+    let mut len = 0;
+    let mut s = s.as_bytes();
+
+    while !s.is_empty() {
+        assert!(
+            len < CAPACITY,
+            "format description has more items than `FixedFormatDescription::CAPACITY`"
+        );
+        let (item, remaining) = parse_item(s);
+        items[len] = item;
+        len += 1;
+        s = remaining;
+    }
+
+    FixedFormatDescription { items, len }
+}
+
+/// Parse either a literal or a component from the format description, returning it along with
+/// the remaining input.
+#[const_fn("1.57")]
+const fn parse_item(s: &[u8]) -> (FormatItem<'_>, &[u8]) {
+    match s {
+        [b'[', b'[', remaining @ ..] => (FormatItem::Literal(b"["), remaining),
+        [b'[', remaining @ ..] => {
+            let (component, remaining) = parse_component(remaining);
+            (FormatItem::Component(component), remaining)
+        }
+        _ => {
+            let (literal, remaining) = split_at(s, position(s, b'['));
+            (FormatItem::Literal(literal), remaining)
+        }
+    }
+}
+
+/// Parse a component from the format description following its opening bracket, returning it
+/// along with the input following its closing bracket.
+#[const_fn("1.57")]
+const fn parse_component(s: &[u8]) -> (Component, &[u8]) {
+    let closing_bracket = position(s, b']');
+    assert!(
+        closing_bracket < s.len(),
+        "unclosed opening bracket in format description"
+    );
+    let (mut s, remaining) = split_at(s, closing_bracket);
+    let remaining = split_at(remaining, 1).1;
+
+    s = trim_whitespace(s);
+    let (name, modifiers) = split_at(s, whitespace_position(s));
+    let mut component = match component(name) {
+        Some(component) => component,
+        None if name.is_empty() => panic!("missing component name in format description"),
+        None => panic!("invalid component name in format description"),
+    };
+
+    s = trim_whitespace(modifiers);
+    while !s.is_empty() {
+        let (modifier, modifiers) = split_at(s, whitespace_position(s));
+        component = match apply_modifier(component, modifier) {
+            Some(component) => component,
+            None => panic!("invalid modifier in format description"),
+        };
+        s = trim_whitespace(modifiers);
+    }
+
+    (component, remaining)
+}
+
+/// The component with the provided name, with the modifiers used when none are present.
+const fn component(name: &[u8]) -> Option<Component> {
+    Some(if eq(name, b"day") {
+        Component::Day(modifier::Day::default())
+    } else if eq(name, b"month") {
+        Component::Month(modifier::Month::default())
+    } else if eq(name, b"ordinal") {
+        Component::Ordinal(modifier::Ordinal::default())
+    } else if eq(name, b"weekday") {
+        Component::Weekday(modifier::Weekday::default())
+    } else if eq(name, b"week_number") {
+        Component::WeekNumber(modifier::WeekNumber::default())
+    } else if eq(name, b"year") {
+        Component::Year(modifier::Year::default())
+    } else if eq(name, b"quarter") {
+        Component::Quarter(modifier::Quarter::default())
+    } else if eq(name, b"century") {
+        Component::Century(modifier::Century::default())
+    } else if eq(name, b"era") {
+        Component::Era(modifier::Era::default())
+    } else if eq(name, b"hour") {
+        Component::Hour(modifier::Hour::default())
+    } else if eq(name, b"minute") {
+        Component::Minute(modifier::Minute::default())
+    } else if eq(name, b"period") {
+        Component::Period(modifier::Period::default())
+    } else if eq(name, b"second") {
+        Component::Second(modifier::Second::default())
+    } else if eq(name, b"subsecond") {
+        Component::Subsecond(modifier::Subsecond::default())
+    } else if eq(name, b"offset_hour") {
+        // The sign is only mandatory when requested in a format description.
+        let mut modifier = modifier::OffsetHour::default();
+        modifier.sign_is_mandatory = false;
+        Component::OffsetHour(modifier)
+    } else if eq(name, b"offset_minute") {
+        Component::OffsetMinute(modifier::OffsetMinute::default())
+    } else if eq(name, b"offset_second") {
+        Component::OffsetSecond(modifier::OffsetSecond::default())
+    } else if eq(name, b"offset") {
+        Component::Offset(modifier::Offset::default())
+    } else if eq(name, b"time_zone") {
+        Component::TimeZone(modifier::TimeZone::default())
+    } else if eq(name, b"whitespace") {
+        Component::Whitespace(modifier::Whitespace::default())
+    } else if eq(name, b"ignore") {
+        Component::Ignore(modifier::Ignore::default())
+    } else if eq(name, b"end") {
+        Component::End(modifier::End::default())
+    } else {
+        return None;
+    })
+}
+
+/// Apply the modifier to the component, returning `None` if the modifier is not valid for it.
+#[allow(clippy::too_many_lines)]
+const fn apply_modifier(component: Component, modifier: &[u8]) -> Option<Component> {
+    Some(match component {
+        Component::Day(mut day) => {
+            if let Some(padding) = padding(modifier) {
+                day.padding = padding;
+            } else if let Some(value) = boolean(modifier, b"ordinal_suffix:") {
+                day.ordinal_suffix = value;
+            } else {
+                return None;
+            }
+            Component::Day(day)
+        }
+        Component::Month(mut month) => {
+            if let Some(padding) = padding(modifier) {
+                month.padding = padding;
+            } else if let Some(repr) = strip_prefix(modifier, b"repr:") {
+                month.repr = if eq(repr, b"numerical") {
+                    MonthRepr::Numerical
+                } else if eq(repr, b"long") {
+                    MonthRepr::Long
+                } else if eq(repr, b"short") {
+                    MonthRepr::Short
+                } else {
+                    return None;
+                };
+            } else if let Some(value) = boolean(modifier, b"case_sensitive:") {
+                month.case_sensitive = value;
+            } else if let Some(locale) = locale(modifier) {
+                month.locale = locale;
+            } else if let Some(value) = boolean(modifier, b"standalone:") {
+                month.standalone = value;
+            } else {
+                return None;
+            }
+            Component::Month(month)
+        }
+        Component::Ordinal(mut ordinal) => {
+            ordinal.padding = match padding(modifier) {
+                Some(padding) => padding,
+                None => return None,
+            };
+            Component::Ordinal(ordinal)
+        }
+        Component::Weekday(mut weekday) => {
+            if let Some(repr) = strip_prefix(modifier, b"repr:") {
+                weekday.repr = if eq(repr, b"short") {
+                    WeekdayRepr::Short
+                } else if eq(repr, b"long") {
+                    WeekdayRepr::Long
+                } else if eq(repr, b"sunday") {
+                    WeekdayRepr::Sunday
+                } else if eq(repr, b"monday") {
+                    WeekdayRepr::Monday
+                } else {
+                    return None;
+                };
+            } else if let Some(value) = boolean(modifier, b"one_indexed:") {
+                weekday.one_indexed = value;
+            } else if let Some(value) = boolean(modifier, b"case_sensitive:") {
+                weekday.case_sensitive = value;
+            } else if let Some(locale) = locale(modifier) {
+                weekday.locale = locale;
+            } else {
+                return None;
+            }
+            Component::Weekday(weekday)
+        }
+        Component::WeekNumber(mut week_number) => {
+            if let Some(padding) = padding(modifier) {
+                week_number.padding = padding;
+            } else if let Some(repr) = strip_prefix(modifier, b"repr:") {
+                week_number.repr = if eq(repr, b"iso") {
+                    WeekNumberRepr::Iso
+                } else if eq(repr, b"sunday") {
+                    WeekNumberRepr::Sunday
+                } else if eq(repr, b"monday") {
+                    WeekNumberRepr::Monday
+                } else {
+                    return None;
+                };
+            } else {
+                return None;
+            }
+            Component::WeekNumber(week_number)
+        }
+        Component::Year(mut year) => {
+            if let Some(padding) = padding(modifier) {
+                year.padding = padding;
+            } else if let Some(repr) = strip_prefix(modifier, b"repr:") {
+                year.repr = if eq(repr, b"full") {
+                    YearRepr::Full
+                } else if eq(repr, b"last_two") {
+                    YearRepr::LastTwo
+                } else if eq(repr, b"of_era") {
+                    YearRepr::OfEra
+                } else {
+                    return None;
+                };
+            } else if let Some(base) = strip_prefix(modifier, b"base:") {
+                year.iso_week_based = if eq(base, b"calendar") {
+                    false
+                } else if eq(base, b"iso_week") {
+                    true
+                } else {
+                    return None;
+                };
+            } else if let Some(sign_is_mandatory) = sign_is_mandatory(modifier) {
+                year.sign_is_mandatory = sign_is_mandatory;
+            } else if let Some(width) = width(modifier) {
+                year.width = Some(width);
+            } else {
+                return None;
+            }
+            Component::Year(year)
+        }
+        Component::Quarter(mut quarter) => {
+            quarter.repr = if eq(modifier, b"repr:numerical") {
+                QuarterRepr::Numerical
+            } else if eq(modifier, b"repr:short") {
+                QuarterRepr::Short
+            } else {
+                return None;
+            };
+            Component::Quarter(quarter)
+        }
+        Component::Century(mut century) => {
+            if let Some(padding) = padding(modifier) {
+                century.padding = padding;
+            } else if let Some(sign_is_mandatory) = sign_is_mandatory(modifier) {
+                century.sign_is_mandatory = sign_is_mandatory;
+            } else if let Some(width) = width(modifier) {
+                century.width = Some(width);
+            } else {
+                return None;
+            }
+            Component::Century(century)
+        }
+        Component::Era(mut era) => {
+            if eq(modifier, b"repr:ad") {
+                era.repr = EraRepr::Ad;
+            } else if eq(modifier, b"repr:ce") {
+                era.repr = EraRepr::Ce;
+            } else if let Some(value) = boolean(modifier, b"case_sensitive:") {
+                era.case_sensitive = value;
+            } else {
+                return None;
+            }
+            Component::Era(era)
+        }
+        Component::Hour(mut hour) => {
+            if let Some(padding) = padding(modifier) {
+                hour.padding = padding;
+            } else if eq(modifier, b"repr:24") {
+                hour.is_12_hour_clock = false;
+            } else if eq(modifier, b"repr:12") {
+                hour.is_12_hour_clock = true;
+            } else {
+                return None;
+            }
+            Component::Hour(hour)
+        }
+        Component::Minute(mut minute) => {
+            minute.padding = match padding(modifier) {
+                Some(padding) => padding,
+                None => return None,
+            };
+            Component::Minute(minute)
+        }
+        Component::Period(mut period) => {
+            if eq(modifier, b"case:upper") {
+                period.is_uppercase = true;
+            } else if eq(modifier, b"case:lower") {
+                period.is_uppercase = false;
+            } else if let Some(value) = boolean(modifier, b"case_sensitive:") {
+                period.case_sensitive = value;
+            } else if let Some(locale) = locale(modifier) {
+                period.locale = locale;
+            } else {
+                return None;
+            }
+            Component::Period(period)
+        }
+        Component::Second(mut second) => {
+            second.padding = match padding(modifier) {
+                Some(padding) => padding,
+                None => return None,
+            };
+            Component::Second(second)
+        }
+        Component::Subsecond(mut subsecond) => {
+            if let Some(digits) = strip_prefix(modifier, b"digits:") {
+                subsecond.digits = match digits {
+                    [b'1'] => SubsecondDigits::One,
+                    [b'2'] => SubsecondDigits::Two,
+                    [b'3'] => SubsecondDigits::Three,
+                    [b'4'] => SubsecondDigits::Four,
+                    [b'5'] => SubsecondDigits::Five,
+                    [b'6'] => SubsecondDigits::Six,
+                    [b'7'] => SubsecondDigits::Seven,
+                    [b'8'] => SubsecondDigits::Eight,
+                    [b'9'] => SubsecondDigits::Nine,
+                    [b'1', b'+'] => SubsecondDigits::OneOrMore,
+                    _ => return None,
+                };
+            } else if let Some(value) = boolean(modifier, b"trim_trailing_zeros:") {
+                subsecond.trim_trailing_zeros = value;
+            } else if eq(modifier, b"rounding:truncate") {
+                subsecond.rounding = SubsecondRounding::Truncate;
+            } else if eq(modifier, b"rounding:nearest_saturating") {
+                subsecond.rounding = SubsecondRounding::NearestSaturating;
+            } else {
+                return None;
+            }
+            Component::Subsecond(subsecond)
+        }
+        Component::OffsetHour(mut offset_hour) => {
+            if let Some(padding) = padding(modifier) {
+                offset_hour.padding = padding;
+            } else if let Some(sign_is_mandatory) = sign_is_mandatory(modifier) {
+                offset_hour.sign_is_mandatory = sign_is_mandatory;
+            } else if let Some(value) = boolean(modifier, b"z_for_utc:") {
+                offset_hour.z_for_utc = value;
+            } else if let Some(width) = width(modifier) {
+                offset_hour.width = Some(width);
+            } else {
+                return None;
+            }
+            Component::OffsetHour(offset_hour)
+        }
+        Component::OffsetMinute(mut offset_minute) => {
+            offset_minute.padding = match padding(modifier) {
+                Some(padding) => padding,
+                None => return None,
+            };
+            Component::OffsetMinute(offset_minute)
+        }
+        Component::OffsetSecond(mut offset_second) => {
+            offset_second.padding = match padding(modifier) {
+                Some(padding) => padding,
+                None => return None,
+            };
+            Component::OffsetSecond(offset_second)
+        }
+        Component::Offset(mut offset) => {
+            if let Some(value) = boolean(modifier, b"z_for_utc:") {
+                offset.z_for_utc = value;
+            } else if let Some(value) = boolean(modifier, b"colon:") {
+                offset.colon_separated = value;
+            } else if let Some(minute) = strip_prefix(modifier, b"minute:") {
+                offset.minute = match presence(minute) {
+                    Some(presence) => presence,
+                    None => return None,
+                };
+            } else if let Some(second) = strip_prefix(modifier, b"second:") {
+                offset.second = match presence(second) {
+                    Some(presence) => presence,
+                    None => return None,
+                };
+            } else {
+                return None;
+            }
+            Component::Offset(offset)
+        }
+        Component::TimeZone(mut time_zone) => {
+            time_zone.repr = if eq(modifier, b"repr:abbreviation") {
+                TimeZoneRepr::Abbreviation
+            } else if eq(modifier, b"repr:name") {
+                TimeZoneRepr::Name
+            } else {
+                return None;
+            };
+            Component::TimeZone(time_zone)
+        }
+        Component::Ignore(mut ignore) => {
+            ignore.count = match strip_prefix(modifier, b"count:") {
+                Some(count) => match count_value(count) {
+                    Some(count) => Some(count),
+                    None => return None,
+                },
+                None => return None,
+            };
+            Component::Ignore(ignore)
+        }
+        Component::Whitespace(_) | Component::End(_) => return None,
+    })
+}
+
+/// The value of a `padding` modifier.
+const fn padding(modifier: &[u8]) -> Option<Padding> {
+    match strip_prefix(modifier, b"padding:") {
+        Some(value) if eq(value, b"space") => Some(Padding::Space),
+        Some(value) if eq(value, b"zero") => Some(Padding::Zero),
+        Some(value) if eq(value, b"none") => Some(Padding::None),
+        _ => None,
+    }
+}
+
+/// The value of a `sign` modifier, being whether the sign is mandatory.
+const fn sign_is_mandatory(modifier: &[u8]) -> Option<bool> {
+    match strip_prefix(modifier, b"sign:") {
+        Some(value) if eq(value, b"automatic") => Some(false),
+        Some(value) if eq(value, b"mandatory") => Some(true),
+        _ => None,
+    }
+}
+
+/// The value of a modifier that is either `true` or `false`. The name must include the colon.
+const fn boolean(modifier: &[u8], name: &[u8]) -> Option<bool> {
+    match strip_prefix(modifier, name) {
+        Some(value) if eq(value, b"true") => Some(true),
+        Some(value) if eq(value, b"false") => Some(false),
+        _ => None,
+    }
+}
+
+/// The value of a `locale` modifier.
+const fn locale(modifier: &[u8]) -> Option<Locale> {
+    match strip_prefix(modifier, b"locale:") {
+        Some(code) => Locale::from_code(code),
+        None => None,
+    }
+}
+
+/// The value of a `width` modifier, which is between one and nine.
+#[allow(clippy::manual_is_ascii_check)] // `u8::is_ascii_digit` is not a `const fn` until Rust 1.47.
+const fn width(modifier: &[u8]) -> Option<u8> {
+    match strip_prefix(modifier, b"width:") {
+        Some(&[digit]) if digit >= b'1' && digit <= b'9' => Some(digit - b'0'),
+        _ => None,
+    }
+}
+
+/// The presence of part of the offset.
+const fn presence(value: &[u8]) -> Option<Presence> {
+    if eq(value, b"mandatory") {
+        Some(Presence::Mandatory)
+    } else if eq(value, b"optional") {
+        Some(Presence::Optional)
+    } else if eq(value, b"omitted") {
+        Some(Presence::Omitted)
+    } else {
+        None
+    }
+}
+
+/// A nonzero count from its decimal representation, which may have a leading `+`.
+const fn count_value(mut digits: &[u8]) -> Option<u16> {
+    if let [b'+', rest @ ..] = digits {
+        digits = rest;
+    }
+    if digits.is_empty() {
+        return None;
+    }
+
+    let mut count: u32 = 0;
+    while let [digit, rest @ ..] = digits {
+        // `u8::is_ascii_digit` is not a `const fn` until Rust 1.47.
+        #[allow(clippy::manual_is_ascii_check)]
+        if !matches!(digit, b'0'..=b'9') {
+            return None;
+        }
+        count = count * 10 + (*digit - b'0') as u32;
+        if count > u16::MAX as u32 {
+            return None;
+        }
+        digits = rest;
+    }
+
+    if count == 0 { None } else { Some(count as u16) }
+}
+
+/// Whether the byte is ASCII whitespace, as determined by [`u8::is_ascii_whitespace`].
+const fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\x0C' | b'\r')
+}
+
+/// Whether the bytes are equal.
+const fn eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The bytes following the prefix, if present.
+const fn strip_prefix<'a>(s: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if s.len() < prefix.len() {
+        return None;
+    }
+    let (start, rest) = split_at(s, prefix.len());
+    if eq(start, prefix) { Some(rest) } else { None }
+}
+
+/// The index of the first occurrence of the byte, or the length if it is not present.
+const fn position(s: &[u8], byte: u8) -> usize {
+    let mut i = 0;
+    while i < s.len() && s[i] != byte {
+        i += 1;
+    }
+    i
+}
+
+/// The index of the first whitespace byte, or the length if there is none.
+const fn whitespace_position(s: &[u8]) -> usize {
+    let mut i = 0;
+    while i < s.len() && !is_whitespace(s[i]) {
+        i += 1;
+    }
+    i
+}
+
+/// The bytes with any leading whitespace removed.
+const fn trim_whitespace(mut s: &[u8]) -> &[u8] {
+    while let [first, rest @ ..] = s {
+        if !is_whitespace(*first) {
+            break;
+        }
+        s = rest;
+    }
+    s
+}
+
+/// Split the bytes at the index, which must not exceed the length. Slices cannot be indexed by a
+/// range in a const context, so this removes one byte at a time.
+const fn split_at(s: &[u8], mid: usize) -> (&[u8], &[u8]) {
+    let mut before = s;
+    while before.len() > mid {
+        if let [rest @ .., _] = before {
+            before = rest;
+        }
+    }
+
+    let mut after = s;
+    while after.len() > s.len() - mid {
+        if let [_, rest @ ..] = after {
+            after = rest;
+        }
+    }
+
+    (before, after)
+}
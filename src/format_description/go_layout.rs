@@ -0,0 +1,314 @@
+//! Parse a Go reference layout into a standardized representation.
+
+use alloc::vec::Vec;
+
+use crate::error::InvalidFormatDescription;
+use crate::format_description::modifier::{self, Padding, SubsecondDigits};
+use crate::format_description::{Component, FormatItem};
+
+/// A part of the layout that is not a literal.
+enum Chunk {
+    /// A single component.
+    Component(Component),
+    /// Fractional seconds, including the preceding separator.
+    Fraction {
+        /// The number of digits present.
+        digits: SubsecondDigits,
+        /// Whether trailing zeros are trimmed (`.999`) rather than kept (`.000`).
+        trim_trailing_zeros: bool,
+    },
+    /// A numeric UTC offset, optionally using `Z` for UTC.
+    Offset {
+        /// Whether UTC is represented as `Z`.
+        z_for_utc: bool,
+        /// Whether a colon separates the hours, minutes, and seconds.
+        colon: bool,
+        /// Whether the minutes are present.
+        minute: bool,
+        /// Whether the seconds are present.
+        second: bool,
+    },
+}
+
+/// Whether the slice starts with a lowercase ASCII letter.
+const fn starts_with_lowercase(s: &[u8]) -> bool {
+    matches!(s, [b'a'..=b'z', ..])
+}
+
+/// Obtain the offset chunk at the start of the layout, which must begin with `-07` or `Z07`.
+fn offset(s: &[u8]) -> (usize, Chunk) {
+    let (len, colon, minute, second) = match &s[3..] {
+        [b'0', b'0', b'0', b'0', ..] => (7, false, true, true),
+        [b':', b'0', b'0', b':', b'0', b'0', ..] => (9, true, true, true),
+        [b'0', b'0', ..] => (5, false, true, false),
+        [b':', b'0', b'0', ..] => (6, true, true, false),
+        _ => (3, false, false, false),
+    };
+
+    (
+        len,
+        Chunk::Offset {
+            z_for_utc: s[0] == b'Z',
+            colon,
+            minute,
+            second,
+        },
+    )
+}
+
+/// Obtain the fractional second chunk, given the repeated digit and the layout following it.
+/// `None` is returned if the digits are immediately followed by another digit.
+fn fraction(
+    digit: u8,
+    rest: &[u8],
+    index: usize,
+) -> Result<Option<(usize, Chunk)>, InvalidFormatDescription> {
+    let num_digits = 1 + rest.iter().take_while(|&&c| c == digit).count();
+    // The run of digits must end here for it to be a fractional second.
+    if matches!(rest.get(num_digits - 1), Some(c) if c.is_ascii_digit()) {
+        return Ok(None);
+    }
+    let digits = match num_digits {
+        1 => SubsecondDigits::One,
+        2 => SubsecondDigits::Two,
+        3 => SubsecondDigits::Three,
+        4 => SubsecondDigits::Four,
+        5 => SubsecondDigits::Five,
+        6 => SubsecondDigits::Six,
+        7 => SubsecondDigits::Seven,
+        8 => SubsecondDigits::Eight,
+        9 => SubsecondDigits::Nine,
+        _ => {
+            return Err(InvalidFormatDescription::NotSupported {
+                what: "more than nine fractional digits",
+                context: "Go reference layout",
+                index,
+            });
+        }
+    };
+    Ok(Some((
+        1 + num_digits,
+        Chunk::Fraction {
+            digits,
+            trim_trailing_zeros: digit == b'9',
+        },
+    )))
+}
+
+/// Obtain the chunk at the start of the layout, along with its length in bytes. `None` is returned
+/// if the layout starts with a literal.
+fn chunk(s: &[u8], index: usize) -> Result<Option<(usize, Chunk)>, InvalidFormatDescription> {
+    match s {
+        [sign, b'0', b'7', ..] if *sign == b'-' || *sign == b'Z' => Ok(Some(offset(s))),
+        [separator, digit, rest @ ..]
+            if matches!(separator, b'.' | b',') && matches!(digit, b'0' | b'9') =>
+        {
+            fraction(*digit, rest, index)
+        }
+        _ => Ok(component(s).map(|(len, component)| (len, Chunk::Component(component)))),
+    }
+}
+
+/// Obtain the component at the start of the layout, along with its length in bytes. `None` is
+/// returned if the layout does not start with a component.
+#[allow(clippy::too_many_lines)]
+fn component(s: &[u8]) -> Option<(usize, Component)> {
+    use Component::*;
+
+    let (len, component) = match s {
+        [b'J', b'a', b'n', b'u', b'a', b'r', b'y', ..] => (
+            7,
+            Month(modifier::Month {
+                repr: modifier::MonthRepr::Long,
+                ..modifier::Month::default()
+            }),
+        ),
+        [b'J', b'a', b'n', rest @ ..] if !starts_with_lowercase(rest) => (
+            3,
+            Month(modifier::Month {
+                repr: modifier::MonthRepr::Short,
+                ..modifier::Month::default()
+            }),
+        ),
+        [b'M', b'o', b'n', b'd', b'a', b'y', ..] => (
+            6,
+            Weekday(modifier::Weekday {
+                repr: modifier::WeekdayRepr::Long,
+                ..modifier::Weekday::default()
+            }),
+        ),
+        [b'M', b'o', b'n', rest @ ..] if !starts_with_lowercase(rest) => (
+            3,
+            Weekday(modifier::Weekday {
+                repr: modifier::WeekdayRepr::Short,
+                ..modifier::Weekday::default()
+            }),
+        ),
+        [b'M', b'S', b'T', ..] => (3, TimeZone(modifier::TimeZone::default())),
+        [b'0', b'1', ..] => (2, Month(modifier::Month::default())),
+        [b'0', b'2', ..] => (2, Day(modifier::Day::default())),
+        [b'0', b'3', ..] => (
+            2,
+            Hour(modifier::Hour {
+                padding: Padding::Zero,
+                is_12_hour_clock: true,
+            }),
+        ),
+        [b'0', b'4', ..] => (2, Minute(modifier::Minute::default())),
+        [b'0', b'5', ..] => (2, Second(modifier::Second::default())),
+        [b'0', b'6', ..] => (
+            2,
+            Year(modifier::Year {
+                repr: modifier::YearRepr::LastTwo,
+                ..modifier::Year::default()
+            }),
+        ),
+        [b'0', b'0', b'2', ..] => (3, Ordinal(modifier::Ordinal::default())),
+        [b'1', b'5', ..] => (2, Hour(modifier::Hour::default())),
+        [b'1', ..] => (
+            1,
+            Month(modifier::Month {
+                padding: Padding::None,
+                ..modifier::Month::default()
+            }),
+        ),
+        [b'2', b'0', b'0', b'6', ..] => (4, Year(modifier::Year::default())),
+        [b'2', ..] => (
+            1,
+            Day(modifier::Day {
+                padding: Padding::None,
+                ..modifier::Day::default()
+            }),
+        ),
+        [b'_', b'_', b'2', ..] => (
+            3,
+            Ordinal(modifier::Ordinal {
+                padding: Padding::Space,
+            }),
+        ),
+        // `_2006` is a literal underscore followed by the year.
+        [b'_', b'2', b'0', b'0', b'6', ..] => return None,
+        [b'_', b'2', ..] => (
+            2,
+            Day(modifier::Day {
+                padding: Padding::Space,
+                ..modifier::Day::default()
+            }),
+        ),
+        [b'3', ..] => (
+            1,
+            Hour(modifier::Hour {
+                padding: Padding::None,
+                is_12_hour_clock: true,
+            }),
+        ),
+        [b'4', ..] => (
+            1,
+            Minute(modifier::Minute {
+                padding: Padding::None,
+            }),
+        ),
+        [b'5', ..] => (
+            1,
+            Second(modifier::Second {
+                padding: Padding::None,
+            }),
+        ),
+        [b'P', b'M', ..] => (2, Period(modifier::Period::default())),
+        [b'p', b'm', ..] => (
+            2,
+            Period(modifier::Period {
+                is_uppercase: false,
+                ..modifier::Period::default()
+            }),
+        ),
+        _ => return None,
+    };
+
+    Some((len, component))
+}
+
+/// Parse a [Go reference layout](https://pkg.go.dev/time#pkg-constants), such as
+/// `Mon Jan _2 15:04:05.000 2006`, into a sequence of items.
+///
+/// Fractional seconds written as `.000` (or `,000`) always contain the given number of digits.
+/// When written as `.999`, trailing zeros are trimmed; if no significant digits remain, the
+/// separator is omitted as well. Offsets starting with `Z` (such as `Z07:00`) represent UTC as
+/// `Z`.
+///
+/// Time zone abbreviations (`MST`) can only be formatted from a value that carries its time zone,
+/// such as [`ZonedDateTime`](crate::ZonedDateTime).
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse_go_layout(layout: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
+    let layout = layout.as_bytes();
+    let mut items = Vec::new();
+    let mut literal_start = 0;
+    let mut index = 0;
+
+    while index < layout.len() {
+        if let Some((len, chunk)) = chunk(&layout[index..], index)? {
+            if literal_start != index {
+                items.push(FormatItem::Literal(&layout[literal_start..index]));
+            }
+
+            match chunk {
+                Chunk::Component(component) => items.push(FormatItem::Component(component)),
+                Chunk::Fraction {
+                    digits,
+                    trim_trailing_zeros,
+                } => {
+                    items.push(FormatItem::Literal(&layout[index..=index]));
+                    items.push(FormatItem::Component(Component::Subsecond(
+                        modifier::Subsecond {
+                            digits,
+                            trim_trailing_zeros,
+                            ..modifier::Subsecond::default()
+                        },
+                    )));
+                }
+                Chunk::Offset {
+                    z_for_utc,
+                    colon,
+                    minute,
+                    second,
+                } => {
+                    items.push(FormatItem::Component(Component::OffsetHour(
+                        modifier::OffsetHour {
+                            sign_is_mandatory: true,
+                            padding: Padding::Zero,
+                            z_for_utc,
+                            ..modifier::OffsetHour::default()
+                        },
+                    )));
+                    if minute {
+                        if colon {
+                            items.push(FormatItem::Literal(b":"));
+                        }
+                        items.push(FormatItem::Component(Component::OffsetMinute(
+                            modifier::OffsetMinute::default(),
+                        )));
+                    }
+                    if second {
+                        if colon {
+                            items.push(FormatItem::Literal(b":"));
+                        }
+                        items.push(FormatItem::Component(Component::OffsetSecond(
+                            modifier::OffsetSecond::default(),
+                        )));
+                    }
+                }
+            }
+
+            index += len;
+            literal_start = index;
+        } else {
+            index += 1;
+        }
+    }
+
+    if literal_start != layout.len() {
+        items.push(FormatItem::Literal(&layout[literal_start..]));
+    }
+
+    Ok(items)
+}
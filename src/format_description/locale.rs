@@ -0,0 +1,392 @@
+//! Locale data for the names of months, weekdays, and periods.
+
+/// The locale used for the names of months, weekdays, and periods (AM/PM).
+///
+/// English is always available. Other locales require the `locale` feature; their names are a
+/// curated subset of the [Unicode CLDR](https://cldr.unicode.org). When parsing names without case
+/// sensitivity, only ASCII letters are compared case-insensitively.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// English (`en`).
+    En,
+    /// German (`de`).
+    #[cfg(feature = "locale")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "locale")))]
+    De,
+    /// Spanish (`es`).
+    #[cfg(feature = "locale")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "locale")))]
+    Es,
+    /// French (`fr`).
+    #[cfg(feature = "locale")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "locale")))]
+    Fr,
+    /// Italian (`it`).
+    #[cfg(feature = "locale")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "locale")))]
+    It,
+    /// Japanese (`ja`).
+    #[cfg(feature = "locale")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "locale")))]
+    Ja,
+    /// Russian (`ru`).
+    #[cfg(feature = "locale")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "locale")))]
+    Ru,
+}
+
+impl Locale {
+    /// The language code of the locale, as used in format descriptions.
+    ///
+    /// ```rust
+    /// # use time::format_description::modifier::Locale;
+    /// assert_eq!(Locale::En.code(), "en");
+    /// ```
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            #[cfg(feature = "locale")]
+            Self::De => "de",
+            #[cfg(feature = "locale")]
+            Self::Es => "es",
+            #[cfg(feature = "locale")]
+            Self::Fr => "fr",
+            #[cfg(feature = "locale")]
+            Self::It => "it",
+            #[cfg(feature = "locale")]
+            Self::Ja => "ja",
+            #[cfg(feature = "locale")]
+            Self::Ru => "ru",
+        }
+    }
+
+    /// Obtain the locale with the provided language code, if it is available.
+    pub(crate) const fn from_code(code: &[u8]) -> Option<Self> {
+        match code {
+            [b'e', b'n'] => Some(Self::En),
+            #[cfg(feature = "locale")]
+            [b'd', b'e'] => Some(Self::De),
+            #[cfg(feature = "locale")]
+            [b'e', b's'] => Some(Self::Es),
+            #[cfg(feature = "locale")]
+            [b'f', b'r'] => Some(Self::Fr),
+            #[cfg(feature = "locale")]
+            [b'i', b't'] => Some(Self::It),
+            #[cfg(feature = "locale")]
+            [b'j', b'a'] => Some(Self::Ja),
+            #[cfg(feature = "locale")]
+            [b'r', b'u'] => Some(Self::Ru),
+            _ => None,
+        }
+    }
+
+    /// The names of the months, starting with January.
+    ///
+    /// The standalone form is used when the month is not part of a complete date, such as in a
+    /// calendar heading. In many locales, it is identical to the format form.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) const fn month_names(
+        self,
+        short: bool,
+        standalone: bool,
+    ) -> &'static [&'static str; 12] {
+        // Allow the parameter to be unused when only English is available.
+        let _ = standalone;
+        match (self, short) {
+            (Self::En, false) => &[
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            (Self::En, true) => &[
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::De, false) => &[
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::De, true) if standalone => &[
+                "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::De, true) => &[
+                "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+                "Nov.", "Dez.",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Es, false) => &[
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Es, true) => &[
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Fr, false) => &[
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Fr, true) => &[
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::It, false) => &[
+                "gennaio",
+                "febbraio",
+                "marzo",
+                "aprile",
+                "maggio",
+                "giugno",
+                "luglio",
+                "agosto",
+                "settembre",
+                "ottobre",
+                "novembre",
+                "dicembre",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::It, true) => &[
+                "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Ja, _) => &[
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Ru, false) if standalone => &[
+                "январь",
+                "февраль",
+                "март",
+                "апрель",
+                "май",
+                "июнь",
+                "июль",
+                "август",
+                "сентябрь",
+                "октябрь",
+                "ноябрь",
+                "декабрь",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Ru, false) => &[
+                "января",
+                "февраля",
+                "марта",
+                "апреля",
+                "мая",
+                "июня",
+                "июля",
+                "августа",
+                "сентября",
+                "октября",
+                "ноября",
+                "декабря",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Ru, true) if standalone => &[
+                "янв.",
+                "февр.",
+                "март",
+                "апр.",
+                "май",
+                "июнь",
+                "июль",
+                "авг.",
+                "сент.",
+                "окт.",
+                "нояб.",
+                "дек.",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Ru, true) => &[
+                "янв.",
+                "февр.",
+                "мар.",
+                "апр.",
+                "мая",
+                "июн.",
+                "июл.",
+                "авг.",
+                "сент.",
+                "окт.",
+                "нояб.",
+                "дек.",
+            ],
+        }
+    }
+
+    /// The names of the weekdays, starting with Monday.
+    pub(crate) const fn weekday_names(self, short: bool) -> &'static [&'static str; 7] {
+        match (self, short) {
+            (Self::En, false) => &[
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Saturday",
+                "Sunday",
+            ],
+            (Self::En, true) => &["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+            #[cfg(feature = "locale")]
+            (Self::De, false) => &[
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+                "Sonntag",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::De, true) => &["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+            #[cfg(feature = "locale")]
+            (Self::Es, false) => &[
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+                "domingo",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Es, true) => &["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+            #[cfg(feature = "locale")]
+            (Self::Fr, false) => &[
+                "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Fr, true) => &["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+            #[cfg(feature = "locale")]
+            (Self::It, false) => &[
+                "lunedì",
+                "martedì",
+                "mercoledì",
+                "giovedì",
+                "venerdì",
+                "sabato",
+                "domenica",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::It, true) => &["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+            #[cfg(feature = "locale")]
+            (Self::Ja, false) => &[
+                "月曜日",
+                "火曜日",
+                "水曜日",
+                "木曜日",
+                "金曜日",
+                "土曜日",
+                "日曜日",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Ja, true) => &["月", "火", "水", "木", "金", "土", "日"],
+            #[cfg(feature = "locale")]
+            (Self::Ru, false) => &[
+                "понедельник",
+                "вторник",
+                "среда",
+                "четверг",
+                "пятница",
+                "суббота",
+                "воскресенье",
+            ],
+            #[cfg(feature = "locale")]
+            (Self::Ru, true) => &["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+        }
+    }
+
+    /// The names of the periods, before and after noon.
+    ///
+    /// Only locales that use the Latin abbreviations "AM" and "PM" distinguish between uppercase
+    /// and lowercase names.
+    pub(crate) const fn period_names(self, is_uppercase: bool) -> &'static [&'static str; 2] {
+        match self {
+            #[cfg(feature = "locale")]
+            Self::Es => &["a.\u{a0}m.", "p.\u{a0}m."],
+            #[cfg(feature = "locale")]
+            Self::Ja => &["午前", "午後"],
+            _ if is_uppercase => &["AM", "PM"],
+            _ => &["am", "pm"],
+        }
+    }
+
+    /// The maximum length in bytes of the name of a month.
+    #[cfg(feature = "formatting")]
+    pub(crate) const fn max_month_len(self, short: bool, standalone: bool) -> usize {
+        max_len(self.month_names(short, standalone))
+    }
+
+    /// The maximum length in bytes of the name of a weekday.
+    #[cfg(feature = "formatting")]
+    pub(crate) const fn max_weekday_len(self, short: bool) -> usize {
+        max_len(self.weekday_names(short))
+    }
+
+    /// The maximum length in bytes of the name of a period.
+    #[cfg(feature = "formatting")]
+    pub(crate) const fn max_period_len(self) -> usize {
+        max_len(self.period_names(true))
+    }
+}
+
+/// The maximum length in bytes of any of the names.
+#[cfg(feature = "formatting")]
+const fn max_len(names: &[&str]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < names.len() {
+        if names[i].len() > max {
+            max = names[i].len();
+        }
+        i += 1;
+    }
+    max
+}
@@ -1,18 +1,32 @@
 //! Description of how types should be formatted and parsed.
 
+#[cfg(feature = "alloc")]
+mod analysis;
 mod component;
+mod fixed;
+#[cfg(feature = "alloc")]
+mod go_layout;
+mod locale;
 pub mod modifier;
 #[cfg(feature = "alloc")]
+mod owned_format_item;
+#[cfg(feature = "alloc")]
 pub(crate) mod parse;
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
-#[cfg(feature = "alloc")]
-use core::fmt;
+use core::fmt::{self, Write};
 
+#[cfg(feature = "alloc")]
+pub use self::analysis::{analyze, Analysis, TargetType};
 pub use self::component::Component;
+pub use self::fixed::{parse_fixed, FixedFormatDescription};
+#[cfg(feature = "alloc")]
+pub use self::go_layout::parse_go_layout;
+#[cfg(feature = "alloc")]
+pub use self::owned_format_item::OwnedFormatItem;
 #[cfg(feature = "alloc")]
-pub use self::parse::parse;
+pub use self::parse::{parse, parse_borrowed, parse_owned};
 
 /// Helper methods.
 #[cfg(feature = "alloc")]
@@ -37,20 +51,212 @@ pub mod well_known {
 }
 
 /// A complete description of how to format and parse a type.
+///
+/// The [`Display`](fmt::Display) implementation writes the item using
+/// [version 2](Version::V2) of the format description syntax, with only the modifiers that differ
+/// from their default. A slice of items can be displayed by wrapping it in
+/// [`FormatItem::Compound`]. [`OwnedFormatItem`] is displayed in the same manner, so the output can
+/// be parsed again with [`parse_owned`].
+///
+/// ```rust
+/// # use time::format_description::{self, Version};
+/// let format = format_description::parse_owned(
+///     Version::V2,
+///     r"[year]-[month padding:zero] \[[hour repr:12]\]",
+/// )?;
+/// assert_eq!(format.to_string(), r"[year]-[month] \[[hour repr:12]\]");
+/// # Ok::<_, time::Error>(())
+/// ```
 #[non_exhaustive]
 #[cfg_attr(not(feature = "alloc"), derive(Debug))]
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum FormatItem<'a> {
     /// Bytes that are formatted as-is.
     ///
     /// **Note**: If you call the `format` method that returns a `String`, these bytes will be
     /// passed through `String::from_utf8_lossy`.
     Literal(&'a [u8]),
+    /// Bytes that are formatted as-is. When parsing, ASCII characters are matched without regard
+    /// to case, so `T` also accepts `t`.
+    CaseInsensitiveLiteral(&'a [u8]),
     /// A minimal representation of a single non-literal item.
     Component(Component),
     /// A series of literals or components that collectively form a partial or complete
     /// description.
     Compound(&'a [Self]),
+    /// An item that may or may not be present. When formatting, the item is always present. When
+    /// parsing, no input is consumed if the item does not match.
+    Optional(&'a Self),
+    /// A series of alternatives, of which the first one that matches is used when parsing. When
+    /// formatting, the first item is used.
+    ///
+    /// This can also be used to accept any one of several literals, such as `T`, `t`, or a space
+    /// separating the date and time.
+    First(&'a [Self]),
+}
+
+/// The version of the format description syntax.
+///
+/// Version 1 is the syntax accepted by [`parse`]. Version 2 uses a backslash to escape `\`, `[`,
+/// and `]`, and allows nesting format descriptions inside of brackets, such as
+/// `[optional [.[subsecond]]]` and `[first [[hour]:[minute]] [[hour]]]`. A literal can be matched
+/// without regard to case when parsing with `[case_insensitive [T]]`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Version {
+    /// The original syntax, where `[[` is a literal `[`.
+    V1,
+    /// The syntax with backslash escapes and nested format descriptions.
+    V2,
+}
+
+/// An item in a sequence of format items. This allows the handling of items that depend on their
+/// neighbors to be shared between borrowed and owned format descriptions.
+pub(crate) trait SequenceItem {
+    /// The bytes of the item if it is a literal, whether or not it is case-sensitive.
+    fn literal(&self) -> Option<&[u8]>;
+
+    /// Whether the item is a literal that is matched ignoring ASCII case.
+    #[cfg(feature = "parsing")]
+    fn is_case_insensitive_literal(&self) -> bool;
+
+    /// The component if the item is a component.
+    fn component(&self) -> Option<Component>;
+
+    /// Whether the item is a literal separating a subsecond from the preceding second.
+    #[allow(clippy::unnested_or_patterns)]
+    fn is_subsecond_separator(&self) -> bool {
+        matches!(self.literal(), Some(b".") | Some(b","))
+    }
+
+    /// Whether the item is a subsecond component that trims trailing zeros.
+    #[cfg(feature = "parsing")]
+    fn is_trimmed_subsecond(&self) -> bool {
+        matches!(
+            self.component(),
+            Some(Component::Subsecond(modifier::Subsecond {
+                trim_trailing_zeros: true,
+                ..
+            }))
+        )
+    }
+
+    /// Whether the item is an ignore component without a fixed number of bytes.
+    #[cfg(feature = "parsing")]
+    fn is_variable_ignore(&self) -> bool {
+        matches!(
+            self.component(),
+            Some(Component::Ignore(modifier::Ignore { count: None }))
+        )
+    }
+
+    /// Whether the item is an offset hour component that represents UTC as `Z`.
+    fn is_offset_hour_z_for_utc(&self) -> bool {
+        matches!(
+            self.component(),
+            Some(Component::OffsetHour(modifier::OffsetHour {
+                z_for_utc: true,
+                ..
+            }))
+        )
+    }
+
+    /// Whether the item is an offset minute or offset second component.
+    #[allow(clippy::unnested_or_patterns)]
+    fn is_offset_minute_or_second(&self) -> bool {
+        matches!(
+            self.component(),
+            Some(Component::OffsetMinute(_)) | Some(Component::OffsetSecond(_))
+        )
+    }
+}
+
+impl SequenceItem for FormatItem<'_> {
+    fn literal(&self) -> Option<&[u8]> {
+        match self {
+            Self::Literal(literal) | Self::CaseInsensitiveLiteral(literal) => Some(literal),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "parsing")]
+    fn is_case_insensitive_literal(&self) -> bool {
+        matches!(self, Self::CaseInsensitiveLiteral(_))
+    }
+
+    fn component(&self) -> Option<Component> {
+        match self {
+            Self::Component(component) => Some(*component),
+            _ => None,
+        }
+    }
+}
+
+/// The number of leading items that are omitted after an offset hour was represented as `Z`. These
+/// are offset minute and offset second components, along with any literals separating them.
+pub(crate) fn offset_remainder_len<T: SequenceItem>(items: &[T]) -> usize {
+    let mut len = 0;
+    loop {
+        match &items[len..] {
+            [item, ..] if item.is_offset_minute_or_second() => len += 1,
+            [literal, item, ..]
+                if literal.literal().is_some() && item.is_offset_minute_or_second() =>
+            {
+                len += 2;
+            }
+            _ => return len,
+        }
+    }
+}
+
+/// Write a literal in version 2 of the format description syntax, escaping any characters with a
+/// special meaning. Bytes that are not valid UTF-8 are written as U+FFFD.
+pub(crate) fn write_literal(f: &mut fmt::Formatter<'_>, mut bytes: &[u8]) -> fmt::Result {
+    while !bytes.is_empty() {
+        let (valid, invalid_len) = match core::str::from_utf8(bytes) {
+            Ok(valid) => (valid, 0),
+            Err(err) => (
+                core::str::from_utf8(&bytes[..err.valid_up_to()]).unwrap_or_default(),
+                err.error_len()
+                    .unwrap_or_else(|| bytes.len() - err.valid_up_to()),
+            ),
+        };
+
+        for c in valid.chars() {
+            if matches!(c, '\\' | '[' | ']') {
+                f.write_char('\\')?;
+            }
+            f.write_char(c)?;
+        }
+        if invalid_len != 0 {
+            f.write_char(char::REPLACEMENT_CHARACTER)?;
+        }
+        bytes = &bytes[valid.len() + invalid_len..];
+    }
+    Ok(())
+}
+
+impl fmt::Display for FormatItem<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => write_literal(f, literal),
+            Self::CaseInsensitiveLiteral(literal) => {
+                f.write_str("[case_insensitive [")?;
+                write_literal(f, literal)?;
+                f.write_str("]]")
+            }
+            Self::Component(component) => component.fmt(f),
+            Self::Compound(items) => items.iter().try_for_each(|item| item.fmt(f)),
+            Self::Optional(item) => write!(f, "[optional [{}]]", item),
+            Self::First(items) => {
+                f.write_str("[first")?;
+                for item in *items {
+                    write!(f, " [{}]", item)?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -58,8 +264,14 @@ impl fmt::Debug for FormatItem<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             FormatItem::Literal(literal) => f.write_str(&String::from_utf8_lossy(literal)),
+            FormatItem::CaseInsensitiveLiteral(literal) => f
+                .debug_tuple("CaseInsensitiveLiteral")
+                .field(&String::from_utf8_lossy(literal))
+                .finish(),
             FormatItem::Component(component) => component.fmt(f),
             FormatItem::Compound(compound) => compound.fmt(f),
+            FormatItem::Optional(item) => f.debug_tuple("Optional").field(item).finish(),
+            FormatItem::First(items) => f.debug_tuple("First").field(items).finish(),
         }
     }
 }
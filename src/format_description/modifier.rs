@@ -8,6 +8,8 @@ use core::mem;
 #[cfg(feature = "alloc")]
 use crate::{error::InvalidFormatDescription, format_description::helper};
 
+pub use super::locale::Locale;
+
 // region: date modifiers
 /// Day of the month.
 #[non_exhaustive]
@@ -15,6 +17,8 @@ use crate::{error::InvalidFormatDescription, format_description::helper};
 pub struct Day {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// Whether the English ordinal suffix (e.g. "1st", "22nd") follows the number.
+    pub ordinal_suffix: bool,
 }
 
 /// The representation of a month.
@@ -39,6 +43,13 @@ pub struct Month {
     pub repr: MonthRepr,
     /// Is the value case sensitive when parsing?
     pub case_sensitive: bool,
+    /// The locale of the month name. Has no effect on the numerical representation.
+    pub locale: Locale,
+    /// Should the standalone form of the month name be used?
+    ///
+    /// Some locales use a different form of the name when the month is not part of a complete
+    /// date, such as in a calendar heading (e.g. "январь" rather than "января" in Russian).
+    pub standalone: bool,
 }
 
 /// Ordinal day of the year.
@@ -77,6 +88,8 @@ pub struct Weekday {
     pub one_indexed: bool,
     /// Is the value case sensitive when parsing?
     pub case_sensitive: bool,
+    /// The locale of the weekday name. Has no effect on numerical representations.
+    pub locale: Locale,
 }
 
 /// The representation used for the week number.
@@ -109,6 +122,11 @@ pub enum YearRepr {
     Full,
     /// Only the last two digits of the year.
     LastTwo,
+    /// The year within the era, where 1 BC is immediately followed by AD 1.
+    ///
+    /// This is always based on the calendar year. It is typically used in conjunction with the
+    /// [`Era`](crate::format_description::Component::Era) component.
+    OfEra,
 }
 
 /// Year of the date.
@@ -123,6 +141,65 @@ pub struct Year {
     pub iso_week_based: bool,
     /// Whether the `+` sign is present when a positive year contains fewer than five digits.
     pub sign_is_mandatory: bool,
+    /// The minimum number of digits, excluding the sign. When `None`, the full year and the year
+    /// of the era have at least four digits, and the last two digits of the year have two.
+    ///
+    /// Values with more digits are not truncated. The padding determines how the minimum width is
+    /// obtained.
+    pub width: Option<u8>,
+}
+
+/// The representation used for the quarter of the year.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuarterRepr {
+    /// The number of the quarter (e.g. "3").
+    Numerical,
+    /// The number of the quarter preceded by "Q" (e.g. "Q3").
+    Short,
+}
+
+/// Quarter of the year.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quarter {
+    /// What form of representation should be used?
+    pub repr: QuarterRepr,
+}
+
+/// Century of the year, being the year with its last two digits removed.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Century {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// Whether the `+` sign is present for a non-negative year.
+    pub sign_is_mandatory: bool,
+    /// The number of digits, excluding the sign. When `None`, there are two digits.
+    ///
+    /// As digits often follow the century, exactly this many digits are read when parsing. A
+    /// century with more digits than this cannot be parsed, so it is also an error to format one.
+    pub width: Option<u8>,
+}
+
+/// The representation used for the era.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EraRepr {
+    /// "BC" or "AD".
+    Ad,
+    /// "BCE" or "CE".
+    Ce,
+}
+
+/// Era of the year.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Era {
+    /// What form of representation should be used?
+    pub repr: EraRepr,
+    /// Is the value case sensitive when parsing?
+    pub case_sensitive: bool,
 }
 // endregion date modifiers
 
@@ -155,6 +232,10 @@ pub struct Period {
     ///
     /// Note that when `false`, the `is_uppercase` field has no effect on parsing behavior.
     pub case_sensitive: bool,
+    /// The locale of the period name.
+    ///
+    /// The `is_uppercase` field only has an effect for locales that use "AM" and "PM".
+    pub locale: Locale,
 }
 
 /// Second within the minute.
@@ -192,12 +273,33 @@ pub enum SubsecondDigits {
     OneOrMore,
 }
 
+/// How the subsecond is reduced to the number of digits formatted.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubsecondRounding {
+    /// Any additional digits are discarded.
+    Truncate,
+    /// The value is rounded to the nearest representable value, with ties rounded up, but
+    /// saturates instead of carrying over into the second. Values that would round up to a whole
+    /// second are formatted as the largest representable value (e.g. `59.9996` is formatted as
+    /// `59.999` with three digits, not `00.000` of the following minute).
+    NearestSaturating,
+}
+
 /// Subsecond within the second.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Subsecond {
     /// How many digits are present in the component?
     pub digits: SubsecondDigits,
+    /// Are trailing zeros removed when formatting?
+    ///
+    /// When the value has no significant digits, nothing is written. A literal `.` or `,`
+    /// immediately preceding the component is omitted along with it, and is optional when parsing.
+    pub trim_trailing_zeros: bool,
+    /// How the value is reduced to the number of digits formatted. This has no effect when
+    /// parsing.
+    pub rounding: SubsecondRounding,
 }
 // endregion time modifiers
 
@@ -210,6 +312,14 @@ pub struct OffsetHour {
     pub sign_is_mandatory: bool,
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// Whether a UTC offset is represented as `Z`. When parsing, `z` is also accepted.
+    ///
+    /// When this is the case, any offset minute and offset second components (and the literals
+    /// separating them) immediately following this component are omitted.
+    pub z_for_utc: bool,
+    /// The minimum number of digits, excluding the sign. When `None`, there are at least two
+    /// digits. The padding determines how the minimum width is obtained.
+    pub width: Option<u8>,
 }
 
 /// Minute within the hour of the UTC offset.
@@ -227,8 +337,95 @@ pub struct OffsetSecond {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
 }
+
+/// Whether part of a component is present.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+    /// The part is always present.
+    Mandatory,
+    /// The part is present when formatting only if it is nonzero, and may be omitted when parsing.
+    Optional,
+    /// The part is never present. Formatting a nonzero value is an error.
+    Omitted,
+}
+
+/// The entire UTC offset, such as `+05:30`.
+///
+/// The sign is always present and the hour always has two digits. The offset second is only
+/// present if the offset minute is.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Offset {
+    /// Whether a UTC offset is represented as `Z`. When parsing, `z` is also accepted.
+    pub z_for_utc: bool,
+    /// Whether the hour, minute, and second are separated by a colon.
+    pub colon_separated: bool,
+    /// Whether the offset minute is present.
+    pub minute: Presence,
+    /// Whether the offset second is present.
+    pub second: Presence,
+}
 // endregion offset modifiers
 
+// region: time zone modifiers
+/// The representation used for a time zone.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeZoneRepr {
+    /// The abbreviation of the time zone in effect (e.g. "PST").
+    Abbreviation,
+    /// The name of the time zone in the IANA time zone database (e.g. `America/Los_Angeles`).
+    Name,
+}
+
+impl TimeZoneRepr {
+    /// The maximum length in bytes of the representation. This is sufficient for all
+    /// abbreviations and names in the IANA time zone database.
+    pub(crate) const fn max_len(self) -> usize {
+        match self {
+            Self::Abbreviation => 6,
+            Self::Name => 32,
+        }
+    }
+}
+
+/// Time zone of the value.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeZone {
+    /// The representation of the time zone.
+    pub repr: TimeZoneRepr,
+}
+// endregion time zone modifiers
+
+// region: input modifiers
+/// One or more whitespace characters.
+///
+/// When formatting, a single space is written.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Whitespace;
+
+/// Bytes that are skipped when parsing.
+///
+/// When formatting, nothing is written.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ignore {
+    /// The number of bytes to skip. When this is `None`, all bytes are skipped up to the literal
+    /// immediately following the item, or to the end of the input if there is no such literal.
+    pub count: Option<u16>,
+}
+
+/// The end of the input. Parsing fails if any input remains.
+///
+/// When formatting, nothing is written.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct End;
+// endregion input modifiers
+
 /// Type of padding to ensure a minimum width.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -242,8 +439,8 @@ pub enum Padding {
     None,
 }
 
-// Every modifier should use this macro rather than a derived `Default`. This ensures that it is
-// const-compatible, albeit with a slight hack.
+/// Every modifier should use this macro rather than a derived `Default`. This ensures that it is
+/// const-compatible, albeit with a slight hack.
 macro_rules! impl_const_default {
     ($($type:ty => $default:expr;)*) => {$(
         impl $type {
@@ -267,12 +464,17 @@ macro_rules! impl_const_default {
 }
 
 impl_const_default! {
-    Day => Self { padding: Padding::default() };
+    Day => Self {
+        padding: Padding::default(),
+        ordinal_suffix: false,
+    };
     MonthRepr => Self::Numerical;
     Month => Self {
         padding: Padding::default(),
         repr: MonthRepr::default(),
         case_sensitive: true,
+        locale: Locale::En,
+        standalone: false,
     };
     Ordinal => Self { padding: Padding::default() };
     WeekdayRepr => Self::Long;
@@ -280,6 +482,7 @@ impl_const_default! {
         repr: WeekdayRepr::default(),
         one_indexed: true,
         case_sensitive: true,
+        locale: Locale::En,
     };
     WeekNumberRepr => Self::Iso;
     WeekNumber => Self {
@@ -292,6 +495,21 @@ impl_const_default! {
         repr: YearRepr::default(),
         iso_week_based: false,
         sign_is_mandatory: false,
+        width: None,
+    };
+    QuarterRepr => Self::Numerical;
+    Quarter => Self {
+        repr: QuarterRepr::default(),
+    };
+    Century => Self {
+        padding: Padding::default(),
+        sign_is_mandatory: false,
+        width: None,
+    };
+    EraRepr => Self::Ad;
+    Era => Self {
+        repr: EraRepr::default(),
+        case_sensitive: true,
     };
     Hour => Self {
         padding: Padding::default(),
@@ -301,20 +519,43 @@ impl_const_default! {
     Period => Self {
         is_uppercase: true,
         case_sensitive: true,
+        locale: Locale::En,
     };
     Second => Self { padding: Padding::default() };
     SubsecondDigits => Self::OneOrMore;
-    Subsecond => Self { digits: SubsecondDigits::default() };
+    SubsecondRounding => Self::Truncate;
+    Subsecond => Self {
+        digits: SubsecondDigits::default(),
+        trim_trailing_zeros: false,
+        rounding: SubsecondRounding::default(),
+    };
     OffsetHour => Self {
         sign_is_mandatory: true,
         padding: Padding::default(),
+        z_for_utc: false,
+        width: None,
     };
     OffsetMinute => Self { padding: Padding::default() };
     OffsetSecond => Self { padding: Padding::default() };
+    Presence => Self::Mandatory;
+    Offset => Self {
+        z_for_utc: true,
+        colon_separated: true,
+        minute: Presence::Mandatory,
+        second: Presence::Optional,
+    };
+    TimeZoneRepr => Self::Abbreviation;
+    TimeZone => Self {
+        repr: TimeZoneRepr::default(),
+    };
+    Whitespace => Self;
+    Ignore => Self { count: None };
+    End => Self;
     Padding => Self::Zero;
 }
 
 /// The modifiers parsed for any given component. `None` indicates the modifier was not present.
+#[cfg(feature = "alloc")]
 #[allow(clippy::missing_docs_in_private_items)] // fields
 #[derive(Debug, Default)]
 pub(crate) struct Modifiers {
@@ -330,11 +571,25 @@ pub(crate) struct Modifiers {
     pub(crate) year_is_iso_week_based: Option<bool>,
     pub(crate) sign_is_mandatory: Option<bool>,
     pub(crate) case_sensitive: Option<bool>,
+    pub(crate) subsecond_trim_trailing_zeros: Option<bool>,
+    pub(crate) subsecond_rounding: Option<SubsecondRounding>,
+    pub(crate) width: Option<u8>,
+    pub(crate) z_for_utc: Option<bool>,
+    pub(crate) offset_colon_separated: Option<bool>,
+    pub(crate) offset_minute: Option<Presence>,
+    pub(crate) offset_second: Option<Presence>,
+    pub(crate) locale: Option<Locale>,
+    pub(crate) month_is_standalone: Option<bool>,
+    pub(crate) day_has_ordinal_suffix: Option<bool>,
+    pub(crate) quarter_repr: Option<QuarterRepr>,
+    pub(crate) era_repr: Option<EraRepr>,
+    pub(crate) time_zone_repr: Option<TimeZoneRepr>,
+    pub(crate) ignore_count: Option<u16>,
 }
 
+#[cfg(feature = "alloc")]
 impl Modifiers {
     /// Parse the modifiers of a given component.
-    #[cfg(feature = "alloc")]
     #[allow(clippy::too_many_lines)]
     pub(crate) fn parse(
         component_name: &[u8],
@@ -362,7 +617,8 @@ impl Modifiers {
 
             #[allow(clippy::unnested_or_patterns)]
             match (component_name, modifier) {
-                (b"day", b"padding:space")
+                (b"century", b"padding:space")
+                | (b"day", b"padding:space")
                 | (b"hour", b"padding:space")
                 | (b"minute", b"padding:space")
                 | (b"month", b"padding:space")
@@ -373,7 +629,8 @@ impl Modifiers {
                 | (b"second", b"padding:space")
                 | (b"week_number", b"padding:space")
                 | (b"year", b"padding:space") => modifiers.padding = Some(Padding::Space),
-                (b"day", b"padding:zero")
+                (b"century", b"padding:zero")
+                | (b"day", b"padding:zero")
                 | (b"hour", b"padding:zero")
                 | (b"minute", b"padding:zero")
                 | (b"month", b"padding:zero")
@@ -384,7 +641,8 @@ impl Modifiers {
                 | (b"second", b"padding:zero")
                 | (b"week_number", b"padding:zero")
                 | (b"year", b"padding:zero") => modifiers.padding = Some(Padding::Zero),
-                (b"day", b"padding:none")
+                (b"century", b"padding:none")
+                | (b"day", b"padding:none")
                 | (b"hour", b"padding:none")
                 | (b"minute", b"padding:none")
                 | (b"month", b"padding:none")
@@ -395,23 +653,63 @@ impl Modifiers {
                 | (b"second", b"padding:none")
                 | (b"week_number", b"padding:none")
                 | (b"year", b"padding:none") => modifiers.padding = Some(Padding::None),
+                (b"day", b"ordinal_suffix:true") => modifiers.day_has_ordinal_suffix = Some(true),
+                (b"day", b"ordinal_suffix:false") => modifiers.day_has_ordinal_suffix = Some(false),
+                (b"era", b"repr:ad") => modifiers.era_repr = Some(EraRepr::Ad),
+                (b"era", b"repr:ce") => modifiers.era_repr = Some(EraRepr::Ce),
                 (b"hour", b"repr:24") => modifiers.hour_is_12_hour_clock = Some(false),
                 (b"hour", b"repr:12") => modifiers.hour_is_12_hour_clock = Some(true),
-                (b"month", b"case_sensitive:true")
+                (b"era", b"case_sensitive:true")
+                | (b"month", b"case_sensitive:true")
                 | (b"period", b"case_sensitive:true")
                 | (b"weekday", b"case_sensitive:true") => modifiers.case_sensitive = Some(true),
-                (b"month", b"case_sensitive:false")
+                (b"era", b"case_sensitive:false")
+                | (b"month", b"case_sensitive:false")
                 | (b"period", b"case_sensitive:false")
                 | (b"weekday", b"case_sensitive:false") => modifiers.case_sensitive = Some(false),
                 (b"month", b"repr:numerical") => modifiers.month_repr = Some(MonthRepr::Numerical),
                 (b"month", b"repr:long") => modifiers.month_repr = Some(MonthRepr::Long),
                 (b"month", b"repr:short") => modifiers.month_repr = Some(MonthRepr::Short),
-                (b"offset_hour", b"sign:automatic") | (b"year", b"sign:automatic") => {
-                    modifiers.sign_is_mandatory = Some(false)
+                (b"month", b"standalone:true") => modifiers.month_is_standalone = Some(true),
+                (b"month", b"standalone:false") => modifiers.month_is_standalone = Some(false),
+                (b"month", _) | (b"period", _) | (b"weekday", _)
+                    if modifier.starts_with(b"locale:")
+                        && Locale::from_code(&modifier[7..]).is_some() =>
+                {
+                    modifiers.locale = Locale::from_code(&modifier[7..]);
+                }
+                (b"century", b"sign:automatic")
+                | (b"offset_hour", b"sign:automatic")
+                | (b"year", b"sign:automatic") => modifiers.sign_is_mandatory = Some(false),
+                (b"century", b"sign:mandatory")
+                | (b"offset_hour", b"sign:mandatory")
+                | (b"year", b"sign:mandatory") => modifiers.sign_is_mandatory = Some(true),
+                (b"offset", b"z_for_utc:true") | (b"offset_hour", b"z_for_utc:true") => {
+                    modifiers.z_for_utc = Some(true)
+                }
+                (b"offset", b"z_for_utc:false") | (b"offset_hour", b"z_for_utc:false") => {
+                    modifiers.z_for_utc = Some(false)
                 }
-                (b"offset_hour", b"sign:mandatory") | (b"year", b"sign:mandatory") => {
-                    modifiers.sign_is_mandatory = Some(true)
+                (b"offset", b"colon:true") => modifiers.offset_colon_separated = Some(true),
+                (b"offset", b"colon:false") => modifiers.offset_colon_separated = Some(false),
+                (b"offset", b"minute:mandatory") => {
+                    modifiers.offset_minute = Some(Presence::Mandatory)
                 }
+                (b"offset", b"minute:optional") => {
+                    modifiers.offset_minute = Some(Presence::Optional)
+                }
+                (b"offset", b"minute:omitted") => modifiers.offset_minute = Some(Presence::Omitted),
+                (b"offset", b"second:mandatory") => {
+                    modifiers.offset_second = Some(Presence::Mandatory)
+                }
+                (b"offset", b"second:optional") => {
+                    modifiers.offset_second = Some(Presence::Optional)
+                }
+                (b"offset", b"second:omitted") => modifiers.offset_second = Some(Presence::Omitted),
+                (b"quarter", b"repr:numerical") => {
+                    modifiers.quarter_repr = Some(QuarterRepr::Numerical)
+                }
+                (b"quarter", b"repr:short") => modifiers.quarter_repr = Some(QuarterRepr::Short),
                 (b"period", b"case:upper") => modifiers.period_is_uppercase = Some(true),
                 (b"period", b"case:lower") => modifiers.period_is_uppercase = Some(false),
                 (b"subsecond", b"digits:1") => {
@@ -444,6 +742,22 @@ impl Modifiers {
                 (b"subsecond", b"digits:1+") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::OneOrMore)
                 }
+                (b"subsecond", b"trim_trailing_zeros:true") => {
+                    modifiers.subsecond_trim_trailing_zeros = Some(true)
+                }
+                (b"subsecond", b"trim_trailing_zeros:false") => {
+                    modifiers.subsecond_trim_trailing_zeros = Some(false)
+                }
+                (b"subsecond", b"rounding:truncate") => {
+                    modifiers.subsecond_rounding = Some(SubsecondRounding::Truncate)
+                }
+                (b"subsecond", b"rounding:nearest_saturating") => {
+                    modifiers.subsecond_rounding = Some(SubsecondRounding::NearestSaturating)
+                }
+                (b"time_zone", b"repr:abbreviation") => {
+                    modifiers.time_zone_repr = Some(TimeZoneRepr::Abbreviation)
+                }
+                (b"time_zone", b"repr:name") => modifiers.time_zone_repr = Some(TimeZoneRepr::Name),
                 (b"weekday", b"repr:short") => modifiers.weekday_repr = Some(WeekdayRepr::Short),
                 (b"weekday", b"repr:long") => modifiers.weekday_repr = Some(WeekdayRepr::Long),
                 (b"weekday", b"repr:sunday") => modifiers.weekday_repr = Some(WeekdayRepr::Sunday),
@@ -461,10 +775,21 @@ impl Modifiers {
                 (b"week_number", b"repr:monday") => {
                     modifiers.week_number_repr = Some(WeekNumberRepr::Monday)
                 }
+                (b"ignore", _)
+                    if modifier.starts_with(b"count:") && parse_count(&modifier[6..]).is_some() =>
+                {
+                    modifiers.ignore_count = parse_count(&modifier[6..]);
+                }
                 (b"year", b"repr:full") => modifiers.year_repr = Some(YearRepr::Full),
                 (b"year", b"repr:last_two") => modifiers.year_repr = Some(YearRepr::LastTwo),
+                (b"year", b"repr:of_era") => modifiers.year_repr = Some(YearRepr::OfEra),
                 (b"year", b"base:calendar") => modifiers.year_is_iso_week_based = Some(false),
                 (b"year", b"base:iso_week") => modifiers.year_is_iso_week_based = Some(true),
+                (b"century", _) | (b"offset_hour", _) | (b"year", _)
+                    if modifier.starts_with(b"width:") && parse_width(&modifier[6..]).is_some() =>
+                {
+                    modifiers.width = parse_width(&modifier[6..]);
+                }
                 _ => {
                     return Err(InvalidFormatDescription::InvalidModifier {
                         value: String::from_utf8_lossy(modifier).into_owned(),
@@ -477,3 +802,21 @@ impl Modifiers {
         Ok(modifiers)
     }
 }
+
+/// Parse a nonzero count from its decimal representation.
+#[cfg(feature = "alloc")]
+fn parse_count(bytes: &[u8]) -> Option<u16> {
+    match core::str::from_utf8(bytes).ok()?.parse() {
+        Ok(0) | Err(_) => None,
+        Ok(count) => Some(count),
+    }
+}
+
+/// Parse a width between one and nine from its decimal representation.
+#[cfg(feature = "alloc")]
+fn parse_width(bytes: &[u8]) -> Option<u8> {
+    match bytes {
+        &[digit @ b'1'..=b'9'] => Some(digit - b'0'),
+        _ => None,
+    }
+}
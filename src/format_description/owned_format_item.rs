@@ -0,0 +1,134 @@
+//! A format item with owned data.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::format_description::{write_literal, Component, FormatItem, SequenceItem};
+
+/// A complete description of how to format and parse a type. Unlike [`FormatItem`], the data is
+/// owned, which allows it to contain nested items created at runtime.
+///
+/// As with [`FormatItem`], the [`Display`](fmt::Display) implementation writes the item using
+/// [version 2](crate::format_description::Version::V2) of the format description syntax.
+#[non_exhaustive]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, PartialEq, Eq)]
+pub enum OwnedFormatItem {
+    /// Bytes that are formatted as-is.
+    ///
+    /// **Note**: If you call the `format` method that returns a `String`, these bytes will be
+    /// passed through `String::from_utf8_lossy`.
+    Literal(Box<[u8]>),
+    /// Bytes that are formatted as-is. When parsing, ASCII characters are matched without regard
+    /// to case, so `T` also accepts `t`.
+    CaseInsensitiveLiteral(Box<[u8]>),
+    /// A minimal representation of a single non-literal item.
+    Component(Component),
+    /// A series of literals or components that collectively form a partial or complete
+    /// description.
+    Compound(Box<[Self]>),
+    /// An item that may or may not be present. When formatting, the item is always present. When
+    /// parsing, no input is consumed if the item does not match.
+    Optional(Box<Self>),
+    /// A series of alternatives, of which the first one that matches is used when parsing. When
+    /// formatting, the first item is used.
+    ///
+    /// This can also be used to accept any one of several literals, such as `T`, `t`, or a space
+    /// separating the date and time.
+    First(Box<[Self]>),
+}
+
+impl SequenceItem for OwnedFormatItem {
+    fn literal(&self) -> Option<&[u8]> {
+        match self {
+            Self::Literal(literal) | Self::CaseInsensitiveLiteral(literal) => Some(literal),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "parsing")]
+    fn is_case_insensitive_literal(&self) -> bool {
+        matches!(self, Self::CaseInsensitiveLiteral(_))
+    }
+
+    fn component(&self) -> Option<Component> {
+        match self {
+            Self::Component(component) => Some(*component),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for OwnedFormatItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => f.write_str(&String::from_utf8_lossy(literal)),
+            Self::CaseInsensitiveLiteral(literal) => f
+                .debug_tuple("CaseInsensitiveLiteral")
+                .field(&String::from_utf8_lossy(literal))
+                .finish(),
+            Self::Component(component) => component.fmt(f),
+            Self::Compound(compound) => compound.fmt(f),
+            Self::Optional(item) => f.debug_tuple("Optional").field(item).finish(),
+            Self::First(items) => f.debug_tuple("First").field(items).finish(),
+        }
+    }
+}
+
+impl fmt::Display for OwnedFormatItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => write_literal(f, literal),
+            Self::CaseInsensitiveLiteral(literal) => {
+                f.write_str("[case_insensitive [")?;
+                write_literal(f, literal)?;
+                f.write_str("]]")
+            }
+            Self::Component(component) => component.fmt(f),
+            Self::Compound(items) => items.iter().try_for_each(|item| item.fmt(f)),
+            Self::Optional(item) => write!(f, "[optional [{}]]", item),
+            Self::First(items) => {
+                f.write_str("[first")?;
+                for item in &**items {
+                    write!(f, " [{}]", item)?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+impl From<&FormatItem<'_>> for OwnedFormatItem {
+    fn from(item: &FormatItem<'_>) -> Self {
+        match *item {
+            FormatItem::Literal(literal) => Self::Literal(literal.into()),
+            FormatItem::CaseInsensitiveLiteral(literal) => {
+                Self::CaseInsensitiveLiteral(literal.into())
+            }
+            FormatItem::Component(component) => Self::Component(component),
+            FormatItem::Compound(items) => items.into(),
+            FormatItem::Optional(item) => Self::Optional(Box::new(item.into())),
+            FormatItem::First(items) => Self::First(items.iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<FormatItem<'_>> for OwnedFormatItem {
+    fn from(item: FormatItem<'_>) -> Self {
+        (&item).into()
+    }
+}
+
+impl From<&[FormatItem<'_>]> for OwnedFormatItem {
+    fn from(items: &[FormatItem<'_>]) -> Self {
+        Self::Compound(items.iter().map(Into::into).collect())
+    }
+}
+
+impl From<Vec<FormatItem<'_>>> for OwnedFormatItem {
+    fn from(items: Vec<FormatItem<'_>>) -> Self {
+        items.as_slice().into()
+    }
+}
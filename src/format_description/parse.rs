@@ -1,10 +1,11 @@
 //! Parse a format description into a standardized representation.
 
+use alloc::boxed::Box;
 use alloc::vec::Vec;
 
 use crate::error::InvalidFormatDescription;
 use crate::format_description::component::{Component, NakedComponent};
-use crate::format_description::{helper, modifier, FormatItem};
+use crate::format_description::{helper, modifier, FormatItem, OwnedFormatItem, Version};
 
 /// The item parsed and remaining chunk of the format description after one iteration.
 #[derive(Debug)]
@@ -54,12 +55,12 @@ fn parse_item<'a>(
     if let [b'[', b'[', remaining @ ..] = s {
         *index += 2;
         return Ok(ParsedItem {
-            item: FormatItem::Literal(&[b'[']),
+            item: FormatItem::Literal(b"["),
             remaining,
         });
-    };
+    }
 
-    if s.starts_with(&[b'[']) {
+    if s.starts_with(b"[") {
         if let Some(bracket_index) = s.iter().position(|&c| c == b']') {
             *index += 1; // opening bracket
             let ret_val = ParsedItem {
@@ -77,6 +78,9 @@ fn parse_item<'a>(
 }
 
 /// Parse a sequence of items from the format description.
+///
+/// This uses [version 1](Version::V1) of the syntax. To use a different version, use
+/// [`parse_borrowed`] or [`parse_owned`].
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
 pub fn parse(s: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
     let mut compound = Vec::new();
@@ -92,3 +96,330 @@ pub fn parse(s: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
 
     Ok(compound)
 }
+
+// region: version 2
+/// An item of a version 2 format description, which may contain nested items.
+enum NestedItem<'a> {
+    /// Bytes that are formatted as-is.
+    Literal(&'a [u8]),
+    /// Bytes that are formatted as-is and parsed without regard to case. Escape sequences split
+    /// the bytes into multiple chunks.
+    CaseInsensitiveLiteral(Vec<&'a [u8]>),
+    /// A single component.
+    Component(Component),
+    /// An optional sequence of items.
+    Optional {
+        /// The items that may be present.
+        items: Vec<Self>,
+        /// The zero-based index of the opening bracket.
+        index: usize,
+    },
+    /// A number of alternative sequences of items.
+    First {
+        /// The alternatives, in the order they are tried.
+        alternatives: Vec<Vec<Self>>,
+        /// The zero-based index of the opening bracket.
+        index: usize,
+    },
+}
+
+/// Parse a sequence of items in a version 2 format description. Parsing stops at the end of the
+/// input or, if the sequence is nested, at the closing bracket that ends it. If `literals_only` is
+/// set, any bracketed item is an error.
+#[allow(clippy::unnested_or_patterns)]
+fn parse_nested_items<'a>(
+    mut s: &'a [u8],
+    index: &mut usize,
+    is_nested: bool,
+    literals_only: bool,
+) -> Result<(Vec<NestedItem<'a>>, &'a [u8]), InvalidFormatDescription> {
+    let mut items = Vec::new();
+
+    loop {
+        match s {
+            [] => return Ok((items, s)),
+            [b']', ..] if is_nested => return Ok((items, s)),
+            [b']', ..] => {
+                return Err(InvalidFormatDescription::UnexpectedClosingBracket { index: *index });
+            }
+            [b'\\', escaped @ b'\\', ..]
+            | [b'\\', escaped @ b'[', ..]
+            | [b'\\', escaped @ b']', ..] => {
+                items.push(NestedItem::Literal(core::slice::from_ref(escaped)));
+                s = &s[2..];
+                *index += 2;
+            }
+            [b'\\', ..] => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "valid escape sequence",
+                    index: *index,
+                });
+            }
+            [b'[', ..] if literals_only => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "literal",
+                    index: *index,
+                });
+            }
+            [b'[', ..] => {
+                let (item, remaining) = parse_bracketed_item(s, index)?;
+                items.push(item);
+                s = remaining;
+            }
+            _ => {
+                let loc = s
+                    .iter()
+                    .position(|&c| matches!(c, b'[' | b']' | b'\\'))
+                    .unwrap_or(s.len());
+                items.push(NestedItem::Literal(&s[..loc]));
+                s = &s[loc..];
+                *index += loc;
+            }
+        }
+    }
+}
+
+/// Parse the nested format descriptions following `optional`, `first`, or `case_insensitive`, up
+/// to and including the closing bracket of the item. At least one nested format description must
+/// be present.
+fn parse_alternatives<'a>(
+    mut s: &'a [u8],
+    index: &mut usize,
+    opening_bracket_index: usize,
+    allow_multiple: bool,
+    literals_only: bool,
+) -> Result<(Vec<Vec<NestedItem<'a>>>, &'a [u8]), InvalidFormatDescription> {
+    let mut alternatives = Vec::new();
+
+    loop {
+        s = helper::consume_whitespace(s, index);
+        match s {
+            [] => {
+                return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: opening_bracket_index,
+                });
+            }
+            [b']', remaining @ ..] if !alternatives.is_empty() => {
+                *index += 1;
+                return Ok((alternatives, remaining));
+            }
+            [b'[', ..] if !allow_multiple && !alternatives.is_empty() => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "closing bracket",
+                    index: *index,
+                });
+            }
+            [b'[', remaining @ ..] => {
+                let nested_index = *index;
+                *index += 1;
+                let (items, remaining) = parse_nested_items(remaining, index, true, literals_only)?;
+                match remaining {
+                    [b']', remaining @ ..] => {
+                        *index += 1;
+                        s = remaining;
+                    }
+                    _ => {
+                        return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                            index: nested_index,
+                        });
+                    }
+                }
+                alternatives.push(items);
+            }
+            _ => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "opening bracket",
+                    index: *index,
+                });
+            }
+        }
+    }
+}
+
+/// Parse an item starting with an opening bracket in a version 2 format description. This is
+/// either a component, a case-insensitive literal, or a group of nested format descriptions.
+fn parse_bracketed_item<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+) -> Result<(NestedItem<'a>, &'a [u8]), InvalidFormatDescription> {
+    let opening_bracket_index = *index;
+    let mut name_index = *index + 1;
+    let after_whitespace = helper::consume_whitespace(&s[1..], &mut name_index);
+    let name_len = after_whitespace
+        .iter()
+        .position(|&c| c.is_ascii_whitespace() || c == b'[' || c == b']')
+        .unwrap_or(after_whitespace.len());
+
+    match &after_whitespace[..name_len] {
+        b"optional" => {
+            *index = name_index + name_len;
+            let (mut alternatives, remaining) = parse_alternatives(
+                &after_whitespace[name_len..],
+                index,
+                opening_bracket_index,
+                false,
+                false,
+            )?;
+            Ok((
+                NestedItem::Optional {
+                    items: alternatives.remove(0),
+                    index: opening_bracket_index,
+                },
+                remaining,
+            ))
+        }
+        b"first" => {
+            *index = name_index + name_len;
+            let (alternatives, remaining) = parse_alternatives(
+                &after_whitespace[name_len..],
+                index,
+                opening_bracket_index,
+                true,
+                false,
+            )?;
+            Ok((
+                NestedItem::First {
+                    alternatives,
+                    index: opening_bracket_index,
+                },
+                remaining,
+            ))
+        }
+        b"case_insensitive" => {
+            *index = name_index + name_len;
+            let (mut alternatives, remaining) = parse_alternatives(
+                &after_whitespace[name_len..],
+                index,
+                opening_bracket_index,
+                false,
+                true,
+            )?;
+            let literals = alternatives
+                .remove(0)
+                .into_iter()
+                .filter_map(|item| match item {
+                    NestedItem::Literal(literal) => Some(literal),
+                    // Only literals are permitted, so nothing else is present.
+                    _ => None,
+                })
+                .collect();
+            Ok((NestedItem::CaseInsensitiveLiteral(literals), remaining))
+        }
+        _ => {
+            let bracket_index = s.iter().position(|&c| c == b']').ok_or(
+                InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: opening_bracket_index,
+                },
+            )?;
+            *index += 1; // opening bracket
+            let component = parse_component(&s[1..bracket_index], index)?;
+            *index = opening_bracket_index + bracket_index + 1;
+            Ok((NestedItem::Component(component), &s[bracket_index + 1..]))
+        }
+    }
+}
+
+/// Convert items parsed from a version 2 format description into borrowed items. Optional and
+/// alternative items cannot be represented without allocating, so they are not supported.
+fn into_borrowed(
+    items: Vec<NestedItem<'_>>,
+) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
+    let mut borrowed = Vec::with_capacity(items.len());
+    for item in items {
+        match item {
+            NestedItem::Literal(literal) => borrowed.push(FormatItem::Literal(literal)),
+            // Matching each chunk in turn is equivalent to matching the concatenated bytes.
+            NestedItem::CaseInsensitiveLiteral(literals) => {
+                borrowed.extend(literals.into_iter().map(FormatItem::CaseInsensitiveLiteral));
+            }
+            NestedItem::Component(component) => borrowed.push(FormatItem::Component(component)),
+            NestedItem::Optional { index, .. } => {
+                return Err(InvalidFormatDescription::NotSupported {
+                    what: "optional item",
+                    context: "borrowed format descriptions",
+                    index,
+                });
+            }
+            NestedItem::First { index, .. } => {
+                return Err(InvalidFormatDescription::NotSupported {
+                    what: "first item",
+                    context: "borrowed format descriptions",
+                    index,
+                });
+            }
+        }
+    }
+    Ok(borrowed)
+}
+
+/// Convert items parsed from a version 2 format description into a single owned item.
+fn into_owned(items: Vec<NestedItem<'_>>) -> OwnedFormatItem {
+    OwnedFormatItem::Compound(
+        items
+            .into_iter()
+            .map(|item| match item {
+                NestedItem::Literal(literal) => OwnedFormatItem::Literal(literal.into()),
+                NestedItem::CaseInsensitiveLiteral(literals) => {
+                    OwnedFormatItem::CaseInsensitiveLiteral(literals.concat().into())
+                }
+                NestedItem::Component(component) => OwnedFormatItem::Component(component),
+                NestedItem::Optional { items, .. } => {
+                    OwnedFormatItem::Optional(Box::new(into_owned(items)))
+                }
+                NestedItem::First { alternatives, .. } => {
+                    OwnedFormatItem::First(alternatives.into_iter().map(into_owned).collect())
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Parse a version 2 format description.
+fn parse_v2(s: &str) -> Result<Vec<NestedItem<'_>>, InvalidFormatDescription> {
+    let (items, _) = parse_nested_items(s.as_bytes(), &mut 0, false, false)?;
+    Ok(items)
+}
+// endregion version 2
+
+/// Parse a sequence of items from the format description using the provided version of the
+/// syntax.
+///
+/// The returned items borrow from the format description. As a result, optional and alternative
+/// items are not supported; use [`parse_owned`] if they are needed.
+///
+/// ```rust
+/// # use time::{format_description::{self, Version}, macros::time};
+/// let format = format_description::parse_borrowed(Version::V2, r"\[[hour]:[minute]\]")?;
+/// assert_eq!(time!(12:05).format(&format)?, "[12:05]");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse_borrowed(
+    version: Version,
+    s: &str,
+) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
+    match version {
+        Version::V1 => parse(s),
+        Version::V2 => into_borrowed(parse_v2(s)?),
+    }
+}
+
+/// Parse the format description into an owned item using the provided version of the syntax.
+///
+/// ```rust
+/// # use time::{format_description::{self, Version}, macros::time, Time};
+/// let format = format_description::parse_owned(
+///     Version::V2,
+///     "[hour]:[minute][optional [:[second]]]",
+/// )?;
+/// assert_eq!(time!(12:05:30).format(&format)?, "12:05:30");
+/// assert_eq!(Time::parse("12:05", &format)?, time!(12:05));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse_owned(version: Version, s: &str) -> Result<OwnedFormatItem, InvalidFormatDescription> {
+    match version {
+        Version::V1 => parse(s).map(Into::into),
+        Version::V2 => parse_v2(s).map(into_owned),
+    }
+}
@@ -0,0 +1,93 @@
+//! A lazily formatted value.
+
+use core::fmt;
+
+use crate::error;
+use crate::formatting::output::{DiscardOutput, FmtOutput};
+use crate::formatting::formattable::Zone;
+use crate::formatting::Formattable;
+use crate::{Date, Time, UtcOffset};
+
+/// A value that is formatted using a format description when displayed.
+///
+/// This is returned by the `display` and `try_display` methods on the various types, such as
+/// [`Date::display`]. No allocation is performed when displaying the value, so it can be passed
+/// directly to `write!`, `format_args!`, and similar.
+///
+/// If the value cannot be formatted, [`fmt::Error`] is returned by the [`fmt::Display`]
+/// implementation. Values obtained via `try_display` have already been checked, so any error
+/// originates from the underlying writer.
+#[derive(Debug)]
+pub struct Display<'a, F: Formattable + ?Sized> {
+    /// The format description.
+    format: &'a F,
+    /// The date to format, if any.
+    date: Option<Date>,
+    /// The time to format, if any.
+    time: Option<Time>,
+    /// The UTC offset to format, if any.
+    offset: Option<UtcOffset>,
+    /// The time zone to format, if any.
+    zone: Option<Zone<'a>>,
+}
+
+impl<'a, F: Formattable + ?Sized> Display<'a, F> {
+    /// Create a value that is formatted using the format description when displayed.
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    pub(crate) fn new(
+        format: &'a F,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Self {
+        Self {
+            format,
+            date,
+            time,
+            offset,
+            zone: None,
+        }
+    }
+
+    /// Include the time zone when formatting the value.
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    pub(crate) fn with_zone(mut self, zone: Zone<'a>) -> Self {
+        self.zone = Some(zone);
+        self
+    }
+
+    /// Ensure that the value can be formatted, returning the error that would otherwise occur.
+    pub(crate) fn validate(self) -> Result<Self, error::Format> {
+        self.format.format_into_zoned(
+            &mut DiscardOutput,
+            self.date,
+            self.time,
+            self.offset,
+            self.zone,
+        )?;
+        Ok(self)
+    }
+}
+
+impl<F: Formattable + ?Sized> Clone for Display<'_, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Formattable + ?Sized> Copy for Display<'_, F> {}
+
+impl<F: Formattable + ?Sized> fmt::Display for Display<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.format.format_into_zoned(
+            &mut FmtOutput(f),
+            self.date,
+            self.time,
+            self.offset,
+            self.zone,
+        ) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(fmt::Error),
+        }
+    }
+}
@@ -1,20 +1,42 @@
 //! A trait that can be used to format an item from its components.
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::ops::Deref;
-use std::io;
 
 #[allow(unused_imports)]
 use standback::prelude::*;
 
 use crate::format_description::well_known::Rfc3339;
-use crate::format_description::FormatItem;
-use crate::formatting::{format_component, format_number_pad_zero};
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::format_description::{offset_remainder_len, Component, FormatItem, SequenceItem};
+use crate::formatting::output::Output;
+use crate::formatting::{format_component, format_number_pad_zero, subsecond_is_omitted};
 use crate::{error, Date, Time, UtcOffset};
 
+/// The name and abbreviation of a time zone.
+///
+/// This type is `pub` only so that it can appear in the sealed trait. It is not reachable from
+/// outside the crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Zone<'a> {
+    /// The name of the time zone in the IANA time zone database.
+    pub(crate) name: &'a str,
+    /// The abbreviation of the time zone in effect.
+    pub(crate) abbreviation: &'a str,
+}
+
 /// A type that can be formatted.
 pub trait Formattable: sealed::Sealed {}
 impl Formattable for FormatItem<'_> {}
 impl Formattable for [FormatItem<'_>] {}
+#[cfg(feature = "alloc")]
+impl Formattable for OwnedFormatItem {}
+#[cfg(feature = "alloc")]
+impl Formattable for [OwnedFormatItem] {}
 impl Formattable for Rfc3339 {}
 #[allow(clippy::use_self)]
 impl<T: Deref> Formattable for T where T::Target: Formattable {}
@@ -30,13 +52,27 @@ mod sealed {
         /// Format the item into the provided output, returning the number of bytes written.
         fn format_into(
             &self,
-            output: &mut impl io::Write,
+            output: &mut impl Output,
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
+        ) -> Result<usize, error::Format> {
+            self.format_into_zoned(output, date, time, offset, None)
+        }
+
+        /// Format the item into the provided output along with the time zone, returning the number
+        /// of bytes written.
+        fn format_into_zoned(
+            &self,
+            output: &mut impl Output,
+            date: Option<Date>,
+            time: Option<Time>,
+            offset: Option<UtcOffset>,
+            zone: Option<Zone<'_>>,
         ) -> Result<usize, error::Format>;
 
         /// Format the item directly to a `String`.
+        #[cfg(feature = "alloc")]
         fn format(
             &self,
             date: Option<Date>,
@@ -45,7 +81,6 @@ mod sealed {
         ) -> Result<String, error::Format> {
             let mut buf = Vec::new();
             self.format_into(&mut buf, date, time, offset)?;
-            io::Write::flush(&mut buf)?;
             Ok(String::from_utf8_lossy(&buf).into_owned())
         }
     }
@@ -53,35 +88,121 @@ mod sealed {
 
 // region: custom formats
 impl<'a> sealed::Sealed for FormatItem<'a> {
-    fn format_into(
+    fn format_into_zoned(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        zone: Option<Zone<'_>>,
     ) -> Result<usize, error::Format> {
         Ok(match *self {
-            Self::Literal(literal) => output.write(literal)?,
-            Self::Component(component) => format_component(output, component, date, time, offset)?,
-            Self::Compound(items) => items.format_into(output, date, time, offset)?,
+            Self::Literal(literal) | Self::CaseInsensitiveLiteral(literal) => {
+                output.write(literal)?
+            }
+            Self::Component(component) => {
+                format_component(output, component, date, time, offset, zone)?
+            }
+            Self::Compound(items) => items.format_into_zoned(output, date, time, offset, zone)?,
+            Self::Optional(item) => item.format_into_zoned(output, date, time, offset, zone)?,
+            Self::First(items) => match items {
+                [item, ..] => item.format_into_zoned(output, date, time, offset, zone)?,
+                [] => 0,
+            },
         })
     }
 }
 
 impl<'a> sealed::Sealed for [FormatItem<'a>] {
-    fn format_into(
+    fn format_into_zoned(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        zone: Option<Zone<'_>>,
     ) -> Result<usize, error::Format> {
-        let mut bytes = 0;
-        for item in self.iter() {
-            bytes += item.format_into(output, date, time, offset)?;
+        format_sequence(self, output, date, time, offset, zone)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for OwnedFormatItem {
+    fn format_into_zoned(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        zone: Option<Zone<'_>>,
+    ) -> Result<usize, error::Format> {
+        Ok(match self {
+            Self::Literal(literal) | Self::CaseInsensitiveLiteral(literal) => {
+                output.write(literal)?
+            }
+            Self::Component(component) => {
+                format_component(output, *component, date, time, offset, zone)?
+            }
+            Self::Compound(items) => items.format_into_zoned(output, date, time, offset, zone)?,
+            Self::Optional(item) => item.format_into_zoned(output, date, time, offset, zone)?,
+            Self::First(items) => match items.first() {
+                Some(item) => item.format_into_zoned(output, date, time, offset, zone)?,
+                None => 0,
+            },
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for [OwnedFormatItem] {
+    fn format_into_zoned(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        zone: Option<Zone<'_>>,
+    ) -> Result<usize, error::Format> {
+        format_sequence(self, output, date, time, offset, zone)
+    }
+}
+
+/// Format a sequence of items, omitting any items that are not present due to the value of a
+/// preceding item.
+fn format_sequence<T: SequenceItem + sealed::Sealed>(
+    items: &[T],
+    output: &mut impl Output,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+    zone: Option<Zone<'_>>,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+    let mut items = items;
+    while let [item, remaining @ ..] = items {
+        items = remaining;
+
+        // A separator is omitted along with the subsecond following it.
+        if item.is_subsecond_separator() {
+            if let (Some(time), Some(Component::Subsecond(modifier))) =
+                (time, remaining.first().and_then(SequenceItem::component))
+            {
+                if subsecond_is_omitted(time, modifier) {
+                    items = &remaining[1..];
+                    continue;
+                }
+            }
+        }
+
+        bytes += item.format_into_zoned(output, date, time, offset, zone)?;
+
+        // The remainder of the offset is omitted when `Z` was written in place of the hour.
+        let offset_is_utc = matches!(offset, Some(offset) if offset.is_utc());
+        if item.is_offset_hour_z_for_utc() && offset_is_utc {
+            items = &remaining[offset_remainder_len(remaining)..];
         }
-        Ok(bytes)
     }
+    Ok(bytes)
 }
 
 #[allow(clippy::use_self)]
@@ -89,26 +210,29 @@ impl<T: Deref> sealed::Sealed for T
 where
     T::Target: sealed::Sealed,
 {
-    fn format_into(
+    fn format_into_zoned(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        zone: Option<Zone<'_>>,
     ) -> Result<usize, error::Format> {
-        self.deref().format_into(output, date, time, offset)
+        self.deref()
+            .format_into_zoned(output, date, time, offset, zone)
     }
 }
 // endregion custom formats
 
 // region: well-known formats
 impl sealed::Sealed for Rfc3339 {
-    fn format_into(
+    fn format_into_zoned(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        _: Option<Zone<'_>>,
     ) -> Result<usize, error::Format> {
         let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
         let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
@@ -126,19 +250,19 @@ impl sealed::Sealed for Rfc3339 {
         }
 
         bytes += format_number_pad_zero(output, year as u32, 4)?;
-        bytes += output.write(&[b'-'])?;
+        bytes += output.write(b"-")?;
         bytes += format_number_pad_zero(output, date.month() as u8, 2)?;
-        bytes += output.write(&[b'-'])?;
+        bytes += output.write(b"-")?;
         bytes += format_number_pad_zero(output, date.day(), 2)?;
-        bytes += output.write(&[b'T'])?;
+        bytes += output.write(b"T")?;
         bytes += format_number_pad_zero(output, time.hour(), 2)?;
-        bytes += output.write(&[b':'])?;
+        bytes += output.write(b":")?;
         bytes += format_number_pad_zero(output, time.minute(), 2)?;
-        bytes += output.write(&[b':'])?;
+        bytes += output.write(b":")?;
         bytes += format_number_pad_zero(output, time.second(), 2)?;
 
         if time.nanosecond() != 0 {
-            bytes += output.write(&[b'.'])?;
+            bytes += output.write(b".")?;
 
             let (value, width) = match time.nanosecond() {
                 nanos if nanos % 10 != 0 => (nanos, 9),
@@ -155,17 +279,13 @@ impl sealed::Sealed for Rfc3339 {
         }
 
         if offset == UtcOffset::UTC {
-            bytes += output.write(&[b'Z'])?;
+            bytes += output.write(b"Z")?;
             return Ok(bytes);
         }
 
-        bytes += output.write(if offset.is_negative() {
-            &[b'-']
-        } else {
-            &[b'+']
-        })?;
+        bytes += output.write(if offset.is_negative() { b"-" } else { b"+" })?;
         bytes += format_number_pad_zero(output, offset.whole_hours().unsigned_abs(), 2)?;
-        bytes += output.write(&[b':'])?;
+        bytes += output.write(b":")?;
         bytes += format_number_pad_zero(output, offset.minutes_past_hour().unsigned_abs(), 2)?;
 
         Ok(bytes)
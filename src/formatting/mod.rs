@@ -1,42 +1,19 @@
 //! Formatting for various types.
 
+mod display;
 pub(crate) mod formattable;
-
-use std::io;
+pub(crate) mod output;
 
 #[allow(unused_imports)]
 use standback::prelude::*;
 
+pub use self::display::Display;
 pub use self::formattable::Formattable;
-use crate::format_description::{modifier, Component};
-use crate::{error, Date, Time, UtcOffset};
-
-#[allow(clippy::missing_docs_in_private_items)]
-const MONTH_NAMES: [&[u8]; 12] = [
-    b"January",
-    b"February",
-    b"March",
-    b"April",
-    b"May",
-    b"June",
-    b"July",
-    b"August",
-    b"September",
-    b"October",
-    b"November",
-    b"December",
-];
-
-#[allow(clippy::missing_docs_in_private_items)]
-const WEEKDAY_NAMES: [&[u8]; 7] = [
-    b"Monday",
-    b"Tuesday",
-    b"Wednesday",
-    b"Thursday",
-    b"Friday",
-    b"Saturday",
-    b"Sunday",
-];
+use self::formattable::Zone;
+use self::output::Output;
+use crate::format_description::well_known::Rfc3339;
+use crate::format_description::{modifier, Component, FormatItem};
+use crate::{error, util, Date, Time, UtcOffset};
 
 // region: extension trait
 /// A trait that indicates the formatted width of the value can be determined.
@@ -104,31 +81,41 @@ impl DigitCount for u32 {
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     value: impl itoa::Integer + DigitCount + Copy,
     padding: modifier::Padding,
     width: u8,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     match padding {
         modifier::Padding::Space => format_number_pad_space(output, value, width),
         modifier::Padding::Zero => format_number_pad_zero(output, value, width),
-        modifier::Padding::None => itoa::write(output, value),
+        modifier::Padding::None => format_number_unpadded(output, value),
     }
 }
 
+/// Format a number without any padding.
+///
+/// The sign must be written by the caller.
+fn format_number_unpadded(
+    output: &mut impl Output,
+    value: impl itoa::Integer,
+) -> Result<usize, error::Format> {
+    output.write(itoa::Buffer::new().format(value).as_bytes())
+}
+
 /// Format a number with the provided width and spaces as padding.
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number_pad_space(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     value: impl itoa::Integer + DigitCount + Copy,
     width: u8,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let mut bytes = 0;
     for _ in 0..(width.saturating_sub(value.num_digits())) {
-        bytes += output.write(&[b' '])?;
+        bytes += output.write(b" ")?;
     }
-    bytes += itoa::write(output, value)?;
+    bytes += format_number_unpadded(output, value)?;
     Ok(bytes)
 }
 
@@ -136,101 +123,274 @@ pub(crate) fn format_number_pad_space(
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number_pad_zero(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     value: impl itoa::Integer + DigitCount + Copy,
     width: u8,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let mut bytes = 0;
     for _ in 0..(width.saturating_sub(value.num_digits())) {
-        bytes += output.write(&[b'0'])?;
+        bytes += output.write(b"0")?;
     }
-    bytes += itoa::write(output, value)?;
+    bytes += format_number_unpadded(output, value)?;
     Ok(bytes)
 }
 
 /// Format the provided component into the designated output. An `Err` will be returned if the
 /// component requires information that it does not provide or if the value cannot be output to the
 /// stream.
+#[allow(clippy::unnested_or_patterns)]
 pub(crate) fn format_component(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     component: Component,
     date: Option<Date>,
     time: Option<Time>,
     offset: Option<UtcOffset>,
+    zone: Option<Zone<'_>>,
 ) -> Result<usize, error::Format> {
     use Component::*;
-    Ok(match (component, date, time, offset) {
+    Ok(match (component, date, time, offset, zone) {
         (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier)?,
         (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier)?,
         (Ordinal(modifier), Some(date), ..) => fmt_ordinal(output, date, modifier)?,
         (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier)?,
         (WeekNumber(modifier), Some(date), ..) => fmt_week_number(output, date, modifier)?,
         (Year(modifier), Some(date), ..) => fmt_year(output, date, modifier)?,
-        (Hour(modifier), _, Some(time), _) => fmt_hour(output, time, modifier)?,
-        (Minute(modifier), _, Some(time), _) => fmt_minute(output, time, modifier)?,
-        (Period(modifier), _, Some(time), _) => fmt_period(output, time, modifier)?,
-        (Second(modifier), _, Some(time), _) => fmt_second(output, time, modifier)?,
-        (Subsecond(modifier), _, Some(time), _) => fmt_subsecond(output, time, modifier)?,
-        (OffsetHour(modifier), .., Some(offset)) => fmt_offset_hour(output, offset, modifier)?,
-        (OffsetMinute(modifier), .., Some(offset)) => fmt_offset_minute(output, offset, modifier)?,
-        (OffsetSecond(modifier), .., Some(offset)) => fmt_offset_second(output, offset, modifier)?,
+        (Quarter(modifier), Some(date), ..) => fmt_quarter(output, date, modifier)?,
+        (Century(modifier), Some(date), ..) => fmt_century(output, date, modifier)?,
+        (Era(modifier), Some(date), ..) => fmt_era(output, date, modifier)?,
+        (Hour(modifier), _, Some(time), ..) => fmt_hour(output, time, modifier)?,
+        (Minute(modifier), _, Some(time), ..) => fmt_minute(output, time, modifier)?,
+        (Period(modifier), _, Some(time), ..) => fmt_period(output, time, modifier)?,
+        (Second(modifier), _, Some(time), ..) => fmt_second(output, time, modifier)?,
+        (Subsecond(modifier), _, Some(time), ..) => fmt_subsecond(output, time, modifier)?,
+        (OffsetHour(modifier), .., Some(offset), _) => fmt_offset_hour(output, offset, modifier)?,
+        (OffsetMinute(modifier), .., Some(offset), _) => {
+            fmt_offset_minute(output, offset, modifier)?
+        }
+        (OffsetSecond(modifier), .., Some(offset), _) => {
+            fmt_offset_second(output, offset, modifier)?
+        }
+        (Offset(modifier), .., Some(offset), _) => fmt_offset(output, offset, modifier)?,
+        (TimeZone(modifier), .., Some(zone)) => fmt_time_zone(output, zone, modifier)?,
+        (Whitespace(_), ..) => output.write(b" ")?,
+        (Ignore(_), ..) | (End(_), ..) => 0,
         _ => return Err(error::Format::InsufficientTypeInformation),
     })
 }
 
+// region: length bounds
+/// The maximum number of bytes that formatting a value using the provided items can produce. As
+/// this is a `const fn`, the result can be used to size a buffer at compile time.
+///
+/// ```rust
+/// # use time::{formatting::max_formatted_len, macros::{date, format_description}};
+/// const FORMAT: &[time::format_description::FormatItem<'_>] =
+///     format_description!("[year]-[month]-[day]");
+/// let mut buf = [0; max_formatted_len(FORMAT)];
+/// assert_eq!(date!(2021 - 01 - 02).format_to_buf(&mut buf, &FORMAT)?, "2021-01-02");
+/// # Ok::<_, time::Error>(())
+/// ```
+pub const fn max_formatted_len(items: &[FormatItem<'_>]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < items.len() {
+        len += items[i].max_formatted_len();
+        i += 1;
+    }
+    len
+}
+
+impl FormatItem<'_> {
+    /// The maximum number of bytes that formatting a value using the item can produce. As this is
+    /// a `const fn`, the result can be used to size a buffer at compile time.
+    pub const fn max_formatted_len(&self) -> usize {
+        match self {
+            Self::Literal(literal) | Self::CaseInsensitiveLiteral(literal) => literal.len(),
+            Self::Component(component) => component_max_len(*component),
+            Self::Compound(items) => max_formatted_len(items),
+            Self::Optional(item) => item.max_formatted_len(),
+            // Only the first item is used when formatting.
+            Self::First(items) => {
+                if items.is_empty() {
+                    0
+                } else {
+                    items[0].max_formatted_len()
+                }
+            }
+        }
+    }
+}
+
+impl Rfc3339 {
+    /// The maximum number of bytes that formatting a value as RFC 3339 can produce. As this is a
+    /// `const fn`, the result can be used to size a buffer at compile time.
+    pub const fn max_formatted_len(self) -> usize {
+        // YYYY-MM-DDTHH:MM:SS.SSSSSSSSS+HH:MM
+        35
+    }
+}
+
+/// The maximum number of bytes that formatting the component can produce.
+const fn component_max_len(component: Component) -> usize {
+    match component {
+        Component::Month(modifier::Month {
+            repr,
+            locale,
+            standalone,
+            ..
+        }) => match repr {
+            modifier::MonthRepr::Numerical => 2,
+            modifier::MonthRepr::Long => locale.max_month_len(false, standalone),
+            modifier::MonthRepr::Short => locale.max_month_len(true, standalone),
+        },
+        Component::Weekday(modifier::Weekday { repr, locale, .. }) => match repr {
+            modifier::WeekdayRepr::Long => locale.max_weekday_len(false),
+            modifier::WeekdayRepr::Short => locale.max_weekday_len(true),
+            modifier::WeekdayRepr::Sunday | modifier::WeekdayRepr::Monday => 1,
+        },
+        Component::Year(modifier::Year { repr, width, .. }) => {
+            let max_digits = if cfg!(feature = "large-dates") { 7 } else { 5 };
+            match repr {
+                // The sign is included.
+                modifier::YearRepr::Full => max_len_with_width(max_digits, width, 1),
+                // The earliest year before the common era has an additional digit.
+                modifier::YearRepr::OfEra => max_len_with_width(max_digits, width, 0),
+                modifier::YearRepr::LastTwo => max_len_with_width(2, width, 0),
+            }
+        }
+        Component::OffsetHour(modifier::OffsetHour { width, .. }) => {
+            // The sign is included.
+            max_len_with_width(3, width, 1)
+        }
+        Component::Century(modifier::Century { width, .. }) => {
+            // The sign is included. A century wider than its width is not formatted, so large
+            // dates do not need additional room.
+            max_len_with_width(3, width, 1)
+        }
+        Component::Day(modifier::Day { ordinal_suffix, .. }) => {
+            if ordinal_suffix { 4 } else { 2 }
+        }
+        Component::Quarter(modifier::Quarter { repr }) => match repr {
+            modifier::QuarterRepr::Numerical => 1,
+            modifier::QuarterRepr::Short => 2,
+        },
+        Component::Era(modifier::Era { repr, .. }) => match repr {
+            modifier::EraRepr::Ad => 2,
+            modifier::EraRepr::Ce => 3,
+        },
+        Component::Period(modifier::Period { locale, .. }) => locale.max_period_len(),
+        Component::TimeZone(modifier::TimeZone { repr }) => repr.max_len(),
+        Component::Ignore(_) | Component::End(_) => 0,
+        Component::Whitespace(_) => 1,
+        Component::Ordinal(_) => 3,
+        Component::Subsecond(modifier::Subsecond { digits, .. }) => match digits {
+            modifier::SubsecondDigits::One => 1,
+            modifier::SubsecondDigits::Two => 2,
+            modifier::SubsecondDigits::Three => 3,
+            modifier::SubsecondDigits::Four => 4,
+            modifier::SubsecondDigits::Five => 5,
+            modifier::SubsecondDigits::Six => 6,
+            modifier::SubsecondDigits::Seven => 7,
+            modifier::SubsecondDigits::Eight => 8,
+            modifier::SubsecondDigits::Nine | modifier::SubsecondDigits::OneOrMore => 9,
+        },
+        Component::WeekNumber(_)
+        | Component::Hour(_)
+        | Component::Minute(_)
+        | Component::Second(_)
+        | Component::OffsetMinute(_)
+        | Component::OffsetSecond(_) => 2,
+        Component::Offset(modifier::Offset {
+            colon_separated,
+            minute,
+            second,
+            ..
+        }) => {
+            let part_len = if colon_separated { 3 } else { 2 };
+            match (minute, second) {
+                (modifier::Presence::Omitted, _) => 3,
+                (_, modifier::Presence::Omitted) => 3 + part_len,
+                _ => 3 + 2 * part_len,
+            }
+        }
+    }
+}
+
+/// The maximum length of a component, accounting for an explicit minimum number of digits along
+/// with the sign that may precede them.
+const fn max_len_with_width(len: usize, width: Option<u8>, sign_len: usize) -> usize {
+    match width {
+        Some(width) if width as usize + sign_len > len => width as usize + sign_len,
+        _ => len,
+    }
+}
+// endregion length bounds
+
 // region: date formatters
 /// Format the day into the designated output.
 fn fmt_day(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
-    modifier::Day { padding }: modifier::Day,
-) -> Result<usize, io::Error> {
-    format_number(output, date.day(), padding, 2)
+    modifier::Day {
+        padding,
+        ordinal_suffix,
+    }: modifier::Day,
+) -> Result<usize, error::Format> {
+    let mut bytes = format_number(output, date.day(), padding, 2)?;
+    if ordinal_suffix {
+        bytes += output.write(util::ordinal_suffix(date.day()).as_bytes())?;
+    }
+    Ok(bytes)
 }
 
 /// Format the month into the designated output.
 fn fmt_month(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Month {
         padding,
         repr,
         case_sensitive: _case_sensitive, // no effect on formatting
+        locale,
+        standalone,
     }: modifier::Month,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
+    let index = date.month() as usize - 1;
     match repr {
         modifier::MonthRepr::Numerical => format_number(output, date.month() as u8, padding, 2),
-        modifier::MonthRepr::Long => output.write(MONTH_NAMES[date.month() as usize - 1]),
-        modifier::MonthRepr::Short => output.write(&MONTH_NAMES[date.month() as usize - 1][..3]),
+        modifier::MonthRepr::Long => {
+            output.write(locale.month_names(false, standalone)[index].as_bytes())
+        }
+        modifier::MonthRepr::Short => {
+            output.write(locale.month_names(true, standalone)[index].as_bytes())
+        }
     }
 }
 
 /// Format the ordinal into the designated output.
 fn fmt_ordinal(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Ordinal { padding }: modifier::Ordinal,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number(output, date.ordinal(), padding, 3)
 }
 
 /// Format the weekday into the designated output.
 fn fmt_weekday(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Weekday {
         repr,
         one_indexed,
         case_sensitive: _case_sensitive, // no effect on formatting
+        locale,
     }: modifier::Weekday,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
+    let index = date.weekday().number_days_from_monday() as usize;
     match repr {
-        modifier::WeekdayRepr::Short => {
-            output.write(&WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize][..3])
-        }
-        modifier::WeekdayRepr::Long => {
-            output.write(WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize])
-        }
+        modifier::WeekdayRepr::Short => output.write(locale.weekday_names(true)[index].as_bytes()),
+        modifier::WeekdayRepr::Long => output.write(locale.weekday_names(false)[index].as_bytes()),
         modifier::WeekdayRepr::Sunday => format_number(
             output,
             date.weekday().number_days_from_sunday() + one_indexed as u8,
@@ -248,10 +408,10 @@ fn fmt_weekday(
 
 /// Format the week number into the designated output.
 fn fmt_week_number(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::WeekNumber { padding, repr }: modifier::WeekNumber,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number(
         output,
         match repr {
@@ -266,22 +426,24 @@ fn fmt_week_number(
 
 /// Format the year into the designated output.
 fn fmt_year(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Year {
         padding,
         repr,
         iso_week_based,
         sign_is_mandatory,
+        width: min_width,
     }: modifier::Year,
-) -> Result<usize, io::Error> {
-    let full_year = if iso_week_based {
+) -> Result<usize, error::Format> {
+    let full_year = if iso_week_based && repr != modifier::YearRepr::OfEra {
         date.iso_year_week().0
     } else {
         date.year()
     };
     let value = match repr {
-        modifier::YearRepr::Full => full_year,
+        modifier::YearRepr::OfEra if full_year <= 0 => 1 - full_year,
+        modifier::YearRepr::Full | modifier::YearRepr::OfEra => full_year,
         modifier::YearRepr::LastTwo => (full_year % 100).abs(),
     };
     let width = match repr {
@@ -289,32 +451,94 @@ fn fmt_year(
         modifier::YearRepr::Full if value.abs() >= 100_000 => 6,
         #[cfg(feature = "large-dates")]
         modifier::YearRepr::Full if value.abs() >= 10_000 => 5,
-        modifier::YearRepr::Full => 4,
+        modifier::YearRepr::Full | modifier::YearRepr::OfEra => 4,
         modifier::YearRepr::LastTwo => 2,
     };
+    let width = min_width.unwrap_or(width);
     let mut bytes = 0;
-    if repr != modifier::YearRepr::LastTwo {
+    if repr == modifier::YearRepr::Full {
         if full_year < 0 {
-            bytes += output.write(&[b'-'])?;
+            bytes += output.write(b"-")?;
         } else if sign_is_mandatory || cfg!(feature = "large-dates") && full_year >= 10_000 {
-            bytes += output.write(&[b'+'])?;
+            bytes += output.write(b"+")?;
         }
     }
     bytes += format_number(output, value.unsigned_abs(), padding, width)?;
     Ok(bytes)
 }
+
+/// Format the quarter into the designated output.
+fn fmt_quarter(
+    output: &mut impl Output,
+    date: Date,
+    modifier::Quarter { repr }: modifier::Quarter,
+) -> Result<usize, error::Format> {
+    let quarter = (date.month() as u8 - 1) / 3 + 1;
+    let mut bytes = 0;
+    if repr == modifier::QuarterRepr::Short {
+        bytes += output.write(b"Q")?;
+    }
+    bytes += format_number(output, quarter, modifier::Padding::None, 1)?;
+    Ok(bytes)
+}
+
+/// Format the century into the designated output.
+fn fmt_century(
+    output: &mut impl Output,
+    date: Date,
+    modifier::Century {
+        padding,
+        sign_is_mandatory,
+        width,
+    }: modifier::Century,
+) -> Result<usize, error::Format> {
+    let year = date.year();
+    let century = (year / 100).unsigned_abs();
+    let width = width.unwrap_or(2);
+    // Exactly this many digits are read when parsing, so a wider century could not round-trip.
+    if century.num_digits() > width {
+        return Err(error::Format::InvalidComponent("century"));
+    }
+
+    let mut bytes = 0;
+    if year < 0 {
+        bytes += output.write(b"-")?;
+    } else if sign_is_mandatory {
+        bytes += output.write(b"+")?;
+    }
+    bytes += format_number(output, century, padding, width)?;
+    Ok(bytes)
+}
+
+/// Format the era into the designated output.
+fn fmt_era(
+    output: &mut impl Output,
+    date: Date,
+    modifier::Era {
+        repr,
+        case_sensitive: _case_sensitive, // no effect on formatting
+    }: modifier::Era,
+) -> Result<usize, error::Format> {
+    let is_bce = date.year() <= 0;
+    output.write(match (repr, is_bce) {
+        (modifier::EraRepr::Ad, false) => b"AD",
+        (modifier::EraRepr::Ad, true) => b"BC",
+        (modifier::EraRepr::Ce, false) => b"CE",
+        (modifier::EraRepr::Ce, true) => b"BCE",
+    })
+}
 // endregion date formatters
 
 // region: time formatters
 /// Format the hour into the designated output.
 fn fmt_hour(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Hour {
         padding,
         is_12_hour_clock,
     }: modifier::Hour,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     #[allow(clippy::unnested_or_patterns)]
     let value = match (time.hour(), is_12_hour_clock) {
         (hour, false) => hour,
@@ -327,46 +551,58 @@ fn fmt_hour(
 
 /// Format the minute into the designated output.
 fn fmt_minute(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Minute { padding }: modifier::Minute,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number(output, time.minute(), padding, 2)
 }
 
 /// Format the period into the designated output.
 fn fmt_period(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Period {
         is_uppercase,
         case_sensitive: _case_sensitive, // no effect on formatting
+        locale,
     }: modifier::Period,
-) -> Result<usize, io::Error> {
-    match (time.hour() >= 12, is_uppercase) {
-        (false, false) => output.write(b"am"),
-        (false, true) => output.write(b"AM"),
-        (true, false) => output.write(b"pm"),
-        (true, true) => output.write(b"PM"),
-    }
+) -> Result<usize, error::Format> {
+    output.write(locale.period_names(is_uppercase)[(time.hour() >= 12) as usize].as_bytes())
 }
 
 /// Format the second into the designated output.
 fn fmt_second(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Second { padding }: modifier::Second,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number(output, time.second(), padding, 2)
 }
 
 /// Format the subsecond into the designated output.
 fn fmt_subsecond(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
-    modifier::Subsecond { digits }: modifier::Subsecond,
-) -> Result<usize, io::Error> {
-    let (value, width) = match digits {
+    modifier: modifier::Subsecond,
+) -> Result<usize, error::Format> {
+    match subsecond_value(time, modifier) {
+        (_, 0) => Ok(0),
+        (value, width) => format_number_pad_zero(output, value, width),
+    }
+}
+
+/// Obtain the value and width of the subsecond as it is formatted. If trailing zeros are trimmed
+/// and there are no significant digits, the width is zero.
+const fn subsecond_value(
+    time: Time,
+    modifier::Subsecond {
+        digits,
+        trim_trailing_zeros,
+        rounding,
+    }: modifier::Subsecond,
+) -> (u32, u8) {
+    let (mut value, mut width) = match digits {
         modifier::SubsecondDigits::One => (time.nanosecond() / 100_000_000, 1),
         modifier::SubsecondDigits::Two => (time.nanosecond() / 10_000_000, 2),
         modifier::SubsecondDigits::Three => (time.nanosecond() / 1_000_000, 3),
@@ -388,36 +624,72 @@ fn fmt_subsecond(
             nanos => (nanos / 100_000_000, 1),
         },
     };
-    format_number_pad_zero(output, value, width)
+    if matches!(rounding, modifier::SubsecondRounding::NearestSaturating) {
+        // The number of nanoseconds represented by the last digit formatted.
+        let mut divisor = 1;
+        let mut i = width;
+        while i < 9 {
+            divisor *= 10;
+            i += 1;
+        }
+        // Rounding saturates rather than carrying over into the second.
+        if time.nanosecond() % divisor * 2 >= divisor && value < 1_000_000_000 / divisor - 1 {
+            value += 1;
+        }
+    }
+    if trim_trailing_zeros {
+        while width > 0 && value % 10 == 0 {
+            value /= 10;
+            width -= 1;
+        }
+    }
+    (value, width)
+}
+
+/// Whether the subsecond is omitted entirely, as trailing zeros are trimmed and there are no
+/// significant digits.
+pub(crate) const fn subsecond_is_omitted(time: Time, modifier: modifier::Subsecond) -> bool {
+    subsecond_value(time, modifier).1 == 0
 }
 // endregion time formatters
 
 // region: offset formatters
 /// Format the offset hour into the designated output.
 fn fmt_offset_hour(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetHour {
         padding,
         sign_is_mandatory,
+        z_for_utc,
+        width,
     }: modifier::OffsetHour,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
+    if z_for_utc && offset.is_utc() {
+        return output.write(b"Z");
+    }
+
     let mut bytes = 0;
     if offset.is_negative() {
-        bytes += output.write(&[b'-'])?;
+        bytes += output.write(b"-")?;
     } else if sign_is_mandatory {
-        bytes += output.write(&[b'+'])?;
+        bytes += output.write(b"+")?;
     }
-    bytes += format_number(output, offset.whole_hours().unsigned_abs(), padding, 2)?;
+    bytes += format_number(
+        output,
+        offset.whole_hours().unsigned_abs(),
+        padding,
+        width.unwrap_or(2),
+    )?;
     Ok(bytes)
 }
 
 /// Format the offset minute into the designated output.
 fn fmt_offset_minute(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetMinute { padding }: modifier::OffsetMinute,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number(
         output,
         offset.minutes_past_hour().unsigned_abs(),
@@ -428,10 +700,10 @@ fn fmt_offset_minute(
 
 /// Format the offset second into the designated output.
 fn fmt_offset_second(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetSecond { padding }: modifier::OffsetSecond,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number(
         output,
         offset.seconds_past_minute().unsigned_abs(),
@@ -439,4 +711,73 @@ fn fmt_offset_second(
         2,
     )
 }
+
+/// Format the entire offset into the designated output.
+fn fmt_offset(
+    output: &mut impl Output,
+    offset: UtcOffset,
+    modifier::Offset {
+        z_for_utc,
+        colon_separated,
+        minute,
+        second,
+    }: modifier::Offset,
+) -> Result<usize, error::Format> {
+    if z_for_utc && offset.is_utc() {
+        return output.write(b"Z");
+    }
+
+    let (hours, minutes, seconds) = offset.as_hms();
+    let include_second = match (minute, second) {
+        (modifier::Presence::Omitted, _) | (_, modifier::Presence::Omitted) => false,
+        (_, modifier::Presence::Mandatory) => true,
+        (_, modifier::Presence::Optional) => seconds != 0,
+    };
+    let include_minute = match minute {
+        modifier::Presence::Mandatory => true,
+        modifier::Presence::Optional => minutes != 0 || include_second,
+        modifier::Presence::Omitted => false,
+    };
+    if !include_second && seconds != 0 {
+        return Err(error::Format::InvalidComponent("offset_second"));
+    }
+    if !include_minute && minutes != 0 {
+        return Err(error::Format::InvalidComponent("offset_minute"));
+    }
+
+    let mut bytes = output.write(if offset.is_negative() {
+        b"-"
+    } else {
+        b"+"
+    })?;
+    bytes += format_number_pad_zero(output, hours.unsigned_abs(), 2)?;
+    for &(include, value) in &[(include_minute, minutes), (include_second, seconds)] {
+        if include {
+            if colon_separated {
+                bytes += output.write(b":")?;
+            }
+            bytes += format_number_pad_zero(output, value.unsigned_abs(), 2)?;
+        }
+    }
+    Ok(bytes)
+}
 // endregion offset formatters
+
+// region: time zone formatters
+/// Format the time zone into the designated output.
+fn fmt_time_zone(
+    output: &mut impl Output,
+    zone: Zone<'_>,
+    modifier::TimeZone { repr }: modifier::TimeZone,
+) -> Result<usize, error::Format> {
+    let text = match repr {
+        modifier::TimeZoneRepr::Abbreviation => zone.abbreviation,
+        modifier::TimeZoneRepr::Name => zone.name,
+    };
+    // Ensure the maximum formatted length is not exceeded.
+    if text.is_empty() || text.len() > repr.max_len() {
+        return Err(error::Format::InvalidComponent("time zone"));
+    }
+    output.write(text.as_bytes())
+}
+// endregion time zone formatters
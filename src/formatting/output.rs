@@ -0,0 +1,145 @@
+//! Destinations that formatted values can be written to.
+
+use core::char::REPLACEMENT_CHARACTER;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::error;
+
+/// A destination for formatted output.
+///
+/// This abstracts over [`std::io::Write`], [`core::fmt::Write`], and fixed-size byte buffers, so
+/// that formatting does not depend on the standard library.
+pub trait Output {
+    /// Write the bytes to the output, returning the number of bytes written.
+    fn write(&mut self, bytes: &[u8]) -> Result<usize, error::Format>;
+}
+
+#[cfg(feature = "alloc")]
+impl Output for Vec<u8> {
+    fn write(&mut self, bytes: &[u8]) -> Result<usize, error::Format> {
+        self.extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+}
+
+/// An [`Output`] wrapping a value implementing [`std::io::Write`].
+#[cfg(feature = "std")]
+pub(crate) struct IoOutput<'a, W: ?Sized>(pub(crate) &'a mut W);
+
+#[cfg(feature = "std")]
+impl<W: io::Write + ?Sized> Output for IoOutput<'_, W> {
+    fn write(&mut self, bytes: &[u8]) -> Result<usize, error::Format> {
+        self.0.write_all(bytes)?;
+        Ok(bytes.len())
+    }
+}
+
+/// Write the bytes as string slices, replacing any invalid UTF-8 sequences with
+/// `U+FFFD REPLACEMENT CHARACTER`. This matches the behavior of [`String::from_utf8_lossy`].
+fn write_lossy(
+    mut bytes: &[u8],
+    mut write_str: impl FnMut(&str) -> Result<(), error::Format>,
+) -> Result<usize, error::Format> {
+    let mut written = 0;
+    loop {
+        match core::str::from_utf8(bytes) {
+            Ok(valid) => {
+                write_str(valid)?;
+                return Ok(written + valid.len());
+            }
+            Err(err) => {
+                let (valid, invalid) = bytes.split_at(err.valid_up_to());
+                // The prefix up to `valid_up_to` is always valid UTF-8.
+                if let Ok(valid) = core::str::from_utf8(valid) {
+                    write_str(valid)?;
+                    written += valid.len();
+                }
+                write_str(REPLACEMENT_CHARACTER.encode_utf8(&mut [0; 4]))?;
+                written += REPLACEMENT_CHARACTER.len_utf8();
+                bytes = &invalid[err.error_len().unwrap_or(invalid.len())..];
+            }
+        }
+    }
+}
+
+/// An [`Output`] wrapping a value implementing [`core::fmt::Write`].
+///
+/// As formatted values are not guaranteed to be valid UTF-8, any invalid sequences are replaced
+/// with `U+FFFD REPLACEMENT CHARACTER`.
+pub(crate) struct FmtOutput<'a, W: ?Sized>(pub(crate) &'a mut W);
+
+impl<W: fmt::Write + ?Sized> Output for FmtOutput<'_, W> {
+    fn write(&mut self, bytes: &[u8]) -> Result<usize, error::Format> {
+        write_lossy(bytes, |s| Ok(self.0.write_str(s)?))
+    }
+}
+
+/// An [`Output`] writing to a fixed-size byte buffer. An error is returned if the buffer is not
+/// large enough to hold the formatted value.
+pub(crate) struct SliceOutput<'a> {
+    /// The buffer being written to.
+    buf: &'a mut [u8],
+    /// The number of bytes that have been written.
+    len: usize,
+}
+
+impl<'a> SliceOutput<'a> {
+    /// Create an output that writes to the start of the provided buffer.
+    #[allow(clippy::missing_const_for_fn)] // mutable references in const fn are unstable
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+}
+
+impl Output for SliceOutput<'_> {
+    fn write(&mut self, bytes: &[u8]) -> Result<usize, error::Format> {
+        let end = self.len + bytes.len();
+        if end > self.buf.len() {
+            return Err(error::Format::InsufficientBufferSize);
+        }
+        self.buf[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+        Ok(bytes.len())
+    }
+}
+
+/// An [`Output`] writing to a fixed-size byte buffer, guaranteeing that the written bytes are
+/// valid UTF-8. Any invalid sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+pub(crate) struct StrOutput<'a>(SliceOutput<'a>);
+
+impl<'a> StrOutput<'a> {
+    /// Create an output that writes to the start of the provided buffer.
+    #[allow(clippy::missing_const_for_fn)] // mutable references in const fn are unstable
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        Self(SliceOutput::new(buf))
+    }
+
+    /// Obtain the portion of the buffer that has been written to.
+    pub(crate) fn into_str(self) -> &'a str {
+        let SliceOutput { buf, len } = self.0;
+        let buf: &'a [u8] = buf;
+        core::str::from_utf8(&buf[..len]).expect("only valid UTF-8 is written to the buffer")
+    }
+}
+
+impl Output for StrOutput<'_> {
+    fn write(&mut self, bytes: &[u8]) -> Result<usize, error::Format> {
+        let output = &mut self.0;
+        write_lossy(bytes, |s| output.write(s.as_bytes()).map(drop))
+    }
+}
+
+/// An [`Output`] that discards everything written to it. This is used to determine whether a value
+/// can be formatted without actually writing it anywhere.
+pub(crate) struct DiscardOutput;
+
+impl Output for DiscardOutput {
+    fn write(&mut self, bytes: &[u8]) -> Result<usize, error::Format> {
+        Ok(bytes.len())
+    }
+}
@@ -0,0 +1,919 @@
+//! A [`DurationFormat`] describes which units may be used and how many of them are shown. The
+//! `Display` implementation of [`Duration`] uses the default format.
+//!
+//! A [`RelativeFormat`] describes a duration approximately, using a single unit chosen by
+//! configurable thresholds. The phrasing is provided by a [`Language`], which is [`English`] by
+//! default.
+
+use core::fmt::{self, Write as _};
+
+#[cfg(feature = "parsing")]
+use crate::error;
+#[cfg(feature = "parsing")]
+use crate::error::ParseFromDescription;
+use crate::{Duration, OffsetDateTime};
+
+/// A unit of time used when formatting or parsing a [`Duration`].
+///
+/// Units are ordered from smallest to largest.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Unit {
+    /// A nanosecond, written as `ns`.
+    Nanosecond,
+    /// A microsecond, written as `µs`. When parsing, `us` is also accepted.
+    Microsecond,
+    /// A millisecond, written as `ms`.
+    Millisecond,
+    /// A second, written as `s`.
+    Second,
+    /// A minute, written as `m`.
+    Minute,
+    /// An hour, written as `h`.
+    Hour,
+    /// A day of exactly 24 hours, written as `d`.
+    Day,
+    /// A week of exactly 7 days, written as `w`.
+    Week,
+}
+
+impl Unit {
+    /// All units, from largest to smallest.
+    const DESCENDING: [Self; 8] = [
+        Self::Week,
+        Self::Day,
+        Self::Hour,
+        Self::Minute,
+        Self::Second,
+        Self::Millisecond,
+        Self::Microsecond,
+        Self::Nanosecond,
+    ];
+
+    /// The symbol used when formatting the unit.
+    ///
+    /// ```rust
+    /// # use time::humanize::Unit;
+    /// assert_eq!(Unit::Hour.symbol(), "h");
+    /// assert_eq!(Unit::Microsecond.symbol(), "µs");
+    /// ```
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Nanosecond => "ns",
+            Self::Microsecond => "µs",
+            Self::Millisecond => "ms",
+            Self::Second => "s",
+            Self::Minute => "m",
+            Self::Hour => "h",
+            Self::Day => "d",
+            Self::Week => "w",
+        }
+    }
+
+    /// The length of the unit.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, humanize::Unit};
+    /// assert_eq!(Unit::Day.duration(), 1.days());
+    /// ```
+    pub const fn duration(self) -> Duration {
+        Duration::nanoseconds_i128(self.nanoseconds() as _)
+    }
+
+    /// The number of nanoseconds in the unit.
+    const fn nanoseconds(self) -> u64 {
+        match self {
+            Self::Nanosecond => 1,
+            Self::Microsecond => 1_000,
+            Self::Millisecond => 1_000_000,
+            Self::Second => 1_000_000_000,
+            Self::Minute => 60_000_000_000,
+            Self::Hour => 3_600_000_000_000,
+            Self::Day => 86_400_000_000_000,
+            Self::Week => 604_800_000_000_000,
+        }
+    }
+
+    /// Obtain the unit from any of its accepted spellings, ignoring case.
+    #[cfg(feature = "parsing")]
+    fn from_name(name: &[u8]) -> Option<Self> {
+        /// The accepted spellings of each unit.
+        const NAMES: [(Unit, &[&str]); 8] = [
+            (
+                Unit::Nanosecond,
+                &["ns", "nsec", "nanosecond", "nanoseconds"],
+            ),
+            (
+                Unit::Microsecond,
+                &["us", "µs", "usec", "microsecond", "microseconds"],
+            ),
+            (
+                Unit::Millisecond,
+                &["ms", "msec", "millisecond", "milliseconds"],
+            ),
+            (Unit::Second, &["s", "sec", "secs", "second", "seconds"]),
+            (Unit::Minute, &["m", "min", "mins", "minute", "minutes"]),
+            (Unit::Hour, &["h", "hr", "hrs", "hour", "hours"]),
+            (Unit::Day, &["d", "day", "days"]),
+            (Unit::Week, &["w", "week", "weeks"]),
+        ];
+
+        NAMES.iter().find_map(|&(unit, names)| {
+            if names
+                .iter()
+                .any(|candidate| candidate.as_bytes().eq_ignore_ascii_case(name))
+            {
+                Some(unit)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// A description of how a [`Duration`] is formatted and parsed.
+///
+/// A duration is formatted as a sequence of components, each consisting of a whole number and the
+/// [symbol](Unit::symbol) of a unit, from the largest unit to the smallest. Components that are
+/// zero are omitted. Any portion of the duration smaller than the smallest unit shown is truncated
+/// towards zero.
+///
+/// By default, units from days to nanoseconds are used with no limit on the number of components
+/// and no separator between them, such as `2d4h` or `1s500ms`.
+///
+/// ```rust
+/// # use time::{ext::NumericalDuration, humanize::{DurationFormat, Unit}};
+/// let duration = 1.days() + 2.hours() + 3.minutes() + 4.seconds();
+/// assert_eq!(DurationFormat::new().display(duration).to_string(), "1d2h3m4s");
+/// assert_eq!(
+///     DurationFormat::new()
+///         .with_largest_unit(Unit::Hour)
+///         .with_max_units(2)
+///         .with_separator(" ")
+///         .display(duration)
+///         .to_string(),
+///     "26h 3m"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DurationFormat {
+    /// The largest unit that may be used.
+    largest_unit: Unit,
+    /// The smallest unit that may be used.
+    smallest_unit: Unit,
+    /// The maximum number of components shown, starting from the first non-zero component.
+    max_units: Option<u8>,
+    /// The string inserted between components.
+    separator: &'static str,
+}
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DurationFormat {
+    /// Create the default format, which uses days to nanoseconds with no separator.
+    pub const fn new() -> Self {
+        Self {
+            largest_unit: Unit::Day,
+            smallest_unit: Unit::Nanosecond,
+            max_units: None,
+            separator: "",
+        }
+    }
+
+    /// Set the largest unit that may be used. Larger amounts are expressed in this unit, such as
+    /// `36h` rather than `1d12h`.
+    ///
+    /// If the unit is smaller than the smallest unit, the smallest unit is changed to match.
+    #[must_use = "This method does not mutate the original `DurationFormat`."]
+    pub const fn with_largest_unit(mut self, unit: Unit) -> Self {
+        self.largest_unit = unit;
+        if (self.smallest_unit as u8) > (unit as u8) {
+            self.smallest_unit = unit;
+        }
+        self
+    }
+
+    /// Set the smallest unit that may be used. When formatting, any remainder is truncated.
+    ///
+    /// If the unit is larger than the largest unit, the largest unit is changed to match.
+    #[must_use = "This method does not mutate the original `DurationFormat`."]
+    pub const fn with_smallest_unit(mut self, unit: Unit) -> Self {
+        self.smallest_unit = unit;
+        if (self.largest_unit as u8) < (unit as u8) {
+            self.largest_unit = unit;
+        }
+        self
+    }
+
+    /// Set the maximum number of components shown when formatting, counted from the first
+    /// non-zero component. This determines the precision of the output: with a maximum of two,
+    /// `1h0m5s` is formatted as `1h`. A maximum of zero is treated as one.
+    #[must_use = "This method does not mutate the original `DurationFormat`."]
+    pub const fn with_max_units(mut self, max_units: u8) -> Self {
+        self.max_units = Some(if max_units == 0 { 1 } else { max_units });
+        self
+    }
+
+    /// Set the string inserted between components when formatting. Whitespace between components
+    /// is always accepted when parsing.
+    #[must_use = "This method does not mutate the original `DurationFormat`."]
+    pub const fn with_separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Get the largest unit that may be used.
+    pub const fn largest_unit(self) -> Unit {
+        self.largest_unit
+    }
+
+    /// Get the smallest unit that may be used.
+    pub const fn smallest_unit(self) -> Unit {
+        self.smallest_unit
+    }
+
+    /// Get the maximum number of components shown when formatting, if limited.
+    pub const fn max_units(self) -> Option<u8> {
+        self.max_units
+    }
+
+    /// Get the string inserted between components when formatting.
+    pub const fn separator(self) -> &'static str {
+        self.separator
+    }
+
+    /// Whether the unit may be used with this format.
+    const fn allows(self, unit: Unit) -> bool {
+        unit as u8 >= self.smallest_unit as u8 && unit as u8 <= self.largest_unit as u8
+    }
+
+    /// Obtain a value that formats the duration using this format when displayed.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, humanize::{DurationFormat, Unit}};
+    /// let format = DurationFormat::new().with_smallest_unit(Unit::Second);
+    /// assert_eq!(format.display((-90.5).seconds()).to_string(), "-1m30s");
+    /// assert_eq!(format.display(250.milliseconds()).to_string(), "0s");
+    /// ```
+    pub const fn display(self, duration: Duration) -> DisplayDuration {
+        DisplayDuration {
+            format: self,
+            duration,
+        }
+    }
+
+    /// Parse a duration that uses any of the units allowed by this format.
+    ///
+    /// The input consists of an optional sign followed by one or more components separated by
+    /// optional whitespace. Each component is a number, optionally with a fractional part, followed
+    /// by a unit. Units may be written as their symbol, an abbreviation such as `sec` or `hrs`, or
+    /// in full, such as `minutes`, ignoring case. Each unit may appear at most once, and units must
+    /// be in descending order. A lone `0` is also accepted.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, humanize::DurationFormat};
+    /// let format = DurationFormat::new();
+    /// assert_eq!(format.parse("1h30m")?, 90.minutes());
+    /// assert_eq!(format.parse("2 days 3 hours")?, 51.hours());
+    /// assert_eq!(format.parse("-1.5s")?, (-1.5).seconds());
+    /// assert!(format.parse("2w").is_err());
+    /// assert!(format.parse("30m1h").is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+    pub fn parse(self, input: &str) -> Result<Duration, error::Parse> {
+        let bytes = input.as_bytes();
+        let position = |remaining: &[u8]| bytes.len() - remaining.len();
+        let invalid = |remaining: &[u8], component| {
+            error::Parse::from(ParseFromDescription::InvalidComponent(component))
+                .with_location(position(remaining), None)
+        };
+
+        let remaining = trim_start(bytes);
+        let (mut remaining, is_negative) = match remaining.first() {
+            Some(b'-') => (&remaining[1..], true),
+            Some(b'+') => (&remaining[1..], false),
+            _ => (remaining, false),
+        };
+        if trim_start(remaining) == b"0" {
+            return Ok(Duration::ZERO);
+        }
+
+        // The magnitude of `Duration::MIN` is larger than that of `Duration::MAX`.
+        let max_whole_seconds = if is_negative {
+            -i128::from(i64::MIN)
+        } else {
+            i128::from(i64::MAX)
+        };
+        let max_nanoseconds = max_whole_seconds * 1_000_000_000 + 999_999_999;
+        let mut nanoseconds: i128 = 0;
+        let mut previous_unit = None;
+        loop {
+            let component_start = remaining;
+            let whole_len = digit_count(remaining);
+            let whole = &remaining[..whole_len];
+            remaining = &remaining[whole_len..];
+            let fraction = match remaining.first() {
+                Some(b'.') => {
+                    let fraction_len = digit_count(&remaining[1..]);
+                    if fraction_len == 0 {
+                        return Err(invalid(component_start, "duration"));
+                    }
+                    let fraction = &remaining[1..=fraction_len];
+                    remaining = &remaining[fraction_len + 1..];
+                    fraction
+                }
+                _ => &[],
+            };
+            if whole.is_empty() && fraction.is_empty() {
+                return Err(invalid(component_start, "duration"));
+            }
+
+            remaining = trim_start(remaining);
+            let unit_len = remaining
+                .iter()
+                .position(|&c| !(c.is_ascii_alphabetic() || c == 0xC2 || c == 0xB5))
+                .unwrap_or(remaining.len());
+            // Units are strictly descending, so none is repeated.
+            let is_descending = |unit| !matches!(previous_unit, Some(previous) if previous <= unit);
+            let unit = match Unit::from_name(&remaining[..unit_len]) {
+                Some(unit) if self.allows(unit) && is_descending(unit) => unit,
+                _ => return Err(invalid(remaining, "unit")),
+            };
+            previous_unit = Some(unit);
+            remaining = &remaining[unit_len..];
+
+            nanoseconds = component_nanoseconds(whole, fraction, unit)
+                .and_then(|component| nanoseconds.checked_add(component))
+                .filter(|&total| total <= max_nanoseconds)
+                .ok_or_else(|| invalid(component_start, "duration"))?;
+
+            remaining = trim_start(remaining);
+            if remaining.is_empty() {
+                break;
+            }
+        }
+
+        if is_negative {
+            nanoseconds = -nanoseconds;
+        }
+        Ok(Duration::nanoseconds_i128(nanoseconds))
+    }
+}
+
+/// Consume any leading ASCII whitespace.
+#[cfg(feature = "parsing")]
+fn trim_start(input: &[u8]) -> &[u8] {
+    let start = input
+        .iter()
+        .position(|c| !c.is_ascii_whitespace())
+        .unwrap_or(input.len());
+    &input[start..]
+}
+
+/// The number of leading ASCII digits.
+#[cfg(feature = "parsing")]
+fn digit_count(input: &[u8]) -> usize {
+    input
+        .iter()
+        .position(|c| !c.is_ascii_digit())
+        .unwrap_or(input.len())
+}
+
+/// The number of nanoseconds represented by a component with the provided whole and fractional
+/// digits, or `None` if it overflows. Any precision beyond a nanosecond is truncated.
+#[cfg(feature = "parsing")]
+fn component_nanoseconds(whole: &[u8], fraction: &[u8], unit: Unit) -> Option<i128> {
+    let unit = i128::from(unit.nanoseconds());
+    let mut nanoseconds = whole
+        .iter()
+        .try_fold(0_i128, |value, &digit| {
+            value.checked_mul(10)?.checked_add(i128::from(digit - b'0'))
+        })?
+        .checked_mul(unit)?;
+
+    let mut scale = unit;
+    for &digit in fraction {
+        scale /= 10;
+        if scale == 0 {
+            break;
+        }
+        nanoseconds += i128::from(digit - b'0') * scale;
+    }
+    Some(nanoseconds)
+}
+
+/// A [`Duration`] that is formatted using a [`DurationFormat`] when displayed.
+///
+/// This value is obtained from [`DurationFormat::display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisplayDuration {
+    /// The format to use.
+    format: DurationFormat,
+    /// The duration to format.
+    duration: Duration,
+}
+
+impl DisplayDuration {
+    /// Write the formatted duration without any padding.
+    fn write_unpadded(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        let format = self.format;
+        #[allow(clippy::cast_abs_to_unsigned)] // i128::unsigned_abs is not stable on the MSRV
+        let mut remaining = self.duration.whole_nanoseconds().abs() as u128;
+        remaining -= remaining % u128::from(format.smallest_unit.nanoseconds());
+
+        if remaining == 0 {
+            // Use the allowed unit closest to seconds.
+            let unit = Unit::Second
+                .min(format.largest_unit)
+                .max(format.smallest_unit);
+            return write!(f, "0{}", unit.symbol());
+        }
+
+        if self.duration.is_negative() {
+            f.write_str("-")?;
+        }
+        let mut components_left = format.max_units;
+        let mut is_first = true;
+        for &unit in Unit::DESCENDING.iter().filter(|&&unit| format.allows(unit)) {
+            if remaining == 0 || components_left == Some(0) {
+                break;
+            }
+
+            let unit_nanoseconds = u128::from(unit.nanoseconds());
+            let value = remaining / unit_nanoseconds;
+            remaining %= unit_nanoseconds;
+
+            if value == 0 {
+                if !is_first {
+                    components_left = components_left.map(|left| left - 1);
+                }
+                continue;
+            }
+            if !is_first {
+                f.write_str(format.separator)?;
+            }
+            write!(f, "{}{}", value, unit.symbol())?;
+            is_first = false;
+            components_left = components_left.map(|left| left - 1);
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DisplayDuration {
+    /// Format the duration, respecting the width, fill, alignment, and precision of the formatter
+    /// in the same manner as [`fmt::Formatter::pad`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write_unpadded(f);
+        }
+
+        // `Formatter::pad` requires a `&str`, which can't be obtained without allocating. Instead,
+        // determine the length up front and write the padding manually.
+        let mut length = CharCount(0);
+        self.write_unpadded(&mut length)?;
+        let length = f
+            .precision()
+            .map_or(length.0, |precision| length.0.min(precision));
+        let padding = f.width().map_or(0, |width| width.saturating_sub(length));
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Left) | None => (0, padding),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        self.write_unpadded(&mut Truncate {
+            output: f,
+            remaining: length,
+        })?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// A writer that counts the number of characters written to it.
+struct CharCount(usize);
+
+impl fmt::Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// A writer that forwards at most `remaining` characters to `output`, discarding the rest.
+struct Truncate<'a, W> {
+    /// The writer that characters are forwarded to.
+    output: &'a mut W,
+    /// The number of characters that may still be written.
+    remaining: usize,
+}
+
+impl<W: fmt::Write> fmt::Write for Truncate<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = s
+            .char_indices()
+            .nth(self.remaining)
+            .map_or(s.len(), |(index, _)| index);
+        self.remaining = self.remaining.saturating_sub(s.chars().count());
+        self.output.write_str(&s[..end])
+    }
+}
+
+/// A unit of time used when describing a relative time.
+///
+/// Units are ordered from smallest to largest.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RelativeUnit {
+    /// A second.
+    Second,
+    /// A minute, which is 60 seconds.
+    Minute,
+    /// An hour, which is 60 minutes.
+    Hour,
+    /// A day, which is 24 hours.
+    Day,
+    /// A week, which is 7 days.
+    Week,
+    /// A month, which is approximated by the average length of a month in the Gregorian calendar:
+    /// 30.436875 days.
+    Month,
+    /// A year, which is approximated by the average length of a year in the Gregorian calendar:
+    /// 365.2425 days.
+    Year,
+}
+
+impl RelativeUnit {
+    /// All units, from smallest to largest.
+    const ASCENDING: [Self; 7] = [
+        Self::Second,
+        Self::Minute,
+        Self::Hour,
+        Self::Day,
+        Self::Week,
+        Self::Month,
+        Self::Year,
+    ];
+
+    /// The number of seconds in the unit.
+    const fn seconds(self) -> u64 {
+        match self {
+            Self::Second => 1,
+            Self::Minute => 60,
+            Self::Hour => 3_600,
+            Self::Day => 86_400,
+            Self::Week => 604_800,
+            Self::Month => 2_629_746,
+            Self::Year => 31_556_952,
+        }
+    }
+}
+
+/// Whether a relative time is in the past or the future.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tense {
+    /// The time is before the anchor, such as `3 minutes ago`.
+    Past,
+    /// The time is after the anchor, such as `in 2 days`.
+    Future,
+}
+
+/// How an amount is rounded to a whole number of units.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards zero, so that 1 hour 59 minutes is 1 hour.
+    Truncate,
+    /// Round to the nearest whole number, with halfway values rounded away from zero.
+    Nearest,
+    /// Round away from zero, so that 1 hour 1 minute is 2 hours.
+    Up,
+}
+
+/// The phrasing of relative times in a language.
+///
+/// Implement this trait to describe relative times in a language other than English, then use
+/// the implementation with [`RelativeFormat::with_language`].
+///
+/// ```rust
+/// # use core::fmt;
+/// # use time::{
+/// #     ext::NumericalDuration,
+/// #     humanize::{English, Language, RelativeFormat, RelativeUnit, Tense},
+/// # };
+/// struct German;
+///
+/// impl Language for German {
+///     fn write_relative(
+///         &self,
+///         f: &mut fmt::Formatter<'_>,
+///         amount: u64,
+///         unit: RelativeUnit,
+///         tense: Tense,
+///     ) -> fmt::Result {
+///         let (singular, plural) = match unit {
+///             RelativeUnit::Second => ("Sekunde", "Sekunden"),
+///             RelativeUnit::Minute => ("Minute", "Minuten"),
+///             RelativeUnit::Hour => ("Stunde", "Stunden"),
+///             RelativeUnit::Day => ("Tag", "Tagen"),
+///             RelativeUnit::Week => ("Woche", "Wochen"),
+///             RelativeUnit::Month => ("Monat", "Monaten"),
+///             RelativeUnit::Year => ("Jahr", "Jahren"),
+///             // Units may be added in the future. Fall back to English for them.
+///             _ => return English.write_relative(f, amount, unit, tense),
+///         };
+///         let name = if amount == 1 { singular } else { plural };
+///         match tense {
+///             Tense::Past => write!(f, "vor {} {}", amount, name),
+///             Tense::Future => write!(f, "in {} {}", amount, name),
+///             _ => English.write_relative(f, amount, unit, tense),
+///         }
+///     }
+///
+///     fn write_now(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str("jetzt")
+///     }
+/// }
+///
+/// let format = RelativeFormat::new().with_language(German);
+/// assert_eq!(format.display((-2).days()).to_string(), "vor 2 Tagen");
+/// assert_eq!(format.display(1.hours()).to_string(), "in 1 Stunde");
+/// ```
+pub trait Language {
+    /// Write a description of a time that is `amount` units in the past or future. The amount is
+    /// never zero.
+    fn write_relative(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        amount: u64,
+        unit: RelativeUnit,
+        tense: Tense,
+    ) -> fmt::Result;
+
+    /// Write a description of a time that is equal to the anchor, or close enough to be
+    /// considered equal.
+    fn write_now(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// The English phrasing of relative times, such as `3 minutes ago`, `in 1 day`, and `now`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct English;
+
+impl Language for English {
+    fn write_relative(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        amount: u64,
+        unit: RelativeUnit,
+        tense: Tense,
+    ) -> fmt::Result {
+        let unit = match unit {
+            RelativeUnit::Second => "second",
+            RelativeUnit::Minute => "minute",
+            RelativeUnit::Hour => "hour",
+            RelativeUnit::Day => "day",
+            RelativeUnit::Week => "week",
+            RelativeUnit::Month => "month",
+            RelativeUnit::Year => "year",
+        };
+        let plural = if amount == 1 { "" } else { "s" };
+        match tense {
+            Tense::Past => write!(f, "{} {}{} ago", amount, unit, plural),
+            Tense::Future => write!(f, "in {} {}{}", amount, unit, plural),
+        }
+    }
+
+    fn write_now(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("now")
+    }
+}
+
+/// A description of how a relative time is formatted.
+///
+/// A relative time is described using a single unit. Starting with seconds, the amount of each
+/// unit is computed and rounded; the first unit whose amount is less than its threshold is used.
+/// Years have no threshold. A threshold of zero skips the unit entirely. Durations shorter than
+/// the "now" threshold are described as now.
+///
+/// The default thresholds are 45 seconds, 45 minutes, 22 hours, 7 days, 4 weeks, and 11 months,
+/// amounts are rounded to the nearest whole number, and durations shorter than one second are
+/// described as now.
+///
+/// ```rust
+/// # use time::{ext::NumericalDuration, humanize::{RelativeFormat, RelativeUnit, Rounding}};
+/// let format = RelativeFormat::new();
+/// assert_eq!(format.display((-3).minutes()).to_string(), "3 minutes ago");
+/// assert_eq!(format.display(47.hours()).to_string(), "in 2 days");
+/// assert_eq!(format.display(50.minutes()).to_string(), "in 1 hour");
+///
+/// let format = format
+///     .with_threshold(RelativeUnit::Minute, 120)
+///     .with_rounding(Rounding::Truncate);
+/// assert_eq!(format.display(-(90.minutes() + 59.seconds())).to_string(), "90 minutes ago");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RelativeFormat<L = English> {
+    /// The thresholds for each unit other than years, in ascending order of unit.
+    thresholds: [u32; 6],
+    /// How amounts are rounded.
+    rounding: Rounding,
+    /// Durations shorter than this are described as now.
+    now_threshold: Duration,
+    /// The phrasing to use.
+    language: L,
+}
+
+impl Default for RelativeFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelativeFormat {
+    /// Create the default format, which uses English phrasing.
+    pub const fn new() -> Self {
+        Self {
+            thresholds: [45, 45, 22, 7, 4, 11],
+            rounding: Rounding::Nearest,
+            now_threshold: Duration::SECOND,
+            language: English,
+        }
+    }
+}
+
+impl<L> RelativeFormat<L> {
+    /// Set the threshold for the unit. The unit is used when the rounded amount is less than the
+    /// threshold, unless a smaller unit has already been used. A threshold of zero skips the unit.
+    /// Setting the threshold for years has no effect.
+    #[must_use = "This method does not mutate the original `RelativeFormat`."]
+    pub const fn with_threshold(mut self, unit: RelativeUnit, threshold: u32) -> Self {
+        if !matches!(unit, RelativeUnit::Year) {
+            self.thresholds[unit as usize] = threshold;
+        }
+        self
+    }
+
+    /// Set how amounts are rounded to a whole number of units.
+    #[must_use = "This method does not mutate the original `RelativeFormat`."]
+    pub const fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set the duration below which a relative time is described as now.
+    #[must_use = "This method does not mutate the original `RelativeFormat`."]
+    pub const fn with_now_threshold(mut self, now_threshold: Duration) -> Self {
+        self.now_threshold = now_threshold;
+        self
+    }
+
+    /// Use the provided phrasing.
+    #[allow(clippy::missing_const_for_fn)] // the previous language may need to be dropped
+    pub fn with_language<M>(self, language: M) -> RelativeFormat<M> {
+        RelativeFormat {
+            thresholds: self.thresholds,
+            rounding: self.rounding,
+            now_threshold: self.now_threshold,
+            language,
+        }
+    }
+
+    /// Get the threshold for the unit, or `None` for years.
+    pub const fn threshold(&self, unit: RelativeUnit) -> Option<u32> {
+        match unit {
+            RelativeUnit::Year => None,
+            _ => Some(self.thresholds[unit as usize]),
+        }
+    }
+
+    /// Get how amounts are rounded to a whole number of units.
+    pub const fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+
+    /// Get the duration below which a relative time is described as now.
+    pub const fn now_threshold(&self) -> Duration {
+        self.now_threshold
+    }
+
+    /// Get the phrasing that is used.
+    pub const fn language(&self) -> &L {
+        &self.language
+    }
+
+    /// Obtain a value that describes the duration as a relative time when displayed. Positive
+    /// durations are in the future and negative durations are in the past.
+    pub const fn display(&self, duration: Duration) -> DisplayRelative<'_, L> {
+        DisplayRelative {
+            format: self,
+            duration,
+        }
+    }
+
+    /// Obtain a value that describes `datetime` relative to `anchor` when displayed.
+    ///
+    /// ```rust
+    /// # use time::{humanize::RelativeFormat, macros::datetime};
+    /// let anchor = datetime!(2021-05-12 12:00 UTC);
+    /// assert_eq!(
+    ///     RelativeFormat::new()
+    ///         .display_relative(datetime!(2021-05-12 9:10 -3), anchor)
+    ///         .to_string(),
+    ///     "in 10 minutes"
+    /// );
+    /// ```
+    pub fn display_relative(
+        &self,
+        datetime: OffsetDateTime,
+        anchor: OffsetDateTime,
+    ) -> DisplayRelative<'_, L> {
+        self.display(datetime - anchor)
+    }
+
+    /// Obtain a value that describes `datetime` relative to the current time when displayed.
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn display_from_now(&self, datetime: OffsetDateTime) -> DisplayRelative<'_, L> {
+        self.display_relative(datetime, OffsetDateTime::now_utc())
+    }
+
+    /// Choose the unit used to describe a duration of the provided number of nanoseconds, and
+    /// compute the rounded amount of that unit.
+    fn amount(&self, nanoseconds: u128) -> (u64, RelativeUnit) {
+        let mut amount = 0;
+        for &unit in &RelativeUnit::ASCENDING {
+            let threshold = self.threshold(unit);
+            if threshold == Some(0) {
+                continue;
+            }
+
+            let length = u128::from(unit.seconds()) * 1_000_000_000;
+            let (quotient, remainder) = (nanoseconds / length, nanoseconds % length);
+            let rounded = match self.rounding {
+                Rounding::Truncate => quotient,
+                Rounding::Nearest => quotient + u128::from(remainder * 2 >= length),
+                Rounding::Up => quotient + u128::from(remainder > 0),
+            };
+            amount = rounded as u64;
+            match threshold {
+                Some(threshold) if rounded >= u128::from(threshold) => {}
+                _ => return (amount, unit),
+            }
+        }
+        (amount, RelativeUnit::Year)
+    }
+}
+
+/// A [`Duration`] that is described as a relative time using a [`RelativeFormat`] when displayed.
+///
+/// This value is obtained from [`RelativeFormat::display`] and related methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisplayRelative<'a, L> {
+    /// The format to use.
+    format: &'a RelativeFormat<L>,
+    /// The duration from the anchor.
+    duration: Duration,
+}
+
+impl<L: Language> fmt::Display for DisplayRelative<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = self.format;
+        if self.duration.abs() < format.now_threshold.abs() {
+            return format.language.write_now(f);
+        }
+
+        #[allow(clippy::cast_abs_to_unsigned)] // i128::unsigned_abs is not stable on the MSRV
+        let nanoseconds = self.duration.whole_nanoseconds().abs() as u128;
+        match format.amount(nanoseconds) {
+            (0, _) => format.language.write_now(f),
+            (amount, unit) => {
+                let tense = if self.duration.is_negative() {
+                    Tense::Past
+                } else {
+                    Tense::Future
+                };
+                format.language.write_relative(f, amount, unit, tense)
+            }
+        }
+    }
+}
@@ -0,0 +1,473 @@
+//! Leap seconds and conversions between time scales.
+//!
+//! [UTC](https://en.wikipedia.org/wiki/Coordinated_Universal_Time) is occasionally adjusted by a
+//! leap second to keep it close to the rotation of the Earth. Other time scales, such as
+//! [TAI](https://en.wikipedia.org/wiki/International_Atomic_Time),
+//! [GPS time](https://en.wikipedia.org/wiki/Global_Positioning_System#Timekeeping), and
+//! [TT](https://en.wikipedia.org/wiki/Terrestrial_Time), are not. Converting between them requires
+//! knowing when each leap second occurred, which is provided by a [`LeapSecondTable`].
+//!
+//! A table is only known to be accurate until its expiration date. The expiration date is advisory:
+//! conversions of later moments are still performed, assuming that no further leap seconds have
+//! been inserted. Use [`LeapSecondTable::is_expired`] to detect when a table should be refreshed.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::util::is_leap_year;
+use crate::{error, Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// The difference between TAI and GPS time, which is constant.
+const TAI_MINUS_GPS: Duration = Duration::seconds(19);
+/// The difference between TT and TAI, which is constant.
+const TT_MINUS_TAI: Duration = Duration::new(32, 184_000_000);
+/// The number of seconds from the NTP epoch (1900-01-01) to the Unix epoch (1970-01-01).
+#[cfg(feature = "alloc")]
+const NTP_TO_UNIX: i64 = 2_208_988_800;
+
+/// A change in the difference between TAI and UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    /// The date at the start of which the difference takes effect.
+    date: Date,
+    /// TAI − UTC in seconds.
+    tai_minus_utc: i16,
+}
+
+impl Entry {
+    /// Create an entry indicating that TAI − UTC is the provided number of seconds from the start
+    /// of the provided date (in UTC).
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::Entry, macros::date};
+    /// let entry = Entry::new(date!(2017 - 01 - 01), 37);
+    /// assert_eq!(entry.date(), date!(2017 - 01 - 01));
+    /// ```
+    pub const fn new(date: Date, tai_minus_utc: i16) -> Self {
+        Self {
+            date,
+            tai_minus_utc,
+        }
+    }
+
+    /// Get the date at the start of which the entry takes effect.
+    pub const fn date(self) -> Date {
+        self.date
+    }
+
+    /// Get the difference between TAI and UTC once the entry has taken effect.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, leap_seconds::Entry, macros::date};
+    /// assert_eq!(
+    ///     Entry::new(date!(2017 - 01 - 01), 37).tai_minus_utc(),
+    ///     37.seconds()
+    /// );
+    /// ```
+    pub const fn tai_minus_utc(self) -> Duration {
+        Duration::seconds(self.tai_minus_utc as _)
+    }
+}
+
+/// Create an entry for January 1 of the provided year.
+const fn january(year: i32, tai_minus_utc: i16) -> Entry {
+    Entry::new(Date::__from_ordinal_date_unchecked(year, 1), tai_minus_utc)
+}
+
+/// Create an entry for July 1 of the provided year.
+const fn july(year: i32, tai_minus_utc: i16) -> Entry {
+    let ordinal = if is_leap_year(year) { 183 } else { 182 };
+    Entry::new(
+        Date::__from_ordinal_date_unchecked(year, ordinal),
+        tai_minus_utc,
+    )
+}
+
+/// All leap seconds announced as of the creation of the built-in table.
+const BUILTIN_ENTRIES: &[Entry] = &[
+    january(1972, 10),
+    july(1972, 11),
+    january(1973, 12),
+    january(1974, 13),
+    january(1975, 14),
+    january(1976, 15),
+    january(1977, 16),
+    january(1978, 17),
+    january(1979, 18),
+    january(1980, 19),
+    july(1981, 20),
+    july(1982, 21),
+    july(1983, 22),
+    july(1985, 23),
+    january(1988, 24),
+    january(1990, 25),
+    january(1991, 26),
+    july(1992, 27),
+    july(1993, 28),
+    july(1994, 29),
+    january(1996, 30),
+    july(1997, 31),
+    january(1999, 32),
+    january(2006, 33),
+    january(2009, 34),
+    july(2012, 35),
+    july(2015, 36),
+    january(2017, 37),
+];
+
+/// A table of leap seconds, used to convert between UTC and other time scales.
+///
+/// Each entry indicates the difference between TAI and UTC from the start of a given date. Before
+/// the first entry, the difference of the first entry is assumed. A positive leap second is
+/// inserted at the end of the day preceding an entry that increases the difference by one second.
+/// Such a leap second is represented as a [`Time`] for which
+/// [`is_leap_second`](Time::is_leap_second) returns `true`.
+///
+/// The entries can be stored in any type that can be viewed as a slice, such as a `Vec` when
+/// loading the table at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeapSecondTable<T = &'static [Entry]> {
+    /// The entries, sorted by date.
+    entries: T,
+    /// The date after which the table is no longer guaranteed to be accurate.
+    expires: Option<Date>,
+}
+
+impl LeapSecondTable {
+    /// A table containing all leap seconds announced as of this version of the crate.
+    ///
+    /// The table is current through IERS Bulletin C 71, which announced that no leap second will be
+    /// inserted at the end of June 2026. As with the `leap-seconds.list` published alongside that
+    /// bulletin, it expires on 2026-12-28. The expiration is not enforced; conversions after that
+    /// date assume that no further leap seconds have been inserted. Applications needing accuracy
+    /// for future dates should load an up-to-date `leap-seconds.list` instead.
+    pub const BUILTIN: Self = Self {
+        entries: BUILTIN_ENTRIES,
+        expires: Some(Date::__from_ordinal_date_unchecked(2026, 362)),
+    };
+}
+
+impl<T: AsRef<[Entry]>> LeapSecondTable<T> {
+    /// Create a table from the provided entries. The dates of the entries must be strictly
+    /// increasing.
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::{Entry, LeapSecondTable}, macros::date};
+    /// let entries = [
+    ///     Entry::new(date!(2015 - 07 - 01), 36),
+    ///     Entry::new(date!(2017 - 01 - 01), 37),
+    /// ];
+    /// assert!(LeapSecondTable::new(&entries[..]).is_ok());
+    /// assert!(LeapSecondTable::new(&[entries[1], entries[0]][..]).is_err());
+    /// ```
+    pub fn new(entries: T) -> Result<Self, error::InvalidLeapSecondTable> {
+        let slice = entries.as_ref();
+        for (index, pair) in slice.windows(2).enumerate() {
+            if pair[0].date >= pair[1].date {
+                return Err(error::InvalidLeapSecondTable::Unsorted { index: index + 1 });
+            }
+        }
+        Ok(Self {
+            entries,
+            expires: None,
+        })
+    }
+
+    /// Set the date after which the table is no longer guaranteed to be accurate, returning the
+    /// updated table.
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    #[must_use = "This method does not mutate the original `LeapSecondTable`."]
+    pub fn with_expiration(mut self, expires: Date) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    /// Get the entries of the table, sorted by date.
+    pub fn entries(&self) -> &[Entry] {
+        self.entries.as_ref()
+    }
+
+    /// Get the date after which the table is no longer guaranteed to be accurate, if known.
+    ///
+    /// The expiration date is advisory. Conversions are still performed after this date, assuming
+    /// that no further leap seconds have occurred.
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    pub fn expires(&self) -> Option<Date> {
+        self.expires
+    }
+
+    /// Check whether the table has expired as of the provided date. A table without an expiration
+    /// date never expires.
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::LeapSecondTable, macros::date};
+    /// let table = LeapSecondTable::BUILTIN;
+    /// assert!(!table.is_expired(date!(2026 - 12 - 27)));
+    /// assert!(table.is_expired(date!(2026 - 12 - 28)));
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    pub fn is_expired(&self, date: Date) -> bool {
+        matches!(self.expires, Some(expires) if date >= expires)
+    }
+
+    /// Get the difference between TAI and UTC at the provided moment.
+    ///
+    /// During a leap second, this is the difference in effect before the leap second.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, leap_seconds::LeapSecondTable, macros::datetime};
+    /// let table = LeapSecondTable::BUILTIN;
+    /// assert_eq!(
+    ///     table.tai_minus_utc(datetime!(2016-12-31 23:59:59 UTC)),
+    ///     36.seconds()
+    /// );
+    /// assert_eq!(
+    ///     table.tai_minus_utc(datetime!(2017-01-01 0:00 UTC)),
+    ///     37.seconds()
+    /// );
+    /// ```
+    pub fn tai_minus_utc(&self, datetime: OffsetDateTime) -> Duration {
+        self.tai_minus_utc_on(datetime.utc_datetime.date())
+    }
+
+    /// Get the difference between TAI and UTC on the provided date in UTC.
+    fn tai_minus_utc_on(&self, date: Date) -> Duration {
+        let entries = self.entries();
+        entries
+            .iter()
+            .rev()
+            .find(|entry| entry.date <= date)
+            .or_else(|| entries.first())
+            .map_or(Duration::ZERO, |entry| entry.tai_minus_utc())
+    }
+
+    /// Convert the moment to [TAI](https://en.wikipedia.org/wiki/International_Atomic_Time).
+    ///
+    /// # Panics
+    ///
+    /// This panics if the moment in TAI is after the end of [`Date::MAX`].
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::LeapSecondTable, macros::datetime, Time};
+    /// let table = LeapSecondTable::BUILTIN;
+    /// assert_eq!(
+    ///     table.to_tai(datetime!(2017-01-01 0:00 UTC)),
+    ///     datetime!(2017-01-01 0:00:37)
+    /// );
+    /// let leap_second = datetime!(2016-12-31 0:00 UTC)
+    ///     .replace_time(Time::leap_second(23, 59, 0)?);
+    /// assert_eq!(table.to_tai(leap_second), datetime!(2017-01-01 0:00:36));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn to_tai(&self, datetime: OffsetDateTime) -> PrimitiveDateTime {
+        let utc = datetime.utc_datetime;
+        if utc.time().is_leap_second() {
+            // The leap second immediately follows the final regular second of the day.
+            let (hour, minute, _, nanosecond) = utc.time().as_hms_nano();
+            let previous = utc.replace_time(Time::__from_hms_nanos_unchecked(
+                hour, minute, 59, nanosecond,
+            ));
+            previous + self.tai_minus_utc_on(previous.date()) + Duration::SECOND
+        } else {
+            utc + self.tai_minus_utc_on(utc.date())
+        }
+    }
+
+    /// Convert the moment from [TAI](https://en.wikipedia.org/wiki/International_Atomic_Time).
+    ///
+    /// The returned value is in UTC. If the moment falls within a leap second, the time of the
+    /// returned value is a leap second.
+    ///
+    /// Only a single leap second can be represented. If an entry increases the difference between
+    /// TAI and UTC by more than one second, any moment after the first inserted second is clamped
+    /// to the end of the leap second. Entries that decrease the difference are handled by skipping
+    /// the corresponding seconds of UTC.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the moment in UTC is before the start of [`Date::MIN`].
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::LeapSecondTable, macros::datetime};
+    /// let table = LeapSecondTable::BUILTIN;
+    /// assert_eq!(
+    ///     table.from_tai(datetime!(2017-01-01 0:00:37)),
+    ///     datetime!(2017-01-01 0:00 UTC)
+    /// );
+    /// assert!(table
+    ///     .from_tai(datetime!(2017-01-01 0:00:36.5))
+    ///     .time()
+    ///     .is_leap_second());
+    /// ```
+    pub fn from_tai(&self, datetime: PrimitiveDateTime) -> OffsetDateTime {
+        let entries = self.entries();
+        let index = entries
+            .iter()
+            .rposition(|entry| datetime >= entry.date.midnight() + entry.tai_minus_utc());
+        let offset = index
+            .map(|index| entries[index])
+            .or_else(|| entries.first().copied())
+            .map_or(Duration::ZERO, Entry::tai_minus_utc);
+        let next = index.and_then(|index| entries.get(index + 1));
+
+        let utc = datetime - offset;
+        match next {
+            Some(next) if utc >= next.date.midnight() => {
+                let previous =
+                    (utc - Duration::SECOND).min(next.date.midnight() - Duration::NANOSECOND);
+                previous
+                    .replace_time(Time::__from_hms_nanos_unchecked(
+                        previous.hour(),
+                        previous.minute(),
+                        60,
+                        previous.nanosecond(),
+                    ))
+                    .assume_utc()
+            }
+            _ => utc.assume_utc(),
+        }
+    }
+
+    /// Convert the moment to
+    /// [GPS time](https://en.wikipedia.org/wiki/Global_Positioning_System#Timekeeping).
+    ///
+    /// # Panics
+    ///
+    /// The conversion passes through TAI, so this panics if the moment in TAI is after the end of
+    /// [`Date::MAX`].
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::LeapSecondTable, macros::datetime};
+    /// assert_eq!(
+    ///     LeapSecondTable::BUILTIN.to_gps(datetime!(2017-01-01 0:00 UTC)),
+    ///     datetime!(2017-01-01 0:00:18)
+    /// );
+    /// ```
+    pub fn to_gps(&self, datetime: OffsetDateTime) -> PrimitiveDateTime {
+        self.to_tai(datetime) - TAI_MINUS_GPS
+    }
+
+    /// Convert the moment from
+    /// [GPS time](https://en.wikipedia.org/wiki/Global_Positioning_System#Timekeeping). The
+    /// returned value is in UTC.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the moment in TAI is after the end of [`Date::MAX`] or the moment in UTC is
+    /// before the start of [`Date::MIN`].
+    pub fn from_gps(&self, datetime: PrimitiveDateTime) -> OffsetDateTime {
+        self.from_tai(datetime + TAI_MINUS_GPS)
+    }
+
+    /// Convert the moment to [TT](https://en.wikipedia.org/wiki/Terrestrial_Time).
+    ///
+    /// # Panics
+    ///
+    /// This panics if the moment in TT is after the end of [`Date::MAX`].
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::LeapSecondTable, macros::datetime};
+    /// assert_eq!(
+    ///     LeapSecondTable::BUILTIN.to_tt(datetime!(2017-01-01 0:00 UTC)),
+    ///     datetime!(2017-01-01 0:01:09.184)
+    /// );
+    /// ```
+    pub fn to_tt(&self, datetime: OffsetDateTime) -> PrimitiveDateTime {
+        self.to_tai(datetime) + TT_MINUS_TAI
+    }
+
+    /// Convert the moment from [TT](https://en.wikipedia.org/wiki/Terrestrial_Time). The returned
+    /// value is in UTC.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the moment in UTC is before the start of [`Date::MIN`].
+    pub fn from_tt(&self, datetime: PrimitiveDateTime) -> OffsetDateTime {
+        self.from_tai(datetime - TT_MINUS_TAI)
+    }
+
+    /// Get the number of SI seconds elapsed from `start` to `end`, including any leap seconds
+    /// between them.
+    ///
+    /// # Panics
+    ///
+    /// This panics if either moment in TAI is after the end of [`Date::MAX`].
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, leap_seconds::LeapSecondTable, macros::datetime};
+    /// let start = datetime!(2016-12-31 23:59:59 UTC);
+    /// let end = datetime!(2017-01-01 0:00:01 UTC);
+    /// assert_eq!(end - start, 2.seconds());
+    /// assert_eq!(LeapSecondTable::BUILTIN.elapsed(start, end), 3.seconds());
+    /// ```
+    pub fn elapsed(&self, start: OffsetDateTime, end: OffsetDateTime) -> Duration {
+        self.to_tai(end) - self.to_tai(start)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl LeapSecondTable<Vec<Entry>> {
+    /// Parse a table in the format of the `leap-seconds.list` file distributed by the
+    /// [IERS](https://hpiers.obspm.fr/iers/bul/bulc/ntp/leap-seconds.list) and included in the IANA
+    /// time zone database.
+    ///
+    /// Each line contains an NTP timestamp and the difference between TAI and UTC from that
+    /// moment, optionally followed by a comment. The expiration date is taken from the line
+    /// starting with `#@`. All other comments are ignored.
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::LeapSecondTable, macros::date};
+    /// let table = LeapSecondTable::parse_leap_seconds_list(
+    ///     "#@\t4007404800\n\
+    ///      3692217600\t37\t# 1 Jan 2017\n",
+    /// )?;
+    /// assert_eq!(table.entries().len(), 1);
+    /// assert_eq!(table.entries()[0].date(), date!(2017 - 01 - 01));
+    /// assert_eq!(table.expires(), Some(date!(2026 - 12 - 28)));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_leap_seconds_list(input: &str) -> Result<Self, error::InvalidLeapSecondTable> {
+        /// Convert an NTP timestamp to the date it is the start of.
+        fn ntp_date(timestamp: &str) -> Option<Date> {
+            let timestamp = timestamp.parse::<i64>().ok()? - NTP_TO_UNIX;
+            let datetime = OffsetDateTime::from_unix_timestamp(timestamp).ok()?;
+            if datetime.time() == Time::MIDNIGHT {
+                Some(datetime.date())
+            } else {
+                None
+            }
+        }
+
+        let mut entries = Vec::new();
+        let mut expires = None;
+        for (index, line) in input.lines().enumerate() {
+            let invalid_line = error::InvalidLeapSecondTable::InvalidLine { line: index + 1 };
+            let line = line.trim();
+
+            if let Some(timestamp) = line.strip_prefix("#@") {
+                expires = Some(ntp_date(timestamp.trim()).ok_or(invalid_line)?);
+                continue;
+            }
+            let data = line
+                .find('#')
+                .map_or(line, |comment_start| &line[..comment_start]);
+            let mut fields = data.split_whitespace();
+            let (timestamp, tai_minus_utc) = match (fields.next(), fields.next(), fields.next()) {
+                (None, ..) => continue,
+                (Some(timestamp), Some(tai_minus_utc), None) => (timestamp, tai_minus_utc),
+                _ => return Err(invalid_line),
+            };
+            entries.push(Entry::new(
+                ntp_date(timestamp).ok_or(invalid_line)?,
+                tai_minus_utc.parse().map_err(|_| invalid_line)?,
+            ));
+        }
+
+        let table = Self::new(entries)?;
+        Ok(match expires {
+            Some(expires) => table.with_expiration(expires),
+            None => table,
+        })
+    }
+}
@@ -20,14 +20,21 @@
 //!
 //!   Enables macros that provide compile-time verification of values and intuitive syntax.
 //!
-//! - `formatting` (_implicitly enables `std`_)
+//! - `formatting`
 //!
-//!   Enables formatting of most structs.
+//!   Enables formatting of most structs. Formatting into a [`core::fmt::Write`] implementor or a
+//!   byte buffer is always available; formatting into a [`std::io::Write`] implementor requires
+//!   the `std` feature, and formatting into a `String` requires the `alloc` feature.
 //!
 //! - `parsing`
 //!
 //!   Enables parsing of most structs.
 //!
+//! - `relative-parsing` (_implicitly enables `parsing`_)
+//!
+//!   Enables parsing of natural-language expressions relative to a known moment, such as
+//!   "yesterday" or "3 days ago".
+//!
 //! - `local-offset` (_implicitly enables `std`_)
 //!
 //!   This feature enables a number of methods that allow obtaining the system's UTC offset.
@@ -45,11 +52,17 @@
 //!   [on this discussion](https://github.com/time-rs/time/discussions/306) with your use case. If
 //!   there is not sufficient demand for this feature, it will be dropped in a future release.
 //!
+//! - `locale`
+//!
+//!   Enables month, weekday, and period names in locales other than English. The names are a
+//!   subset of the [Unicode CLDR](https://cldr.unicode.org) data for German, Spanish, French,
+//!   Italian, Japanese, and Russian.
+//!
 //! - `serde`
 //!
 //!   Enables [serde](https://docs.rs/serde) support for all types.
 //!
-//! - `serde-human-readable` (_implicitly enables `serde`, `formatting`, and `parsing`_)
+//! - `serde-human-readable` (_implicitly enables `serde`, `formatting`, `parsing`, and `alloc`_)
 //!
 //!   Allows serde representations to use a human-readable format. This is determined by the
 //!   serializer, not the user. If this feature is not enabled or if the serializer requests a
@@ -272,10 +285,14 @@ pub mod format_description;
 #[cfg(feature = "formatting")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
 pub mod formatting;
+/// Human-friendly representations of durations, such as `1h30m` or `2d 4h`, and of relative times,
+/// such as `3 minutes ago` or `in 2 days`.
+pub mod humanize;
 /// The [`Instant`] struct and its associated `impl`s.
 #[cfg(feature = "std")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
 mod instant;
+pub mod leap_seconds;
 /// Macros to construct statically known values.
 #[cfg(feature = "macros")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "macros")))]
@@ -307,6 +324,8 @@ mod utc_offset;
 pub mod util;
 /// Days of the week.
 mod weekday;
+/// The [`ZonedDateTime`] struct and its associated `impl`s.
+mod zoned_date_time;
 
 pub use crate::date::Date;
 pub use crate::duration::Duration;
@@ -319,6 +338,7 @@ pub use crate::primitive_date_time::PrimitiveDateTime;
 pub use crate::time::Time;
 pub use crate::utc_offset::UtcOffset;
 pub use crate::weekday::Weekday;
+pub use crate::zoned_date_time::ZonedDateTime;
 
 /// An alias for [`std::result::Result`] with a generic error from the time crate.
 pub type Result<T> = core::result::Result<T, Error>;
@@ -46,7 +46,47 @@ pub use time_macros::datetime;
 /// # Ok::<_, time::Error>(())
 /// ```
 ///
+/// Version 2 of the syntax can be selected by passing `version = 2` before the format description.
+/// As the items are stored statically, optional and alternative items are supported, unlike with
+/// [`format_description::parse_borrowed`].
+///
+/// ```rust
+/// # use time::{macros::{format_description, time}, Time};
+/// let format = format_description!(version = 2, "[hour]:[minute][optional [:[second]]]");
+/// assert_eq!(Time::parse("12:05", &format)?, time!(12:05));
+/// assert_eq!(Time::parse("12:05:30", &format)?, time!(12:05:30));
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// A target type can be provided with `target = Type`, after the version if one is present. The
+/// format description is then rejected at compile time if it cannot be used to both format and
+/// parse a value of that type (only formatting is checked for `ZonedDateTime`), or if a component
+/// may replace a value set by an earlier component.
+///
+/// ```rust
+/// # use time::{macros::{datetime, format_description}, PrimitiveDateTime};
+/// let format = format_description!(
+///     target = PrimitiveDateTime,
+///     "[year]-[month]-[day] [hour]:[minute]"
+/// );
+/// assert_eq!(
+///     PrimitiveDateTime::parse("2021-01-02 03:04", &format)?,
+///     datetime!(2021-01-02 03:04)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// ```rust,compile_fail
+/// # use time::macros::format_description;
+/// // There is no hour, so a `PrimitiveDateTime` cannot be parsed.
+/// let format = format_description!(
+///     target = PrimitiveDateTime,
+///     "[year]-[month]-[day] [minute]"
+/// );
+/// ```
+///
 /// [`format_description::parse()`]: crate::format_description::parse()
+/// [`format_description::parse_borrowed`]: crate::format_description::parse_borrowed()
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 #[cfg_attr(
     __time_03_docs,
@@ -4,15 +4,22 @@ use core::convert::From;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::ops::{Add, AddAssign, Sub, SubAssign};
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting", feature = "std"))]
 use std::io;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
+
+#[cfg(all(feature = "formatting", feature = "std"))]
+use crate::formatting::output::IoOutput;
 #[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::formatting::output::{FmtOutput, SliceOutput, StrOutput};
+#[cfg(feature = "formatting")]
+use crate::formatting::{Display, Formattable};
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{Parsable, Parsed};
 use crate::{error, Date, Duration, Month, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 /// The Julian day of the Unix epoch.
@@ -246,17 +253,7 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn date(self) -> Date {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
-        let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
-        let mut hour = self.utc_datetime.hour() as i8 + self.offset.whole_hours();
-        let (mut year, mut ordinal) = self.utc_datetime.date.to_ordinal_date();
-
-        cascade!(second in 0..60 => minute);
-        cascade!(minute in 0..60 => hour);
-        cascade!(hour in 0..24 => ordinal);
-        cascade!(ordinal => year);
-
-        Date::__from_ordinal_date_unchecked(year, ordinal)
+        self.utc_datetime.utc_to_offset(self.offset).date()
     }
 
     /// Get the [`Time`] in the stored offset.
@@ -272,19 +269,7 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn time(self) -> Time {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
-        let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
-        let mut hour = self.utc_datetime.hour() as i8 + self.offset.whole_hours();
-
-        cascade!(second in 0..60 => minute);
-        cascade!(minute in 0..60 => hour);
-
-        Time::__from_hms_nanos_unchecked(
-            rem_euclid!(hour, 24) as _,
-            minute as _,
-            second as _,
-            self.utc_datetime.nanosecond(),
-        )
+        self.utc_datetime.utc_to_offset(self.offset).time()
     }
 
     // region: date getters
@@ -302,17 +287,7 @@ impl OffsetDateTime {
     /// assert_eq!(datetime!(2020-01-01 0:00 UTC).year(), 2020);
     /// ```
     pub const fn year(self) -> i32 {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
-        let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
-        let mut hour = self.utc_datetime.hour() as i8 + self.offset.whole_hours();
-        let (mut year, mut ordinal) = self.utc_datetime.date.to_ordinal_date();
-
-        cascade!(second in 0..60 => minute);
-        cascade!(minute in 0..60 => hour);
-        cascade!(hour in 0..24 => ordinal);
-        cascade!(ordinal => year);
-
-        year
+        self.utc_datetime.utc_to_offset(self.offset).year()
     }
 
     /// Get the month of the date in the stored offset.
@@ -367,17 +342,7 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn ordinal(self) -> u16 {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
-        let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
-        let mut hour = self.utc_datetime.hour() as i8 + self.offset.whole_hours();
-        let (mut year, mut ordinal) = self.utc_datetime.date.to_ordinal_date();
-
-        cascade!(second in 0..60 => minute);
-        cascade!(minute in 0..60 => hour);
-        cascade!(hour in 0..24 => ordinal);
-        cascade!(ordinal => year);
-
-        ordinal
+        self.utc_datetime.utc_to_offset(self.offset).ordinal()
     }
 
     /// Get the ISO week number of the date in the stored offset.
@@ -587,13 +552,7 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn hour(self) -> u8 {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
-        let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
-        let mut hour = self.utc_datetime.hour() as i8 + self.offset.whole_hours();
-
-        cascade!(second in 0..60 => minute);
-        cascade!(minute in 0..60 => hour);
-        rem_euclid!(hour, 24) as _
+        self.time().hour()
     }
 
     /// Get the minute within the hour in the stored offset.
@@ -611,16 +570,13 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn minute(self) -> u8 {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
-        let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
-
-        cascade!(second in 0..60 => minute);
-        rem_euclid!(minute, 60) as _
+        self.time().minute()
     }
 
     /// Get the second within the minute in the stored offset.
     ///
-    /// The returned value will always be in the range `0..60`.
+    /// The returned value will always be in the range `0..60`, unless the time is a
+    /// [leap second](Time::leap_second), in which case it is `60`.
     ///
     /// ```rust
     /// # use time::macros::{datetime, offset};
@@ -633,8 +589,7 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn second(self) -> u8 {
-        let second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
-        rem_euclid!(second, 60) as _
+        self.time().second()
     }
 
     // Because a `UtcOffset` is limited in resolution to one second, any subsecond value will not
@@ -781,6 +736,8 @@ impl OffsetDateTime {
     /// Format the `OffsetDateTime` using the provided format description. The formatted value will
     /// be output to the provided writer. The format description will typically be parsed by using
     /// [`format_description::parse`](crate::format_description::parse()).
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(all(feature = "formatting", feature = "std"))))]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -788,13 +745,145 @@ impl OffsetDateTime {
     ) -> Result<usize, error::Format> {
         let local = self.utc_datetime.utc_to_offset(self.offset);
         format.format_into(
-            output,
+            &mut IoOutput(output),
             Some(local.date),
             Some(local.time),
             Some(self.offset),
         )
     }
 
+    /// Format the `OffsetDateTime` using the provided format description. The formatted value will
+    /// be output to the provided [`fmt::Write`](core::fmt::Write) implementor, returning the
+    /// number of bytes written. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime};
+    /// let format = format_description::parse(
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour \
+    ///          sign:mandatory]:[offset_minute]:[offset_second]",
+    /// )?;
+    /// let mut output = String::new();
+    /// datetime!(2020-01-02 03:04:05 +06:07:08).format_into_fmt(&mut output, &format)?;
+    /// assert_eq!(output, "2020-01-02 03:04:05 +06:07:08");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        let local = self.utc_datetime.utc_to_offset(self.offset);
+        format.format_into(
+            &mut FmtOutput(output),
+            Some(local.date),
+            Some(local.time),
+            Some(self.offset),
+        )
+    }
+
+    /// Format the `OffsetDateTime` using the provided format description. The formatted value will
+    /// be written to the start of the provided buffer, returning the number of bytes written. If
+    /// the buffer is not large enough to hold the formatted value,
+    /// [`error::Format::InsufficientBufferSize`](crate::error::Format::InsufficientBufferSize) is
+    /// returned. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime};
+    /// let format = format_description::parse(
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour \
+    ///          sign:mandatory]:[offset_minute]:[offset_second]",
+    /// )?;
+    /// let mut buf = [0; 32];
+    /// let len = datetime!(2020-01-02 03:04:05 +06:07:08).format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], "2020-01-02 03:04:05 +06:07:08".as_bytes());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        let local = self.utc_datetime.utc_to_offset(self.offset);
+        format.format_into(
+            &mut SliceOutput::new(output),
+            Some(local.date),
+            Some(local.time),
+            Some(self.offset),
+        )
+    }
+
+    /// Format the `OffsetDateTime` into the provided buffer using the provided format description,
+    /// returning the formatted value as a string slice. A buffer that is always large enough can be
+    /// sized at compile time using [`max_formatted_len`](crate::formatting::max_formatted_len).
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime};
+    /// let format = format_description::parse(
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour \
+    ///          sign:mandatory]:[offset_minute]:[offset_second]",
+    /// )?;
+    /// let mut buf = [0; 32];
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05 +06:07:08).format_to_buf(&mut buf, &format)?,
+    ///     "2020-01-02 03:04:05 +06:07:08"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_buf<'a>(
+        self,
+        buf: &'a mut [u8],
+        format: &impl Formattable,
+    ) -> Result<&'a str, error::Format> {
+        let local = self.utc_datetime.utc_to_offset(self.offset);
+        let mut output = StrOutput::new(buf);
+        format.format_into(
+            &mut output,
+            Some(local.date),
+            Some(local.time),
+            Some(self.offset),
+        )?;
+        Ok(output.into_str())
+    }
+
+    /// Obtain a value that formats the `OffsetDateTime` using the provided format description
+    /// when displayed, such as via `write!` or `format_args!`. No allocation is performed. If the
+    /// value cannot be formatted, [`fmt::Error`] is returned when displaying it.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime};
+    /// let format = format_description::parse(
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour \
+    ///          sign:mandatory]:[offset_minute]:[offset_second]",
+    /// )?;
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05 +06:07:08)
+    ///         .display(&format)
+    ///         .to_string(),
+    ///     "2020-01-02 03:04:05 +06:07:08"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    pub fn display<F: Formattable + ?Sized>(self, format: &F) -> Display<'_, F> {
+        let local = self.utc_datetime.utc_to_offset(self.offset);
+        Display::new(
+            format,
+            Some(local.date),
+            Some(local.time),
+            Some(self.offset),
+        )
+    }
+
+    /// Obtain a value that formats the `OffsetDateTime` using the provided format description
+    /// when displayed. Unlike [`display`](Self::display), an error is returned immediately if the
+    /// value cannot be formatted.
+    pub fn try_display<F: Formattable + ?Sized>(
+        self,
+        format: &F,
+    ) -> Result<Display<'_, F>, error::Format> {
+        self.display(format).validate()
+    }
+
     /// Format the `OffsetDateTime` using the provided format description. The format description
     /// will typically be parsed by using
     /// [`format_description::parse`](crate::format_description::parse()).
@@ -811,6 +900,11 @@ impl OffsetDateTime {
     /// );
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(
+        __time_03_docs,
+        doc(cfg(all(feature = "formatting", feature = "alloc")))
+    )]
     pub fn format(self, format: &impl Formattable) -> Result<String, error::Format> {
         let local = self.utc_datetime.utc_to_offset(self.offset);
         format.format(Some(local.date), Some(local.time), Some(self.offset))
@@ -839,6 +933,48 @@ impl OffsetDateTime {
     pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
         description.parse_offset_date_time(input.as_bytes())
     }
+
+    /// Parse an `OffsetDateTime` from the start of the input using the provided format description,
+    /// returning it along with the remaining input. Unlike [`parse`](Self::parse), characters
+    /// following the parsed value are not an error.
+    ///
+    /// ```rust
+    /// # use time::{format_description::well_known::Rfc3339, macros::datetime, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_prefix("2020-01-02T03:04:05Z rest", &Rfc3339)?,
+    ///     (datetime!(2020-01-02 03:04:05 UTC), " rest")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &impl Parsable,
+    ) -> Result<(Self, &'a str), error::Parse> {
+        description.parse_prefix(input)
+    }
+
+    /// Parse an `OffsetDateTime` from the input using the provided format description, using the
+    /// provided defaults for any values that are not present in the input. See
+    /// [`Parsed::with_defaults`](crate::parsing::Parsed::with_defaults) for details on how the
+    /// defaults are applied.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, OffsetDateTime};
+    /// let format = format_description::parse("[hour]:[minute]")?;
+    /// let reference = datetime!(2021-05-03 00:00 +2);
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_with_defaults("14:30", &format, reference.into())?,
+    ///     datetime!(2021-05-03 14:30 +2)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_defaults(
+        input: &str,
+        description: &impl Parsable,
+        defaults: Parsed,
+    ) -> Result<Self, error::Parse> {
+        description.parse_with_defaults(input.as_bytes(), defaults)
+    }
 }
 
 impl fmt::Display for OffsetDateTime {
@@ -24,22 +24,48 @@ pub(crate) fn first_match<'a, 'b: 'a, T: Copy + 'a>(
 ) -> impl FnMut(&'b [u8]) -> Option<ParsedItem<'b, T>> {
     move |input| {
         options.find_map(|&(expected, t)| {
-            if case_sensitive {
-                Some(ParsedItem(input.strip_prefix(expected.as_bytes())?, t))
-            } else {
-                let n = expected.len();
-                if n <= input.len() {
-                    let (head, tail) = input.split_at(n);
-                    if head.eq_ignore_ascii_case(expected.as_bytes()) {
-                        return Some(ParsedItem(tail, t));
-                    }
-                }
-                None
-            }
+            Some(ParsedItem(
+                strip_prefix(input, expected.as_bytes(), case_sensitive)?,
+                t,
+            ))
+        })
+    }
+}
+
+/// Consume the first matching name, returning its index.
+pub(crate) fn first_match_index<'a>(
+    names: &'static [&'static str],
+    case_sensitive: bool,
+) -> impl Fn(&'a [u8]) -> Option<ParsedItem<'a, usize>> {
+    move |input| {
+        names.iter().enumerate().find_map(|(index, expected)| {
+            Some(ParsedItem(
+                strip_prefix(input, expected.as_bytes(), case_sensitive)?,
+                index,
+            ))
         })
     }
 }
 
+/// Remove the expected prefix from the input, ignoring ASCII case if requested.
+pub(crate) fn strip_prefix<'a>(
+    input: &'a [u8],
+    expected: &[u8],
+    case_sensitive: bool,
+) -> Option<&'a [u8]> {
+    if case_sensitive {
+        return input.strip_prefix(expected);
+    }
+    let n = expected.len();
+    if n <= input.len() {
+        let (head, tail) = input.split_at(n);
+        if head.eq_ignore_ascii_case(expected) {
+            return Some(tail);
+        }
+    }
+    None
+}
+
 /// Consume between `n` and `m` instances of the provided parser.
 pub(crate) fn n_to_m<'a, T>(
     n: u8,
@@ -77,7 +103,7 @@ pub(crate) fn n_to_m_digits<'a, T: Integer>(
     m: u8,
 ) -> impl Fn(&'a [u8]) -> Option<ParsedItem<'a, T>> {
     debug_assert!(m >= n);
-    move |input| n_to_m(n, m, any_digit)(input)?.flat_map(|value| value.parse_bytes())
+    move |input| n_to_m(n, m, any_digit)(input)?.flat_map(IntegerParseBytes::parse_bytes)
 }
 
 /// Consume exactly `n` digits, returning the numerical value.
@@ -1,28 +1,31 @@
 //! Parsing implementations for all [`Component`](crate::format_description::Component)s.
 
+use core::convert::TryFrom;
 use core::num::{NonZeroU16, NonZeroU8};
 
 use crate::format_description::modifier;
-#[cfg(feature = "large-dates")]
-use crate::parsing::combinator::n_to_m_digits_padded;
 use crate::parsing::combinator::{
-    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, opt, sign,
+    any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, exactly_n_digits_padded,
+    first_match, first_match_index, n_to_m, n_to_m_digits_padded, opt, sign,
 };
 use crate::parsing::ParsedItem;
-use crate::{Month, Weekday};
+use crate::{util, Month, Weekday};
 
 // region: date components
 /// Parse the "year" component of a `Date`.
 pub(crate) fn parse_year(input: &[u8], modifiers: modifier::Year) -> Option<ParsedItem<'_, i32>> {
+    // The maximum number of digits in a year, excluding the sign.
+    let max_digits = if cfg!(feature = "large-dates") { 6 } else { 4 };
+
     match modifiers.repr {
         modifier::YearRepr::Full => {
             let ParsedItem(input, sign) = opt(sign)(input);
-            #[cfg(not(feature = "large-dates"))]
-            let ParsedItem(input, year) =
-                exactly_n_digits_padded::<u32>(4, modifiers.padding)(input)?;
-            #[cfg(feature = "large-dates")]
-            let ParsedItem(input, year) =
-                n_to_m_digits_padded::<u32>(4, 6, modifiers.padding)(input)?;
+            let min_digits = modifiers.width.unwrap_or(4);
+            let ParsedItem(input, year) = n_to_m_digits_padded::<u32>(
+                min_digits,
+                max_digits.max(min_digits),
+                modifiers.padding,
+            )(input)?;
             match sign {
                 Some(b'-') => Some(ParsedItem(input, -(year as i32))),
                 None if modifiers.sign_is_mandatory || year >= 10_000 => None,
@@ -30,57 +33,65 @@ pub(crate) fn parse_year(input: &[u8], modifiers: modifier::Year) -> Option<Pars
             }
         }
         modifier::YearRepr::LastTwo => {
-            Some(exactly_n_digits_padded::<u32>(2, modifiers.padding)(input)?.map(|v| v as i32))
+            let min_digits = modifiers.width.unwrap_or(2);
+            n_to_m_digits_padded::<u32>(min_digits, min_digits.max(2), modifiers.padding)(input)?
+                .flat_map(|v| if v < 100 { Some(v as i32) } else { None })
+        }
+        modifier::YearRepr::OfEra => {
+            let min_digits = modifiers.width.unwrap_or(4);
+            Some(
+                n_to_m_digits_padded::<u32>(
+                    min_digits,
+                    max_digits.max(min_digits),
+                    modifiers.padding,
+                )(input)?
+                .map(|v| v as i32),
+            )
         }
     }
 }
 
+/// The months of the year, in the order of their names in the locale data.
+const MONTHS: [Month; 12] = [
+    Month::January,
+    Month::February,
+    Month::March,
+    Month::April,
+    Month::May,
+    Month::June,
+    Month::July,
+    Month::August,
+    Month::September,
+    Month::October,
+    Month::November,
+    Month::December,
+];
+
+/// The days of the week, in the order of their names in the locale data.
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
 /// Parse the "month" component of a `Date`.
 pub(crate) fn parse_month(
     input: &[u8],
     modifiers: modifier::Month,
 ) -> Option<ParsedItem<'_, Month>> {
-    use Month::*;
-    let ParsedItem(remaining, value) = first_match(
-        match modifiers.repr {
-            modifier::MonthRepr::Numerical => {
-                return exactly_n_digits_padded(2, modifiers.padding)(input)?
-                    .flat_map(|n| Month::from_number(n).ok());
-            }
-            modifier::MonthRepr::Long => [
-                ("January", January),
-                ("February", February),
-                ("March", March),
-                ("April", April),
-                ("May", May),
-                ("June", June),
-                ("July", July),
-                ("August", August),
-                ("September", September),
-                ("October", October),
-                ("November", November),
-                ("December", December),
-            ]
-            .iter(),
-            modifier::MonthRepr::Short => [
-                ("Jan", January),
-                ("Feb", February),
-                ("Mar", March),
-                ("Apr", April),
-                ("May", May),
-                ("Jun", June),
-                ("Jul", July),
-                ("Aug", August),
-                ("Sep", September),
-                ("Oct", October),
-                ("Nov", November),
-                ("Dec", December),
-            ]
-            .iter(),
-        },
-        modifiers.case_sensitive,
-    )(input)?;
-    Some(ParsedItem(remaining, value))
+    let names = match modifiers.repr {
+        modifier::MonthRepr::Numerical => {
+            return exactly_n_digits_padded(2, modifiers.padding)(input)?
+                .flat_map(|n| Month::from_number(n).ok());
+        }
+        modifier::MonthRepr::Long => modifiers.locale.month_names(false, modifiers.standalone),
+        modifier::MonthRepr::Short => modifiers.locale.month_names(true, modifiers.standalone),
+    };
+    Some(first_match_index(names, modifiers.case_sensitive)(input)?.map(|index| MONTHS[index]))
 }
 
 /// Parse the "week number" component of a `Date`.
@@ -96,28 +107,19 @@ pub(crate) fn parse_weekday(
     input: &[u8],
     modifiers: modifier::Weekday,
 ) -> Option<ParsedItem<'_, Weekday>> {
+    #[allow(clippy::unnested_or_patterns)]
     first_match(
         match (modifiers.repr, modifiers.one_indexed) {
-            (modifier::WeekdayRepr::Short, _) => [
-                ("Mon", Weekday::Monday),
-                ("Tue", Weekday::Tuesday),
-                ("Wed", Weekday::Wednesday),
-                ("Thu", Weekday::Thursday),
-                ("Fri", Weekday::Friday),
-                ("Sat", Weekday::Saturday),
-                ("Sun", Weekday::Sunday),
-            ]
-            .iter(),
-            (modifier::WeekdayRepr::Long, _) => [
-                ("Monday", Weekday::Monday),
-                ("Tuesday", Weekday::Tuesday),
-                ("Wednesday", Weekday::Wednesday),
-                ("Thursday", Weekday::Thursday),
-                ("Friday", Weekday::Friday),
-                ("Saturday", Weekday::Saturday),
-                ("Sunday", Weekday::Sunday),
-            ]
-            .iter(),
+            (modifier::WeekdayRepr::Short, _) | (modifier::WeekdayRepr::Long, _) => {
+                let short = modifiers.repr == modifier::WeekdayRepr::Short;
+                return Some(
+                    first_match_index(
+                        modifiers.locale.weekday_names(short),
+                        modifiers.case_sensitive,
+                    )(input)?
+                    .map(|index| WEEKDAYS[index]),
+                );
+            }
             (modifier::WeekdayRepr::Sunday, false) => [
                 ("1", Weekday::Monday),
                 ("2", Weekday::Tuesday),
@@ -176,7 +178,55 @@ pub(crate) fn parse_day(
     input: &[u8],
     modifiers: modifier::Day,
 ) -> Option<ParsedItem<'_, NonZeroU8>> {
-    exactly_n_digits_padded(2, modifiers.padding)(input)
+    let ParsedItem(mut input, day) =
+        exactly_n_digits_padded::<NonZeroU8>(2, modifiers.padding)(input)?;
+    if modifiers.ordinal_suffix {
+        input = input.strip_prefix(util::ordinal_suffix(day.get()).as_bytes())?;
+    }
+    Some(ParsedItem(input, day))
+}
+
+/// Parse the "quarter" component of a `Date`.
+pub(crate) fn parse_quarter(
+    input: &[u8],
+    modifiers: modifier::Quarter,
+) -> Option<ParsedItem<'_, NonZeroU8>> {
+    let input = match modifiers.repr {
+        modifier::QuarterRepr::Numerical => input,
+        modifier::QuarterRepr::Short => ascii_char(b'Q')(input)?.0,
+    };
+    exactly_n_digits::<NonZeroU8>(1)(input).filter(|quarter| quarter.1.get() <= 4)
+}
+
+/// Parse the "century" component of a `Date`. The returned boolean indicates whether the century
+/// is negative, as this is not represented by a century of zero.
+pub(crate) fn parse_century(
+    input: &[u8],
+    modifiers: modifier::Century,
+) -> Option<ParsedItem<'_, (i16, bool)>> {
+    let ParsedItem(input, sign) = opt(sign)(input);
+    // Digits often follow the century, so no more than the width can be read.
+    let ParsedItem(input, century) =
+        exactly_n_digits_padded::<u32>(modifiers.width.unwrap_or(2), modifiers.padding)(input)?;
+    // A wider century may not fit.
+    let century = i16::try_from(century).ok()?;
+    match sign {
+        Some(b'-') => Some(ParsedItem(input, (-century, true))),
+        None if modifiers.sign_is_mandatory => None,
+        _ => Some(ParsedItem(input, (century, false))),
+    }
+}
+
+/// Parse the "era" component of a `Date`. The returned boolean indicates whether the era is before
+/// the common era.
+pub(crate) fn parse_era(input: &[u8], modifiers: modifier::Era) -> Option<ParsedItem<'_, bool>> {
+    first_match(
+        match modifiers.repr {
+            modifier::EraRepr::Ad => [("AD", false), ("BC", true)].iter(),
+            modifier::EraRepr::Ce => [("CE", false), ("BCE", true)].iter(),
+        },
+        modifiers.case_sensitive,
+    )(input)
 }
 // endregion date components
 
@@ -216,14 +266,13 @@ pub(crate) fn parse_period(
     input: &[u8],
     modifiers: modifier::Period,
 ) -> Option<ParsedItem<'_, Period>> {
-    first_match(
-        if modifiers.is_uppercase {
-            [("AM", Period::Am), ("PM", Period::Pm)].iter()
-        } else {
-            [("am", Period::Am), ("pm", Period::Pm)].iter()
-        },
-        modifiers.case_sensitive,
-    )(input)
+    Some(
+        first_match_index(
+            modifiers.locale.period_names(modifiers.is_uppercase),
+            modifiers.case_sensitive,
+        )(input)?
+        .map(|index| if index == 0 { Period::Am } else { Period::Pm }),
+    )
 }
 
 /// Parse the "subsecond" component of a `Time`.
@@ -231,6 +280,28 @@ pub(crate) fn parse_subsecond(
     input: &[u8],
     modifiers: modifier::Subsecond,
 ) -> Option<ParsedItem<'_, u32>> {
+    if modifiers.trim_trailing_zeros {
+        let max_digits = match modifiers.digits {
+            modifier::SubsecondDigits::One => 1,
+            modifier::SubsecondDigits::Two => 2,
+            modifier::SubsecondDigits::Three => 3,
+            modifier::SubsecondDigits::Four => 4,
+            modifier::SubsecondDigits::Five => 5,
+            modifier::SubsecondDigits::Six => 6,
+            modifier::SubsecondDigits::Seven => 7,
+            modifier::SubsecondDigits::Eight => 8,
+            modifier::SubsecondDigits::Nine | modifier::SubsecondDigits::OneOrMore => 9,
+        };
+        let ParsedItem(input, digits) = n_to_m(0, max_digits, any_digit)(input)?;
+        let mut value = 0;
+        let mut multiplier = 100_000_000;
+        for digit in digits {
+            value += (digit - b'0') as u32 * multiplier;
+            multiplier /= 10;
+        }
+        return Some(ParsedItem(input, value));
+    }
+
     Some(match modifiers.digits {
         modifier::SubsecondDigits::One => exactly_n_digits(1)(input)?.map(|v: u32| v * 100_000_000),
         modifier::SubsecondDigits::Two => exactly_n_digits(2)(input)?.map(|v: u32| v * 10_000_000),
@@ -263,13 +334,23 @@ pub(crate) fn parse_subsecond(
 pub(crate) fn parse_offset_hour(
     input: &[u8],
     modifiers: modifier::OffsetHour,
-) -> Option<ParsedItem<'_, i8>> {
+) -> Option<ParsedItem<'_, (i8, bool)>> {
+    if modifiers.z_for_utc {
+        if let Some(ParsedItem(input, ())) = ascii_char_ignore_case(b'Z')(input) {
+            return Some(ParsedItem(input, (0, false)));
+        }
+    }
+
     let ParsedItem(input, sign) = opt(sign)(input);
-    let ParsedItem(input, hour) = exactly_n_digits_padded::<u8>(2, modifiers.padding)(input)?;
+    let min_digits = modifiers.width.unwrap_or(2);
+    let ParsedItem(input, hour) =
+        n_to_m_digits_padded::<u8>(min_digits, min_digits.max(2), modifiers.padding)(input)?;
+    // A wider hour may not fit, and it must be possible to negate it.
+    let hour = i8::try_from(hour).ok()?;
     match sign {
-        Some(b'-') => Some(ParsedItem(input, -(hour as i8))),
+        Some(b'-') => Some(ParsedItem(input, (-hour, true))),
         None if modifiers.sign_is_mandatory => None,
-        _ => Some(ParsedItem(input, hour as i8)),
+        _ => Some(ParsedItem(input, (hour, false))),
     }
 }
 
@@ -288,4 +369,119 @@ pub(crate) fn parse_offset_second(
 ) -> Option<ParsedItem<'_, u8>> {
     exactly_n_digits_padded(2, modifiers.padding)(input)
 }
+
+/// Parse the entire offset, returning the hour, minute, and second, along with whether the offset
+/// is negative. Any part that is not present is zero. The sign applies to the entire offset, which
+/// is necessary to distinguish offsets such as `-00:30` from `+00:30`.
+pub(crate) fn parse_offset(
+    input: &[u8],
+    modifiers: modifier::Offset,
+) -> Option<ParsedItem<'_, (i8, u8, u8, bool)>> {
+    if modifiers.z_for_utc {
+        if let Some(ParsedItem(input, ())) = ascii_char_ignore_case(b'Z')(input) {
+            return Some(ParsedItem(input, (0, 0, 0, false)));
+        }
+    }
+
+    let ParsedItem(input, sign) = sign(input)?;
+    let is_negative = sign == b'-';
+    let ParsedItem(mut input, hour) = exactly_n_digits::<u8>(2)(input)?;
+    let hour = if is_negative {
+        -(hour as i8)
+    } else {
+        hour as i8
+    };
+
+    // The second is only present if the minute is.
+    let mut values = [0; 2];
+    for (value, &presence) in values.iter_mut().zip(&[modifiers.minute, modifiers.second]) {
+        if presence == modifier::Presence::Omitted {
+            break;
+        }
+        let part = if modifiers.colon_separated {
+            ascii_char(b':')(input).map(|item| item.0)
+        } else {
+            Some(input)
+        }
+        .and_then(exactly_n_digits(2));
+        match (part, presence) {
+            (Some(ParsedItem(remaining, part)), _) => {
+                input = remaining;
+                *value = part;
+            }
+            (None, modifier::Presence::Mandatory) => return None,
+            (None, _) => break,
+        }
+    }
+    Some(ParsedItem(input, (hour, values[0], values[1], is_negative)))
+}
 // endregion offset components
+
+// region: time zone components
+/// Parse the "time zone" component, returning the text of the abbreviation or name.
+pub(crate) fn parse_time_zone(
+    input: &[u8],
+    modifiers: modifier::TimeZone,
+) -> Option<ParsedItem<'_, &[u8]>> {
+    #[allow(clippy::unnested_or_patterns)]
+    let len = match (modifiers.repr, *input.first()?) {
+        // Numeric abbreviations, such as "+0530", have either two or four digits.
+        (modifier::TimeZoneRepr::Abbreviation, b'+')
+        | (modifier::TimeZoneRepr::Abbreviation, b'-') => {
+            match input[1..].iter().take_while(|c| c.is_ascii_digit()).count() {
+                2 => 3,
+                4 => 5,
+                _ => return None,
+            }
+        }
+        (modifier::TimeZoneRepr::Abbreviation, _) => {
+            input.iter().take_while(|c| c.is_ascii_alphabetic()).count()
+        }
+        (modifier::TimeZoneRepr::Name, first) if first.is_ascii_alphabetic() => input
+            .iter()
+            .take_while(|&&c| c.is_ascii_alphanumeric() || matches!(c, b'/' | b'_' | b'+' | b'-'))
+            .count(),
+        (modifier::TimeZoneRepr::Name, _) => return None,
+    };
+
+    if len == 0 || len > modifiers.repr.max_len() {
+        return None;
+    }
+    Some(ParsedItem(&input[len..], &input[..len]))
+}
+// endregion time zone components
+
+// region: input components
+/// Parse the "whitespace" component, consuming one or more whitespace characters.
+pub(crate) fn parse_whitespace(
+    input: &[u8],
+    _: modifier::Whitespace,
+) -> Option<ParsedItem<'_, ()>> {
+    let len = input.iter().take_while(|c| c.is_ascii_whitespace()).count();
+    if len == 0 {
+        return None;
+    }
+    Some(ParsedItem(&input[len..], ()))
+}
+
+/// Parse the "ignore" component. A variable number of bytes consumes the remainder of the input.
+pub(crate) fn parse_ignore(
+    input: &[u8],
+    modifiers: modifier::Ignore,
+) -> Option<ParsedItem<'_, ()>> {
+    let count = modifiers.count.map_or(input.len(), |count| count as usize);
+    if count > input.len() {
+        return None;
+    }
+    Some(ParsedItem(&input[count..], ()))
+}
+
+/// Parse the "end" component, which only succeeds if no input remains.
+pub(crate) const fn parse_end(input: &[u8], _: modifier::End) -> Option<ParsedItem<'_, ()>> {
+    if input.is_empty() {
+        Some(ParsedItem(input, ()))
+    } else {
+        None
+    }
+}
+// endregion input components
@@ -4,10 +4,15 @@ pub(crate) mod combinator;
 mod component;
 pub(crate) mod parsable;
 mod parsed;
+#[cfg(feature = "relative-parsing")]
+mod relative;
 mod shim;
 
 pub use self::parsable::Parsable;
-pub use self::parsed::Parsed;
+pub use self::parsed::{LeapSecond, Parsed, TwoDigitYear};
+#[cfg(feature = "relative-parsing")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "relative-parsing")))]
+pub use self::relative::Interval;
 
 /// An item that has been parsed. Represented as a `(remaining, value)` pair.
 #[derive(Debug, Clone)]
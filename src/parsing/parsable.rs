@@ -1,6 +1,6 @@
 //! A trait that can be used to parse an item from an input.
 
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
 use core::ops::Deref;
 
 #[allow(unused_imports)]
@@ -8,7 +8,11 @@ use standback::prelude::*;
 
 use crate::error::TryFromParsed;
 use crate::format_description::well_known::Rfc3339;
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
 use crate::format_description::FormatItem;
+#[cfg(feature = "alloc")]
+use crate::parsing::combinator::strip_prefix;
 use crate::parsing::{Parsed, ParsedItem};
 use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 
@@ -16,6 +20,10 @@ use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffs
 pub trait Parsable: sealed::Sealed {}
 impl Parsable for FormatItem<'_> {}
 impl Parsable for [FormatItem<'_>] {}
+#[cfg(feature = "alloc")]
+impl Parsable for OwnedFormatItem {}
+#[cfg(feature = "alloc")]
+impl Parsable for [OwnedFormatItem] {}
 impl Parsable for Rfc3339 {}
 #[allow(clippy::use_self)]
 impl<T: Deref> Parsable for T where T::Target: Parsable {}
@@ -45,11 +53,51 @@ mod sealed {
         /// remain after parsing, an error will be returned.
         fn parse(&self, input: &[u8]) -> Result<Parsed, error::Parse> {
             let mut parsed = Parsed::new();
-            if self.parse_into(input, &mut parsed)?.is_empty() {
+            let remaining = self.parse_into(input, &mut parsed)?;
+            if remaining.is_empty() {
                 Ok(parsed)
             } else {
-                Err(error::Parse::UnexpectedTrailingCharacters)
+                Err(
+                    error::Parse::from(error::ParseFromDescription::UnexpectedTrailingCharacters)
+                        .with_location(input.len() - remaining.len(), None),
+                )
+            }
+        }
+
+        /// Parse a value from the start of the input, returning it along with the remaining
+        /// input. Characters following the parsed value are not considered an error.
+        fn parse_prefix<'a, T: TryFrom<Parsed, Error = TryFromParsed>>(
+            &self,
+            input: &'a str,
+        ) -> Result<(T, &'a str), error::Parse> {
+            let mut parsed = Parsed::new();
+            let position = input.len() - self.parse_into(input.as_bytes(), &mut parsed)?.len();
+            // A literal can only end in the middle of a character if it is not valid UTF-8.
+            let remaining = input.get(position..).ok_or_else(|| {
+                error::Parse::from(error::ParseFromDescription::InvalidLiteral)
+                    .with_location(position, None)
+            })?;
+            Ok((parsed.try_into()?, remaining))
+        }
+
+        /// Parse a value from the format description, using the provided defaults for any values
+        /// that are not present in the input.
+        fn parse_with_defaults<T: TryFrom<Parsed, Error = TryFromParsed>>(
+            &self,
+            input: &[u8],
+            defaults: Parsed,
+        ) -> Result<T, error::Parse> {
+            // The configuration is needed while parsing, as some formats handle values differently
+            // depending on it.
+            let mut parsed = defaults.configuration();
+            let remaining = self.parse_into(input, &mut parsed)?;
+            if !remaining.is_empty() {
+                return Err(
+                    error::Parse::from(error::ParseFromDescription::UnexpectedTrailingCharacters)
+                        .with_location(input.len() - remaining.len(), None),
+                );
             }
+            Ok(parsed.with_defaults(defaults).try_into()?)
         }
 
         /// Parse a [`Date`] from the format description.
@@ -83,32 +131,56 @@ mod sealed {
 impl sealed::Sealed for FormatItem<'_> {
     fn parse_into<'a>(
         &self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
-        match self {
-            Self::Literal(literal) => {
-                input = input
-                    .strip_prefix(*literal)
-                    .ok_or(error::ParseFromDescription::InvalidLiteral)?;
-            }
-            Self::Component(component) => input = parsed.parse_component(input, *component)?,
-            Self::Compound(compound) => input = compound.parse_into(input, parsed)?,
-        }
-        Ok(input)
+        parsed.parse_item(input, self)
     }
 }
 
 impl sealed::Sealed for [FormatItem<'_>] {
     fn parse_into<'a>(
         &self,
-        mut input: &'a [u8],
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        parsed.parse_items(input, self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for OwnedFormatItem {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
-        for item in self.iter() {
-            input = item.parse_into(input, parsed)?;
+        match self {
+            Self::Literal(literal) => input
+                .strip_prefix(&**literal)
+                .ok_or(error::ParseFromDescription::InvalidLiteral)
+                .map_err(|err| error::Parse::from(err).with_location(0, None)),
+            Self::CaseInsensitiveLiteral(literal) => strip_prefix(input, literal, false)
+                .ok_or(error::ParseFromDescription::InvalidLiteral)
+                .map_err(|err| error::Parse::from(err).with_location(0, None)),
+            Self::Component(component) => parsed
+                .parse_component(input, *component)
+                .map_err(|err| error::Parse::from(err).with_location(0, None)),
+            Self::Compound(items) => parsed.parse_sequence(input, items),
+            Self::Optional(item) => Ok(parsed.parse_optional(input, &**item)),
+            Self::First(items) => parsed.parse_first(input, items),
         }
-        Ok(input)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for [OwnedFormatItem] {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        parsed.parse_sequence(input, self)
     }
 }
 
@@ -139,43 +211,60 @@ impl sealed::Sealed for Rfc3339 {
             any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
         };
 
+        let original_len = input.len();
+        let located = |error, remaining: &[u8]| {
+            error::Parse::from(error).with_location(original_len - remaining.len(), None)
+        };
         let dash = ascii_char(b'-');
         let colon = ascii_char(b':');
+        let leap_second = parsed.leap_second();
 
         let input = exactly_n_digits(4)(input)
-            .ok_or(InvalidComponent("year"))?
+            .ok_or_else(|| located(InvalidComponent("year"), input))?
             .assign_value_to_with(&mut parsed.year, |year: u32| year as i32);
-        let input = dash(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = dash(input)
+            .ok_or_else(|| located(InvalidLiteral, input))?
+            .unwrap();
         let input = exactly_n_digits(2)(input)
-            .ok_or(InvalidComponent("month"))?
+            .ok_or_else(|| located(InvalidComponent("month"), input))?
             .flat_map_res(Month::from_number)
             .map_err(error::TryFromParsed::ComponentRange)?
             .assign_value_to(&mut parsed.month);
-        let input = dash(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = dash(input)
+            .ok_or_else(|| located(InvalidLiteral, input))?
+            .unwrap();
         let input = exactly_n_digits(2)(input)
-            .ok_or(InvalidComponent("day"))?
+            .ok_or_else(|| located(InvalidComponent("day"), input))?
             .assign_value_to(&mut parsed.day);
         let input = ascii_char_ignore_case(b'T')(input)
-            .ok_or(InvalidLiteral)?
+            .ok_or_else(|| located(InvalidLiteral, input))?
             .unwrap();
         let input = exactly_n_digits(2)(input)
-            .ok_or(InvalidComponent("hour"))?
+            .ok_or_else(|| located(InvalidComponent("hour"), input))?
             .assign_value_to(&mut parsed.hour_24);
-        let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = colon(input)
+            .ok_or_else(|| located(InvalidLiteral, input))?
+            .unwrap();
         let input = exactly_n_digits(2)(input)
-            .ok_or(InvalidComponent("minute"))?
+            .ok_or_else(|| located(InvalidComponent("minute"), input))?
             .assign_value_to(&mut parsed.minute);
-        let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = colon(input)
+            .ok_or_else(|| located(InvalidLiteral, input))?
+            .unwrap();
         let input = exactly_n_digits(2)(input)
-            .ok_or(InvalidComponent("second"))?
+            .ok_or_else(|| located(InvalidComponent("second"), input))?
             .assign_value_to_with(&mut parsed.second, |second| {
-                // The RFC explicitly allows leap seconds. We don't support them, so treat it as
-                // the previous second.
-                if second == 60 { 59 } else { second }
+                // The RFC explicitly allows leap seconds. Unless their handling is configured,
+                // treat it as the previous second.
+                if second == 60 && leap_second.is_none() {
+                    59
+                } else {
+                    second
+                }
             });
         let input = if let Some(ParsedItem(input, ())) = ascii_char(b'.')(input) {
             let ParsedItem(mut input, mut value) = any_digit(input)
-                .ok_or(InvalidComponent("subsecond"))?
+                .ok_or_else(|| located(InvalidComponent("subsecond"), input))?
                 .map(|v| (v - b'0') as u32 * 100_000_000);
 
             let mut multiplier = 10_000_000;
@@ -197,9 +286,10 @@ impl sealed::Sealed for Rfc3339 {
             return Ok(input);
         }
 
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset_hour"))?;
+        let ParsedItem(input, offset_sign) =
+            sign(input).ok_or_else(|| located(InvalidComponent("offset_hour"), input))?;
         let input = exactly_n_digits(2)(input)
-            .ok_or(InvalidComponent("offset_hour"))?
+            .ok_or_else(|| located(InvalidComponent("offset_hour"), input))?
             .assign_value_to_with(&mut parsed.offset_hour, |offset_hour: u8| {
                 if offset_sign == b'-' {
                     -(offset_hour as i8)
@@ -207,9 +297,11 @@ impl sealed::Sealed for Rfc3339 {
                     offset_hour as _
                 }
             });
-        let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = colon(input)
+            .ok_or_else(|| located(InvalidLiteral, input))?
+            .unwrap();
         let input = exactly_n_digits(2)(input)
-            .ok_or(InvalidComponent("offset_minute"))?
+            .ok_or_else(|| located(InvalidComponent("offset_minute"), input))?
             .assign_value_to(&mut parsed.offset_minute);
 
         Ok(input)
@@ -221,31 +313,45 @@ impl sealed::Sealed for Rfc3339 {
             any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
         };
 
+        let original_len = input.len();
+        let located = |error, remaining: &[u8]| {
+            error::Parse::from(error).with_location(original_len - remaining.len(), None)
+        };
         let dash = ascii_char(b'-');
         let colon = ascii_char(b':');
 
-        let ParsedItem(input, year) =
-            exactly_n_digits::<u32>(4)(input).ok_or(InvalidComponent("year"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.unwrap();
+        let ParsedItem(input, year) = exactly_n_digits::<u32>(4)(input)
+            .ok_or_else(|| located(InvalidComponent("year"), input))?;
+        let input = dash(input)
+            .ok_or_else(|| located(InvalidLiteral, input))?
+            .unwrap();
         let ParsedItem(input, month) =
-            exactly_n_digits(2)(input).ok_or(InvalidComponent("month"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.unwrap();
-        let ParsedItem(input, day) = exactly_n_digits(2)(input).ok_or(InvalidComponent("day"))?;
+            exactly_n_digits(2)(input).ok_or_else(|| located(InvalidComponent("month"), input))?;
+        let input = dash(input)
+            .ok_or_else(|| located(InvalidLiteral, input))?
+            .unwrap();
+        let ParsedItem(input, day) =
+            exactly_n_digits(2)(input).ok_or_else(|| located(InvalidComponent("day"), input))?;
         let input = ascii_char_ignore_case(b'T')(input)
-            .ok_or(InvalidLiteral)?
+            .ok_or_else(|| located(InvalidLiteral, input))?
+            .unwrap();
+        let ParsedItem(input, hour) =
+            exactly_n_digits(2)(input).ok_or_else(|| located(InvalidComponent("hour"), input))?;
+        let input = colon(input)
+            .ok_or_else(|| located(InvalidLiteral, input))?
             .unwrap();
-        let ParsedItem(input, hour) = exactly_n_digits(2)(input).ok_or(InvalidComponent("hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
         let ParsedItem(input, minute) =
-            exactly_n_digits(2)(input).ok_or(InvalidComponent("minute"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
+            exactly_n_digits(2)(input).ok_or_else(|| located(InvalidComponent("minute"), input))?;
+        let input = colon(input)
+            .ok_or_else(|| located(InvalidLiteral, input))?
+            .unwrap();
         let ParsedItem(input, second) = exactly_n_digits(2)(input)
-            .ok_or(InvalidComponent("second"))?
+            .ok_or_else(|| located(InvalidComponent("second"), input))?
             .map(|seconds| if seconds == 60 { 59 } else { seconds });
         let ParsedItem(input, nanosecond) =
             if let Some(ParsedItem(input, ())) = ascii_char(b'.')(input) {
                 let ParsedItem(mut input, mut value) = any_digit(input)
-                    .ok_or(InvalidComponent("subsecond"))?
+                    .ok_or_else(|| located(InvalidComponent("subsecond"), input))?
                     .map(|v| (v - b'0') as u32 * 100_000_000);
 
                 let mut multiplier = 10_000_000;
@@ -264,12 +370,14 @@ impl sealed::Sealed for Rfc3339 {
                 ParsedItem(input, UtcOffset::UTC)
             } else {
                 let ParsedItem(input, offset_sign) =
-                    sign(input).ok_or(InvalidComponent("offset_hour"))?;
-                let ParsedItem(input, offset_hour) =
-                    exactly_n_digits::<u8>(2)(input).ok_or(InvalidComponent("offset_hour"))?;
-                let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
-                let ParsedItem(input, offset_minute) =
-                    exactly_n_digits::<u8>(2)(input).ok_or(InvalidComponent("offset_minute"))?;
+                    sign(input).ok_or_else(|| located(InvalidComponent("offset_hour"), input))?;
+                let ParsedItem(input, offset_hour) = exactly_n_digits::<u8>(2)(input)
+                    .ok_or_else(|| located(InvalidComponent("offset_hour"), input))?;
+                let input = colon(input)
+                    .ok_or_else(|| located(InvalidLiteral, input))?
+                    .unwrap();
+                let ParsedItem(input, offset_minute) = exactly_n_digits::<u8>(2)(input)
+                    .ok_or_else(|| located(InvalidComponent("offset_minute"), input))?;
                 ParsedItem(
                     input,
                     UtcOffset::from_hms(
@@ -287,7 +395,10 @@ impl sealed::Sealed for Rfc3339 {
         };
 
         if !input.is_empty() {
-            return Err(error::Parse::UnexpectedTrailingCharacters);
+            return Err(
+                error::Parse::from(error::ParseFromDescription::UnexpectedTrailingCharacters)
+                    .with_location(original_len - input.len(), None),
+            );
         }
 
         Ok(Date::from_calendar_date(
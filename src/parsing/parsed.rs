@@ -1,18 +1,146 @@
 //! Information parsed from an input and format description.
 
 use core::convert::{TryFrom, TryInto};
-use core::num::{NonZeroU16, NonZeroU8};
+use core::fmt;
+use core::num::{NonZeroU16, NonZeroU32, NonZeroU8};
 
-use crate::error::TryFromParsed::InsufficientInformation;
-use crate::format_description::modifier::{WeekNumberRepr, YearRepr};
-use crate::format_description::Component;
+#[allow(unused_imports)]
+use standback::prelude::*;
+
+use crate::date::{MAX_YEAR, MIN_YEAR};
+use crate::error::ParseFromDescription::InvalidComponent;
+use crate::error::TryFromParsed::{InconsistentInformation, InsufficientInformation};
+use crate::format_description::modifier::{self, TimeZoneRepr, WeekNumberRepr, YearRepr};
+use crate::format_description::{offset_remainder_len, Component, FormatItem, SequenceItem};
+use crate::parsing::combinator::strip_prefix;
 use crate::parsing::component::{
-    parse_day, parse_hour, parse_minute, parse_month, parse_offset_hour, parse_offset_minute,
-    parse_offset_second, parse_ordinal, parse_period, parse_second, parse_subsecond,
-    parse_week_number, parse_weekday, parse_year, Period,
+    parse_century, parse_day, parse_end, parse_era, parse_hour, parse_ignore, parse_minute,
+    parse_month, parse_offset, parse_offset_hour, parse_offset_minute, parse_offset_second,
+    parse_ordinal, parse_period, parse_quarter, parse_second, parse_subsecond, parse_time_zone,
+    parse_week_number, parse_weekday, parse_whitespace, parse_year, Period,
+};
+use crate::parsing::{Parsable, ParsedItem};
+use crate::util::DateAdjustment;
+use crate::{
+    error, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
 };
-use crate::parsing::ParsedItem;
-use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+/// How a year with only its last two digits known is resolved to a full year.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoDigitYear {
+    /// The year is in the provided century, where century 20 contains the years 2000 through
+    /// 2099.
+    Century(i32),
+    /// The year is in the 100-year period starting at the provided year. A pivot of 1950 resolves
+    /// `50` to 1950 and `49` to 2049.
+    Pivot(i32),
+    /// The year is in the 100-year period ending the provided number of years after the reference
+    /// year. This is typically used with the current year as the reference.
+    Window {
+        /// The year the window is relative to.
+        reference_year: i32,
+        /// The maximum number of years after the reference year that a year can resolve to. Any
+        /// value greater than 99 is treated as 99.
+        years_ahead: u8,
+    },
+}
+
+impl TwoDigitYear {
+    /// Resolve the last two digits of a year to a full year.
+    ///
+    /// ```rust
+    /// # use time::parsing::TwoDigitYear;
+    /// assert_eq!(TwoDigitYear::Century(19).resolve(21), 1921);
+    /// assert_eq!(TwoDigitYear::Pivot(1970).resolve(69), 2069);
+    /// assert_eq!(TwoDigitYear::Pivot(1970).resolve(70), 1970);
+    /// assert_eq!(
+    ///     TwoDigitYear::Window {
+    ///         reference_year: 2021,
+    ///         years_ahead: 20,
+    ///     }
+    ///     .resolve(42),
+    ///     1942
+    /// );
+    /// ```
+    pub fn resolve(self, last_two: u8) -> i32 {
+        let pivot = match self {
+            Self::Century(century) => {
+                return century.saturating_mul(100).saturating_add(last_two as _);
+            }
+            Self::Pivot(pivot) => pivot,
+            Self::Window {
+                reference_year,
+                years_ahead,
+            } => reference_year
+                .saturating_add(years_ahead.min(99) as _)
+                .saturating_sub(99),
+        };
+        let offset = (last_two as i32 - pivot % 100).rem_euclid(100);
+        pivot.saturating_add(offset)
+    }
+}
+
+/// How a positive leap second, having the second `60`, is handled when converting to another
+/// type.
+///
+/// Without this, a leap second is rejected as being out of range. The only exception is when
+/// parsing [`Rfc3339`](crate::format_description::well_known::Rfc3339), which treats a leap
+/// second as the previous second for backwards compatibility.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeapSecond {
+    /// The leap second is treated as the previous second, such that `23:59:60.5` becomes
+    /// `23:59:59.5`.
+    Clamp,
+    /// The leap second is treated as the following second, such that `23:59:60.5` becomes
+    /// `00:00:00.5` on the next day.
+    Fold,
+    /// The leap second is preserved, resulting in a [`Time`] for which
+    /// [`is_leap_second`](Time::is_leap_second) returns `true`.
+    Preserve,
+}
+
+/// The text of a time zone abbreviation or name, stored inline so that [`Parsed`] remains `Copy`.
+#[derive(Clone, Copy)]
+struct TimeZoneText {
+    /// The bytes of the text, of which only the first `len` are used.
+    bytes: [u8; TimeZoneRepr::Name.max_len()],
+    /// The length of the text in bytes.
+    len: u8,
+}
+
+impl TimeZoneText {
+    /// Store the provided text, which must be ASCII and no longer than the capacity.
+    fn new(text: &[u8]) -> Self {
+        let mut bytes = [0; TimeZoneRepr::Name.max_len()];
+        bytes[..text.len()].copy_from_slice(text);
+        Self {
+            bytes,
+            len: text.len() as u8,
+        }
+    }
+
+    /// Store the provided text if it can be parsed as the given representation in its entirety.
+    fn parse(text: &str, repr: TimeZoneRepr) -> Result<Self, error::ParseFromDescription> {
+        match parse_time_zone(text.as_bytes(), modifier::TimeZone { repr }) {
+            Some(ParsedItem(b"", text)) => Ok(Self::new(text)),
+            _ => Err(InvalidComponent("time zone")),
+        }
+    }
+
+    /// Obtain the text as a string slice.
+    fn as_str(&self) -> &str {
+        // The text is only ever created from ASCII bytes.
+        core::str::from_utf8(&self.bytes[..self.len as usize]).unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for TimeZoneText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
 
 /// All information parsed.
 ///
@@ -20,6 +148,10 @@ use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffs
 ///
 /// Most users will not need think about this struct in any way. It is public to allow for manual
 /// control over values, in the instance that the default parser is insufficient.
+///
+/// By default, values that are not needed to construct a type are ignored when converting to that
+/// type, even if they contradict other values. When strict checking is enabled via
+/// [`with_strict`](Self::with_strict), such contradictions result in an error.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
 pub struct Parsed {
@@ -27,12 +159,20 @@ pub struct Parsed {
     pub year: Option<i32>,
     /// The last two digits of the calendar year.
     pub year_last_two: Option<u8>,
+    /// Century of the calendar year, being the year with its last two digits removed.
+    pub century: Option<i16>,
+    /// Year within the era. This is used in conjunction with the `era_is_bce` field.
+    pub year_of_era: Option<NonZeroU32>,
+    /// Whether the year is before the common era (BC or BCE).
+    pub era_is_bce: Option<bool>,
     /// Year of the [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date).
     pub iso_year: Option<i32>,
     /// The last two digits of the ISO week year.
     pub iso_year_last_two: Option<u8>,
     /// Month of the year.
     pub month: Option<Month>,
+    /// Quarter of the year.
+    pub quarter: Option<NonZeroU8>,
     /// Week of the year, where week one begins on the first Sunday of the calendar year.
     pub sunday_week_number: Option<u8>,
     /// Week of the year, where week one begins on the first Monday of the calendar year.
@@ -64,6 +204,52 @@ pub struct Parsed {
     pub offset_minute: Option<u8>,
     /// Seconds within the minute of the UTC offset.
     pub offset_second: Option<u8>,
+    /// Abbreviation of the time zone, such as "PST".
+    time_zone_abbreviation: Option<TimeZoneText>,
+    /// Name of the time zone in the IANA time zone database, such as `America/Los_Angeles`.
+    time_zone_name: Option<TimeZoneText>,
+    /// Whether the century was parsed with a negative sign. This is necessary to distinguish the
+    /// years -99 through -1 from the years 0 through 99.
+    century_is_negative: bool,
+    /// Whether the UTC offset was parsed with a negative sign. This is necessary to distinguish
+    /// offsets such as `-00:30` from `+00:30`, where the hour is zero.
+    offset_is_negative: bool,
+    /// Whether redundant values are checked for consistency when converting to another type.
+    strict: bool,
+    /// How years with only their last two digits known are resolved to a full year.
+    two_digit_year: Option<TwoDigitYear>,
+    /// How a leap second is handled.
+    leap_second: Option<LeapSecond>,
+}
+
+/// Generate a getter, setter, and builder method for each field. The provided description is
+/// used to document the methods.
+macro_rules! accessors {
+    ($($field:ident, $set:ident, $with:ident: $ty:ty, $description:literal;)*) => {$(
+        #[doc = "Obtain the"]
+        #[doc = $description]
+        #[doc = ", if it is known."]
+        pub const fn $field(&self) -> Option<$ty> {
+            self.$field
+        }
+
+        #[doc = "Set the"]
+        #[doc = $description]
+        #[doc = "."]
+        #[allow(clippy::missing_const_for_fn)] // mutable references in const fn are unstable
+        pub fn $set(&mut self, value: $ty) {
+            self.$field = Some(value);
+        }
+
+        #[doc = "Set the"]
+        #[doc = $description]
+        #[doc = ", returning the updated struct."]
+        #[must_use = "This method does not mutate the original `Parsed`."]
+        pub const fn $with(mut self, value: $ty) -> Self {
+            self.$field = Some(value);
+            self
+        }
+    )*};
 }
 
 impl Parsed {
@@ -72,9 +258,13 @@ impl Parsed {
         Self {
             year: None,
             year_last_two: None,
+            century: None,
+            year_of_era: None,
+            era_is_bce: None,
             iso_year: None,
             iso_year_last_two: None,
             month: None,
+            quarter: None,
             sunday_week_number: None,
             monday_week_number: None,
             iso_week_number: None,
@@ -90,18 +280,354 @@ impl Parsed {
             offset_hour: None,
             offset_minute: None,
             offset_second: None,
+            time_zone_abbreviation: None,
+            time_zone_name: None,
+            century_is_negative: false,
+            offset_is_negative: false,
+            strict: false,
+            two_digit_year: None,
+            leap_second: None,
+        }
+    }
+
+    accessors! {
+        year, set_year, with_year: i32, "calendar year";
+        year_last_two, set_year_last_two, with_year_last_two: u8,
+            "last two digits of the calendar year";
+        century, set_century, with_century: i16, "century of the calendar year";
+        year_of_era, set_year_of_era, with_year_of_era: NonZeroU32, "year within the era";
+        era_is_bce, set_era_is_bce, with_era_is_bce: bool,
+            "whether the year is before the common era";
+        iso_year, set_iso_year, with_iso_year: i32, "year of the ISO week date";
+        iso_year_last_two, set_iso_year_last_two, with_iso_year_last_two: u8,
+            "last two digits of the ISO week year";
+        month, set_month, with_month: Month, "month of the year";
+        quarter, set_quarter, with_quarter: NonZeroU8, "quarter of the year";
+        sunday_week_number, set_sunday_week_number, with_sunday_week_number: u8,
+            "Sunday-based week of the year";
+        monday_week_number, set_monday_week_number, with_monday_week_number: u8,
+            "Monday-based week of the year";
+        iso_week_number, set_iso_week_number, with_iso_week_number: NonZeroU8,
+            "ISO week of the year";
+        weekday, set_weekday, with_weekday: Weekday, "day of the week";
+        ordinal, set_ordinal, with_ordinal: NonZeroU16, "day of the year";
+        day, set_day, with_day: NonZeroU8, "day of the month";
+        hour_24, set_hour_24, with_hour_24: u8, "hour within the day";
+        hour_12, set_hour_12, with_hour_12: NonZeroU8, "hour within the 12-hour period";
+        hour_12_is_pm, set_hour_12_is_pm, with_hour_12_is_pm: bool,
+            "whether the 12-hour period is PM";
+        minute, set_minute, with_minute: u8, "minute within the hour";
+        second, set_second, with_second: u8, "second within the minute";
+        subsecond, set_subsecond, with_subsecond: u32, "nanosecond within the second";
+        offset_hour, set_offset_hour, with_offset_hour: i8, "whole hours of the UTC offset";
+        offset_minute, set_offset_minute, with_offset_minute: u8,
+            "minutes within the hour of the UTC offset";
+        offset_second, set_offset_second, with_offset_second: u8,
+            "seconds within the minute of the UTC offset";
+    }
+
+    /// Obtain the abbreviation of the time zone, such as "PST", if it is known.
+    ///
+    /// The abbreviations "UTC", "GMT", "UT", and "Z" are resolved to a UTC offset of zero when no
+    /// other information about the offset is known.
+    pub fn time_zone_abbreviation(&self) -> Option<&str> {
+        self.time_zone_abbreviation
+            .as_ref()
+            .map(TimeZoneText::as_str)
+    }
+
+    /// Set the abbreviation of the time zone, such as "PST".
+    ///
+    /// The abbreviation must be one that the `time_zone` component would parse: up to six ASCII
+    /// letters, or a sign followed by two or four digits. Otherwise, an error is returned and the
+    /// value is left unchanged.
+    pub fn set_time_zone_abbreviation(
+        &mut self,
+        abbreviation: &str,
+    ) -> Result<(), error::ParseFromDescription> {
+        self.time_zone_abbreviation = Some(TimeZoneText::parse(
+            abbreviation,
+            TimeZoneRepr::Abbreviation,
+        )?);
+        Ok(())
+    }
+
+    /// Set the abbreviation of the time zone, such as "PST", returning the updated struct.
+    ///
+    /// The abbreviation must be one that the `time_zone` component would parse: up to six ASCII
+    /// letters, or a sign followed by two or four digits.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// let parsed = Parsed::new().with_time_zone_abbreviation("PST")?;
+    /// assert_eq!(parsed.time_zone_abbreviation(), Some("PST"));
+    /// assert!(Parsed::new().with_time_zone_abbreviation("P S T").is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn with_time_zone_abbreviation(
+        mut self,
+        abbreviation: &str,
+    ) -> Result<Self, error::ParseFromDescription> {
+        self.set_time_zone_abbreviation(abbreviation)?;
+        Ok(self)
+    }
+
+    /// Obtain the name of the time zone in the IANA time zone database, such as
+    /// `America/Los_Angeles`, if it is known.
+    ///
+    /// The names "UTC", "GMT", "Etc/UTC", and "Etc/GMT" are resolved to a UTC offset of zero when
+    /// no other information about the offset is known.
+    pub fn time_zone_name(&self) -> Option<&str> {
+        self.time_zone_name.as_ref().map(TimeZoneText::as_str)
+    }
+
+    /// Set the name of the time zone in the IANA time zone database, such as
+    /// `America/Los_Angeles`.
+    ///
+    /// The name must be one that the `time_zone repr:name` component would parse: up to 32 ASCII
+    /// characters, starting with a letter and otherwise consisting of alphanumerics, `/`, `_`, `+`,
+    /// and `-`. Otherwise, an error is returned and the value is left unchanged.
+    pub fn set_time_zone_name(&mut self, name: &str) -> Result<(), error::ParseFromDescription> {
+        self.time_zone_name = Some(TimeZoneText::parse(name, TimeZoneRepr::Name)?);
+        Ok(())
+    }
+
+    /// Set the name of the time zone in the IANA time zone database, such as
+    /// `America/Los_Angeles`, returning the updated struct.
+    ///
+    /// The name must be one that the `time_zone repr:name` component would parse: up to 32 ASCII
+    /// characters, starting with a letter and otherwise consisting of alphanumerics, `/`, `_`, `+`,
+    /// and `-`.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// let parsed = Parsed::new().with_time_zone_name("America/Los_Angeles")?;
+    /// assert_eq!(parsed.time_zone_name(), Some("America/Los_Angeles"));
+    /// assert!(Parsed::new().with_time_zone_name("Los Angeles").is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn with_time_zone_name(mut self, name: &str) -> Result<Self, error::ParseFromDescription> {
+        self.set_time_zone_name(name)?;
+        Ok(self)
+    }
+
+    /// The UTC offset of the time zone, if it is well-known to always be UTC.
+    fn time_zone_offset(&self) -> Option<UtcOffset> {
+        #[allow(clippy::unnested_or_patterns)]
+        match (self.time_zone_abbreviation(), self.time_zone_name()) {
+            (Some("UTC"), _)
+            | (Some("GMT"), _)
+            | (Some("UT"), _)
+            | (Some("Z"), _)
+            | (_, Some("UTC"))
+            | (_, Some("GMT"))
+            | (_, Some("Etc/UTC"))
+            | (_, Some("Etc/GMT")) => Some(UtcOffset::UTC),
+            _ => None,
         }
     }
 
+    /// Whether redundant values are checked for consistency when converting to another type.
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Set whether redundant values are checked for consistency when converting to another type.
+    #[allow(clippy::missing_const_for_fn)] // mutable references in const fn are unstable
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Set whether redundant values are checked for consistency when converting to another type,
+    /// returning the updated struct.
+    ///
+    /// When enabled, values that are not needed to construct the type must agree with the
+    /// constructed value. For example, a weekday must match the weekday of the date constructed
+    /// from the year, month, and day.
+    ///
+    /// ```rust
+    /// # use core::convert::TryFrom;
+    /// # use core::num::NonZeroU8;
+    /// # use time::{error::TryFromParsed, parsing::Parsed, Date, Month, Weekday};
+    /// let parsed = Parsed::new()
+    ///     .with_year(2021)
+    ///     .with_month(Month::January)
+    ///     .with_day(NonZeroU8::new(1).unwrap())
+    ///     .with_weekday(Weekday::Monday);
+    /// assert!(Date::try_from(parsed).is_ok());
+    /// assert_eq!(
+    ///     Date::try_from(parsed.with_strict(true)),
+    ///     Err(TryFromParsed::InconsistentInformation { component: "weekday" })
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `Parsed`."]
+    pub const fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// How years with only their last two digits known are resolved to a full year, if
+    /// configured.
+    pub const fn two_digit_year(&self) -> Option<TwoDigitYear> {
+        self.two_digit_year
+    }
+
+    /// Set how years with only their last two digits known are resolved to a full year.
+    #[allow(clippy::missing_const_for_fn)] // mutable references in const fn are unstable
+    pub fn set_two_digit_year(&mut self, resolution: TwoDigitYear) {
+        self.two_digit_year = Some(resolution);
+    }
+
+    /// Set how years with only their last two digits known are resolved to a full year, returning
+    /// the updated struct.
+    ///
+    /// Without this, the last two digits of the calendar year or ISO week year are not sufficient
+    /// to construct a [`Date`]. The full year takes precedence if it is known.
+    ///
+    /// ```rust
+    /// # use core::convert::TryFrom;
+    /// # use core::num::NonZeroU8;
+    /// # use time::{macros::date, parsing::{Parsed, TwoDigitYear}, Date, Month};
+    /// let parsed = Parsed::new()
+    ///     .with_year_last_two(21)
+    ///     .with_month(Month::May)
+    ///     .with_day(NonZeroU8::new(3).unwrap());
+    /// assert!(Date::try_from(parsed).is_err());
+    /// assert_eq!(
+    ///     Date::try_from(parsed.with_two_digit_year(TwoDigitYear::Pivot(1950)))?,
+    ///     date!(2021 - 05 - 03)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[must_use = "This method does not mutate the original `Parsed`."]
+    pub const fn with_two_digit_year(mut self, resolution: TwoDigitYear) -> Self {
+        self.two_digit_year = Some(resolution);
+        self
+    }
+
+    /// How a leap second is handled, if configured.
+    pub const fn leap_second(&self) -> Option<LeapSecond> {
+        self.leap_second
+    }
+
+    /// Set how a leap second is handled.
+    #[allow(clippy::missing_const_for_fn)] // mutable references in const fn are unstable
+    pub fn set_leap_second(&mut self, handling: LeapSecond) {
+        self.leap_second = Some(handling);
+    }
+
+    /// Set how a leap second is handled, returning the updated struct.
+    ///
+    /// ```rust
+    /// # use core::convert::TryFrom;
+    /// # use time::{macros::time, parsing::{LeapSecond, Parsed}, Time};
+    /// let parsed = Parsed::new()
+    ///     .with_hour_24(23)
+    ///     .with_minute(59)
+    ///     .with_second(60);
+    /// assert!(Time::try_from(parsed).is_err());
+    /// assert_eq!(
+    ///     Time::try_from(parsed.with_leap_second(LeapSecond::Clamp))?,
+    ///     time!(23:59:59)
+    /// );
+    /// assert_eq!(
+    ///     Time::try_from(parsed.with_leap_second(LeapSecond::Fold))?,
+    ///     time!(0:00)
+    /// );
+    /// assert!(Time::try_from(parsed.with_leap_second(LeapSecond::Preserve))?.is_leap_second());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[must_use = "This method does not mutate the original `Parsed`."]
+    pub const fn with_leap_second(mut self, handling: LeapSecond) -> Self {
+        self.leap_second = Some(handling);
+        self
+    }
+
+    /// Obtain a new struct with the same configuration but no known values.
+    pub(crate) const fn configuration(&self) -> Self {
+        Self {
+            strict: self.strict,
+            two_digit_year: self.two_digit_year,
+            leap_second: self.leap_second,
+            ..Self::new()
+        }
+    }
+
+    /// Fill in any unknown values using the provided defaults, returning the updated struct.
+    ///
+    /// Each value is filled in independently. For example, if only the hour and minute are known,
+    /// the second is taken from the defaults if present there. Defaults are only used where they
+    /// cannot conflict with known values. The month and day are not filled in when the date is
+    /// represented by an ordinal or week number, the hour is not filled in when a 12-hour clock was
+    /// used, and the UTC offset is filled in only if no part of it is known. Strict checking is
+    /// enabled if it is enabled for either struct, and the resolution of two-digit years and the
+    /// handling of leap seconds are taken from the defaults if they are not configured.
+    ///
+    /// Defaults can be obtained from an existing value, such as the current date, via the various
+    /// `From` implementations.
+    ///
+    /// ```rust
+    /// # use core::convert::TryFrom;
+    /// # use core::num::NonZeroU8;
+    /// # use time::{macros::date, parsing::Parsed, Date, Month};
+    /// let parsed = Parsed::new().with_year(2021).with_month(Month::May);
+    /// let defaults = Parsed::new().with_day(NonZeroU8::new(1).unwrap());
+    /// assert_eq!(
+    ///     Date::try_from(parsed.with_defaults(defaults))?,
+    ///     date!(2021 - 05 - 01)
+    /// );
+    /// assert_eq!(
+    ///     Date::try_from(parsed.with_defaults(Parsed::from(date!(2020 - 01 - 15))))?,
+    ///     date!(2021 - 05 - 15)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[must_use = "This method does not mutate the original `Parsed`."]
+    pub fn with_defaults(mut self, defaults: Self) -> Self {
+        if self.year.is_none() && self.year_last_two.is_none() && self.year_of_era.is_none() {
+            self.year = defaults.year;
+        }
+        let has_other_date_repr = self.ordinal.is_some()
+            || self.iso_week_number.is_some()
+            || self.sunday_week_number.is_some()
+            || self.monday_week_number.is_some();
+        if !has_other_date_repr {
+            self.month = self.month.or(defaults.month);
+            self.day = self.day.or(defaults.day);
+        }
+
+        if self.hour_12.is_none() {
+            self.hour_24 = self.hour_24.or(defaults.hour_24);
+        }
+        self.minute = self.minute.or(defaults.minute);
+        self.second = self.second.or(defaults.second);
+        self.subsecond = self.subsecond.or(defaults.subsecond);
+
+        if self.offset_hour.is_none() {
+            self.offset_hour = defaults.offset_hour;
+            self.offset_minute = defaults.offset_minute;
+            self.offset_second = defaults.offset_second;
+            self.offset_is_negative = defaults.offset_is_negative;
+        }
+        self.time_zone_abbreviation = self
+            .time_zone_abbreviation
+            .or(defaults.time_zone_abbreviation);
+        self.time_zone_name = self.time_zone_name.or(defaults.time_zone_name);
+
+        self.strict |= defaults.strict;
+        self.two_digit_year = self.two_digit_year.or(defaults.two_digit_year);
+        self.leap_second = self.leap_second.or(defaults.leap_second);
+        self
+    }
+
     /// Parse a single component, mutating the struct. The remaining input is returned as the `Ok`
     /// value.
+    #[allow(clippy::too_many_lines)]
     pub fn parse_component<'a>(
         &mut self,
         input: &'a [u8],
         component: Component,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        use error::ParseFromDescription::InvalidComponent;
-
         match component {
             Component::Day(modifiers) => Ok(parse_day(input, modifiers)
                 .ok_or(InvalidComponent("day"))?
@@ -136,9 +662,26 @@ impl Parsed {
                     (false, YearRepr::LastTwo) => self.year_last_two = Some(value as u8),
                     (true, YearRepr::Full) => self.iso_year = Some(value),
                     (true, YearRepr::LastTwo) => self.iso_year_last_two = Some(value as u8),
+                    (_, YearRepr::OfEra) => {
+                        self.year_of_era =
+                            Some(NonZeroU32::new(value as u32).ok_or(InvalidComponent("year"))?);
+                    }
                 }
                 Ok(remaining)
             }
+            Component::Quarter(modifiers) => Ok(parse_quarter(input, modifiers)
+                .ok_or(InvalidComponent("quarter"))?
+                .assign_value_to(&mut self.quarter)),
+            Component::Century(modifiers) => {
+                let ParsedItem(remaining, (value, is_negative)) =
+                    parse_century(input, modifiers).ok_or(InvalidComponent("century"))?;
+                self.century = Some(value);
+                self.century_is_negative = is_negative;
+                Ok(remaining)
+            }
+            Component::Era(modifiers) => Ok(parse_era(input, modifiers)
+                .ok_or(InvalidComponent("era"))?
+                .assign_value_to(&mut self.era_is_bce)),
             Component::Hour(modifiers) => {
                 let ParsedItem(remaining, value) =
                     parse_hour(input, modifiers).ok_or(InvalidComponent("hour"))?;
@@ -162,23 +705,330 @@ impl Parsed {
             Component::Subsecond(modifiers) => Ok(parse_subsecond(input, modifiers)
                 .ok_or(InvalidComponent("subsecond"))?
                 .assign_value_to(&mut self.subsecond)),
-            Component::OffsetHour(modifiers) => Ok(parse_offset_hour(input, modifiers)
-                .ok_or(InvalidComponent("offset hour"))?
-                .assign_value_to(&mut self.offset_hour)),
+            Component::OffsetHour(modifiers) => {
+                let ParsedItem(remaining, (hour, is_negative)) =
+                    parse_offset_hour(input, modifiers).ok_or(InvalidComponent("offset hour"))?;
+                self.offset_hour = Some(hour);
+                self.offset_is_negative = is_negative;
+                Ok(remaining)
+            }
             Component::OffsetMinute(modifiers) => Ok(parse_offset_minute(input, modifiers)
                 .ok_or(InvalidComponent("offset minute"))?
                 .assign_value_to(&mut self.offset_minute)),
             Component::OffsetSecond(modifiers) => Ok(parse_offset_second(input, modifiers)
                 .ok_or(InvalidComponent("offset second"))?
                 .assign_value_to(&mut self.offset_second)),
+            Component::Offset(modifiers) => {
+                let ParsedItem(remaining, (hour, minute, second, is_negative)) =
+                    parse_offset(input, modifiers).ok_or(InvalidComponent("offset"))?;
+                self.offset_hour = Some(hour);
+                self.offset_minute = Some(minute);
+                self.offset_second = Some(second);
+                self.offset_is_negative = is_negative;
+                Ok(remaining)
+            }
+            Component::TimeZone(modifiers) => {
+                let ParsedItem(remaining, text) =
+                    parse_time_zone(input, modifiers).ok_or(InvalidComponent("time zone"))?;
+                let text = Some(TimeZoneText::new(text));
+                match modifiers.repr {
+                    TimeZoneRepr::Abbreviation => self.time_zone_abbreviation = text,
+                    TimeZoneRepr::Name => self.time_zone_name = text,
+                }
+                Ok(remaining)
+            }
+            Component::Whitespace(modifiers) => Ok(parse_whitespace(input, modifiers)
+                .ok_or(InvalidComponent("whitespace"))?
+                .0),
+            Component::Ignore(modifiers) => Ok(parse_ignore(input, modifiers)
+                .ok_or(InvalidComponent("ignore"))?
+                .0),
+            Component::End(modifiers) => Ok(parse_end(input, modifiers)
+                .ok_or(InvalidComponent("end"))?
+                .0),
+        }
+    }
+
+    /// Parse a single format item, mutating the struct. The remaining input is returned as the
+    /// `Ok` value.
+    pub fn parse_item<'a>(
+        &mut self,
+        mut input: &'a [u8],
+        item: &FormatItem<'_>,
+    ) -> Result<&'a [u8], error::Parse> {
+        match item {
+            FormatItem::Literal(literal) => {
+                input = input
+                    .strip_prefix(*literal)
+                    .ok_or(error::ParseFromDescription::InvalidLiteral)
+                    .map_err(|err| error::Parse::from(err).with_location(0, None))?;
+            }
+            FormatItem::CaseInsensitiveLiteral(literal) => {
+                input = strip_prefix(input, literal, false)
+                    .ok_or(error::ParseFromDescription::InvalidLiteral)
+                    .map_err(|err| error::Parse::from(err).with_location(0, None))?;
+            }
+            FormatItem::Component(component) => {
+                input = self
+                    .parse_component(input, *component)
+                    .map_err(|err| error::Parse::from(err).with_location(0, None))?;
+            }
+            FormatItem::Compound(compound) => input = self.parse_items(input, compound)?,
+            FormatItem::Optional(item) => input = self.parse_optional(input, *item),
+            FormatItem::First(items) => input = self.parse_first(input, items)?,
+        }
+        Ok(input)
+    }
+
+    /// Parse an item that may not be present. If the item does not match, the struct is left
+    /// unchanged and no input is consumed.
+    pub(crate) fn parse_optional<'a, T: Parsable + ?Sized>(
+        &mut self,
+        input: &'a [u8],
+        item: &T,
+    ) -> &'a [u8] {
+        let mut parsed = *self;
+        item.parse_into(input, &mut parsed)
+            .map_or(input, |remaining| {
+                *self = parsed;
+                remaining
+            })
+    }
+
+    /// Parse the first of the items that matches. If none match, the error from the first item is
+    /// returned and the struct is left unchanged.
+    pub(crate) fn parse_first<'a, T: Parsable>(
+        &mut self,
+        input: &'a [u8],
+        items: &[T],
+    ) -> Result<&'a [u8], error::Parse> {
+        let mut first_err = None;
+        for item in items {
+            let mut parsed = *self;
+            match item.parse_into(input, &mut parsed) {
+                Ok(remaining) => {
+                    *self = parsed;
+                    return Ok(remaining);
+                }
+                Err(err) if first_err.is_none() => first_err = Some(err),
+                Err(_) => {}
+            }
         }
+        first_err.map_or(Ok(input), Err)
+    }
+
+    /// Parse a sequence of format items, mutating the struct. The remaining input is returned as
+    /// the `Ok` value.
+    ///
+    /// ```rust
+    /// # use time::{macros::format_description, parsing::Parsed, Month};
+    /// let mut parsed = Parsed::new();
+    /// let format = format_description!("[year]-[month]");
+    /// let remaining = parsed.parse_items(b"2021-01 rest", &format)?;
+    /// assert_eq!(remaining, b" rest");
+    /// assert_eq!(parsed.year(), Some(2021));
+    /// assert_eq!(parsed.month(), Some(Month::January));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_items<'a>(
+        &mut self,
+        input: &'a [u8],
+        items: &[FormatItem<'_>],
+    ) -> Result<&'a [u8], error::Parse> {
+        self.parse_sequence(input, items)
+    }
+
+    /// Parse a sequence of items, handling any items that depend on the item following them.
+    pub(crate) fn parse_sequence<'a, T: SequenceItem + Parsable>(
+        &mut self,
+        mut input: &'a [u8],
+        items: &[T],
+    ) -> Result<&'a [u8], error::Parse> {
+        let original_len = input.len();
+        let mut remaining_items = items;
+        while let [item, remaining @ ..] = remaining_items {
+            let index = items.len() - remaining_items.len();
+            remaining_items = remaining;
+
+            // The separator is optional when followed by a subsecond that trims trailing zeros.
+            if let (Some(literal), [next, ..]) = (item.literal(), remaining) {
+                let case_sensitive = !item.is_case_insensitive_literal();
+                if item.is_subsecond_separator()
+                    && next.is_trimmed_subsecond()
+                    && strip_prefix(input, literal, case_sensitive).is_none()
+                {
+                    remaining_items = &remaining[1..];
+                    continue;
+                }
+            }
+
+            // A variable number of ignored bytes extends up to the literal following it.
+            let next_literal = remaining
+                .first()
+                .and_then(|next| Some((next.literal()?, !next.is_case_insensitive_literal())));
+            if let (true, Some((literal, case_sensitive))) =
+                (item.is_variable_ignore(), next_literal)
+            {
+                let len = (0..=input.len())
+                    .find(|&len| strip_prefix(&input[len..], literal, case_sensitive).is_some())
+                    .ok_or_else(|| {
+                        error::Parse::from(InvalidComponent("ignore"))
+                            .with_location(original_len - input.len(), Some(index))
+                    })?;
+                input = &input[len..];
+                continue;
+            }
+
+            let is_z = item.is_offset_hour_z_for_utc()
+                && matches!(input.first(), Some(b'Z') | Some(b'z'));
+            input = item
+                .parse_into(input, self)
+                .map_err(|err| err.with_location(original_len - input.len(), Some(index)))?;
+
+            // The remainder of the offset is not present when `Z` was parsed in place of the hour.
+            if is_z {
+                self.offset_minute = Some(0);
+                self.offset_second = Some(0);
+                remaining_items = &remaining[offset_remainder_len(remaining)..];
+            }
+        }
+        Ok(input)
     }
 }
 
+impl From<Date> for Parsed {
+    fn from(date: Date) -> Self {
+        let (year, month, day) = date.to_calendar_date();
+        Self {
+            year: Some(year),
+            month: Some(month),
+            day: NonZeroU8::new(day),
+            ..Self::new()
+        }
+    }
+}
+
+impl From<Time> for Parsed {
+    fn from(time: Time) -> Self {
+        Self {
+            hour_24: Some(time.hour()),
+            minute: Some(time.minute()),
+            second: Some(time.second()),
+            subsecond: Some(time.nanosecond()),
+            ..Self::new()
+        }
+    }
+}
+
+impl From<UtcOffset> for Parsed {
+    fn from(offset: UtcOffset) -> Self {
+        Self {
+            offset_hour: Some(offset.whole_hours()),
+            offset_minute: Some(offset.minutes_past_hour().unsigned_abs()),
+            offset_second: Some(offset.seconds_past_minute().unsigned_abs()),
+            offset_is_negative: offset.is_negative(),
+            ..Self::new()
+        }
+    }
+}
+
+impl From<PrimitiveDateTime> for Parsed {
+    fn from(datetime: PrimitiveDateTime) -> Self {
+        Self::from(datetime.date()).with_defaults(datetime.time().into())
+    }
+}
+
+impl From<OffsetDateTime> for Parsed {
+    fn from(datetime: OffsetDateTime) -> Self {
+        Self::from(datetime.date())
+            .with_defaults(datetime.time().into())
+            .with_defaults(datetime.offset().into())
+    }
+}
+
+/// Ensure that the value, if known, is equal to the expected value.
+fn check_consistent<T: PartialEq + Copy>(
+    value: Option<T>,
+    expected: T,
+    component: &'static str,
+) -> Result<(), error::TryFromParsed> {
+    match value {
+        Some(value) if value != expected => Err(InconsistentInformation { component }),
+        _ => Ok(()),
+    }
+}
+
+/// Ensure that all date-related values are consistent with the provided date.
+fn check_date(parsed: &Parsed, date: Date) -> Result<(), error::TryFromParsed> {
+    let (iso_year, iso_week_number, weekday) = date.to_iso_week_date();
+    check_consistent(parsed.year, date.year(), "year")?;
+    check_consistent(
+        parsed.year_last_two.map(i32::from),
+        (date.year() % 100).abs(),
+        "year",
+    )?;
+    check_consistent(parsed.iso_year, iso_year, "year")?;
+    check_consistent(
+        parsed.iso_year_last_two.map(i32::from),
+        (iso_year % 100).abs(),
+        "year",
+    )?;
+    check_consistent(parsed.century, (date.year() / 100) as i16, "century")?;
+    check_consistent(
+        parsed.year_of_era.map(NonZeroU32::get),
+        if date.year() <= 0 {
+            (1 - date.year()) as u32
+        } else {
+            date.year() as u32
+        },
+        "year",
+    )?;
+    check_consistent(parsed.era_is_bce, date.year() <= 0, "era")?;
+    check_consistent(parsed.month, date.month(), "month")?;
+    check_consistent(
+        parsed.quarter.map(NonZeroU8::get),
+        (date.month() as u8 - 1) / 3 + 1,
+        "quarter",
+    )?;
+    check_consistent(
+        parsed.sunday_week_number,
+        date.sunday_based_week(),
+        "week number",
+    )?;
+    check_consistent(
+        parsed.monday_week_number,
+        date.monday_based_week(),
+        "week number",
+    )?;
+    check_consistent(
+        parsed.iso_week_number.map(NonZeroU8::get),
+        iso_week_number,
+        "week number",
+    )?;
+    check_consistent(parsed.weekday, weekday, "weekday")?;
+    check_consistent(
+        parsed.ordinal.map(NonZeroU16::get),
+        date.ordinal(),
+        "ordinal",
+    )?;
+    check_consistent(parsed.day.map(NonZeroU8::get), date.day(), "day")
+}
+
+/// Ensure that all time-related values are consistent with the provided hour.
+fn check_time(parsed: &Parsed, hour: u8) -> Result<(), error::TryFromParsed> {
+    check_consistent(parsed.hour_24, hour, "hour")?;
+    check_consistent(
+        parsed.hour_12.map(NonZeroU8::get),
+        (hour + 11) % 12 + 1,
+        "hour",
+    )?;
+    check_consistent(parsed.hour_12_is_pm, hour >= 12, "period")
+}
+
 impl TryFrom<Parsed> for Date {
     type Error = error::TryFromParsed;
 
-    fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+    fn try_from(mut parsed: Parsed) -> Result<Self, Self::Error> {
         macro_rules! items {
             ($($item:ident),+ $(,)?) => {
                 Parsed { $($item: Some($item)),*, .. }
@@ -199,30 +1049,91 @@ impl TryFrom<Parsed> for Date {
             }
         }
 
+        if parsed.year.is_none() {
+            if let (Some(year_of_era), Some(is_bce)) = (parsed.year_of_era, parsed.era_is_bce) {
+                let year = year_of_era.get() as i32;
+                parsed.year = Some(if is_bce { 1 - year } else { year });
+            } else if let (Some(century), Some(last_two)) = (parsed.century, parsed.year_last_two) {
+                let year = century.abs() as i32 * 100 + last_two as i32;
+                parsed.year = Some(if century < 0 || parsed.century_is_negative {
+                    -year
+                } else {
+                    year
+                });
+            }
+        }
+
+        if let Some(resolution) = parsed.two_digit_year {
+            if parsed.year.is_none() {
+                parsed.year = parsed.year_last_two.map(|year| resolution.resolve(year));
+            }
+            if parsed.iso_year.is_none() {
+                parsed.iso_year = parsed
+                    .iso_year_last_two
+                    .map(|year| resolution.resolve(year));
+            }
+        }
+
         // TODO Only the basics have been covered. There are many other valid values that are not
         // currently constructed from the information known.
 
-        match parsed {
-            items!(year, ordinal) => Ok(Self::from_ordinal_date(year, ordinal.get())?),
-            items!(year, month, day) => Ok(Self::from_calendar_date(year, month, day.get())?),
-            items!(iso_year, iso_week_number, weekday) => Ok(Self::from_iso_week_date(
-                iso_year,
-                iso_week_number.get(),
-                weekday,
-            )?),
-            items!(year, sunday_week_number, weekday) => Ok(Self::from_ordinal_date(
+        let date = match parsed {
+            items!(year, ordinal) => Self::from_ordinal_date(year, ordinal.get())?,
+            items!(year, month, day) => Self::from_calendar_date(year, month, day.get())?,
+            items!(iso_year, iso_week_number, weekday) => {
+                Self::from_iso_week_date(iso_year, iso_week_number.get(), weekday)?
+            }
+            items!(year, sunday_week_number, weekday) => Self::from_ordinal_date(
                 year,
                 (sunday_week_number as i16 * 7 + weekday.number_days_from_sunday() as i16
                     - adjustment(year)
                     + 1) as u16,
-            )?),
-            items!(year, monday_week_number, weekday) => Ok(Self::from_ordinal_date(
+            )?,
+            items!(year, monday_week_number, weekday) => Self::from_ordinal_date(
                 year,
                 (monday_week_number as i16 * 7 + weekday.number_days_from_monday() as i16
                     - adjustment(year)
                     + 1) as u16,
-            )?),
-            _ => Err(InsufficientInformation),
+            )?,
+            _ => return Err(InsufficientInformation),
+        };
+
+        if parsed.strict {
+            check_date(&parsed, date)?;
+        }
+        Ok(date)
+    }
+}
+
+/// Construct the [`Time`], handling a leap second as configured. The returned boolean indicates
+/// whether a leap second was folded into the following day.
+fn time_with_carry(parsed: &Parsed) -> Result<(Time, bool), error::TryFromParsed> {
+    let hour = match (parsed.hour_24, parsed.hour_12, parsed.hour_12_is_pm) {
+        (Some(hour), _, _) => hour,
+        (_, Some(hour), Some(false)) if hour.get() == 12 => 0,
+        (_, Some(hour), Some(true)) if hour.get() == 12 => 12,
+        (_, Some(hour), Some(false)) => hour.get(),
+        (_, Some(hour), Some(true)) => hour.get() + 12,
+        _ => return Err(InsufficientInformation),
+    };
+    if parsed.strict {
+        check_time(parsed, hour)?;
+    }
+    let minute = parsed.minute.ok_or(InsufficientInformation)?;
+    let second = parsed.second.unwrap_or(0);
+    let subsecond = parsed.subsecond.unwrap_or(0);
+
+    if second != 60 {
+        return Ok((Time::from_hms_nano(hour, minute, second, subsecond)?, false));
+    }
+    match parsed.leap_second {
+        None => Ok((Time::from_hms_nano(hour, minute, second, subsecond)?, false)),
+        Some(LeapSecond::Clamp) => Ok((Time::from_hms_nano(hour, minute, 59, subsecond)?, false)),
+        Some(LeapSecond::Preserve) => Ok((Time::leap_second(hour, minute, subsecond)?, false)),
+        Some(LeapSecond::Fold) => {
+            let (adjustment, time) =
+                Time::leap_second(hour, minute, subsecond)?.adjusting_add(Duration::ZERO);
+            Ok((time, matches!(adjustment, DateAdjustment::Next)))
         }
     }
 }
@@ -231,18 +1142,7 @@ impl TryFrom<Parsed> for Time {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
-        let hour = match (parsed.hour_24, parsed.hour_12, parsed.hour_12_is_pm) {
-            (Some(hour), _, _) => hour,
-            (_, Some(hour), Some(false)) if hour.get() == 12 => 0,
-            (_, Some(hour), Some(true)) if hour.get() == 12 => 12,
-            (_, Some(hour), Some(false)) => hour.get(),
-            (_, Some(hour), Some(true)) => hour.get() + 12,
-            _ => return Err(InsufficientInformation),
-        };
-        let minute = parsed.minute.ok_or(InsufficientInformation)?;
-        let second = parsed.second.unwrap_or(0);
-        let subsecond = parsed.subsecond.unwrap_or(0);
-        Ok(Self::from_hms_nano(hour, minute, second, subsecond)?)
+        Ok(time_with_carry(&parsed)?.0)
     }
 }
 
@@ -250,10 +1150,24 @@ impl TryFrom<Parsed> for UtcOffset {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
-        let hour = parsed.offset_hour.ok_or(InsufficientInformation)?;
-        let minute = parsed.offset_minute.unwrap_or(0);
-        let second = parsed.offset_second.unwrap_or(0);
-        Ok(Self::from_hms(hour, minute as i8, second as i8)?)
+        let offset = match parsed.offset_hour {
+            Some(hour) => {
+                // The sign applies to the entire offset, even if the hour is zero.
+                let sign = if hour < 0 || parsed.offset_is_negative {
+                    -1
+                } else {
+                    1
+                };
+                let minute = parsed.offset_minute.unwrap_or(0) as i8;
+                let second = parsed.offset_second.unwrap_or(0) as i8;
+                Self::from_hms(hour, sign * minute, sign * second)?
+            }
+            None => return parsed.time_zone_offset().ok_or(InsufficientInformation),
+        };
+        if parsed.strict {
+            check_consistent(parsed.time_zone_offset(), offset, "time zone")?;
+        }
+        Ok(offset)
     }
 }
 
@@ -261,7 +1175,20 @@ impl TryFrom<Parsed> for PrimitiveDateTime {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
-        Ok(Self::new(parsed.try_into()?, parsed.try_into()?))
+        let date: Date = parsed.try_into()?;
+        let (time, is_next_day) = time_with_carry(&parsed)?;
+        if !is_next_day {
+            return Ok(Self::new(date, time));
+        }
+        // If there is no following day, it is in a year that is out of range.
+        let next_day = date.next_day().ok_or_else(|| error::ComponentRange {
+            name: "year",
+            minimum: MIN_YEAR as _,
+            maximum: MAX_YEAR as _,
+            value: date.year() as i64 + 1,
+            conditional_range: false,
+        })?;
+        Ok(Self::new(next_day, time))
     }
 }
 
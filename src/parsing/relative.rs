@@ -0,0 +1,565 @@
+//! Parsing of natural-language expressions relative to a known moment, such as "3 days ago".
+
+use core::convert::TryFrom;
+
+use crate::error::{ComponentRange, ParseFromDescription, TryFromParsed};
+use crate::format_description::modifier;
+use crate::parsing::combinator::{ascii_char, n_to_m_digits, opt};
+use crate::parsing::component::{parse_month, parse_weekday};
+use crate::parsing::ParsedItem;
+use crate::util::{days_in_year_month, DateAdjustment};
+use crate::{error, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, Weekday};
+
+/// A range of time, from `start` (inclusive) to `end` (exclusive).
+///
+/// An interval is obtained by parsing an expression relative to a known moment. The interval spans
+/// the period of time described by the expression, such as a full day for "yesterday" or a full
+/// month for "last month". Expressions that describe a single moment, such as "3 hours ago", have
+/// equal start and end values.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    /// The start of the interval, inclusive.
+    pub start: OffsetDateTime,
+    /// The end of the interval, exclusive.
+    pub end: OffsetDateTime,
+}
+
+impl Interval {
+    /// Parse an expression relative to the provided anchor. The expression is resolved using the
+    /// anchor's UTC offset, and the resulting values have the same offset.
+    ///
+    /// The following expressions are accepted, ignoring case:
+    ///
+    /// - `now`, `today`, `yesterday`, and `tomorrow`
+    /// - a number of units followed by `ago` or `from now`, or preceded by `in`, such as `3 days
+    ///   ago` or `in an hour`. The units are `second`, `minute`, `hour`, `day`, `week`, `month`,
+    ///   and `year`, optionally pluralized. Adding months or years clamps the day to the end of the
+    ///   month if necessary.
+    /// - `last`, `this`, or `next` followed by a unit, such as `last month`. This spans the full
+    ///   unit, with weeks starting on Monday.
+    /// - `last`, `this`, or `next` followed by a weekday or month name, such as `next friday` or
+    ///   `last march`. `last` and `next` are the closest such day or month strictly before or after
+    ///   the anchor, while `this` is within the current week or year. A weekday or month name on
+    ///   its own is equivalent to `this`.
+    /// - any of the above resolving to at most a single day, optionally followed by a time of day,
+    ///   such as `tomorrow 9am` or `next friday at 17:30`. The time can be `noon`, `midnight`, a
+    ///   12-hour time with `am` or `pm`, or a 24-hour time with minutes. A time of day on its own
+    ///   is relative to today.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, parsing::Interval};
+    /// let anchor = datetime!(2021-05-12 15:30 +2); // a Wednesday
+    /// let yesterday = Interval::parse_relative("yesterday", anchor)?;
+    /// assert_eq!(yesterday.start, datetime!(2021-05-11 0:00 +2));
+    /// assert_eq!(yesterday.end, datetime!(2021-05-12 0:00 +2));
+    ///
+    /// let last_month = Interval::parse_relative("last month", anchor)?;
+    /// assert_eq!(last_month.start, datetime!(2021-04-01 0:00 +2));
+    /// assert_eq!(last_month.end, datetime!(2021-05-01 0:00 +2));
+    ///
+    /// let friday = Interval::parse_relative("next friday 9am", anchor)?;
+    /// assert_eq!(friday.start, datetime!(2021-05-14 9:00 +2));
+    /// assert_eq!(friday.start, friday.end);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_relative(input: &str, anchor: OffsetDateTime) -> Result<Self, error::Parse> {
+        let offset = anchor.offset();
+        let (start, end) = parse(input.as_bytes(), anchor.date().with_time(anchor.time()))?;
+        Ok(Self {
+            start: start.assume_offset(offset),
+            end: end.assume_offset(offset),
+        })
+    }
+}
+
+/// A unit of time that can be used in a relative expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    #[allow(clippy::missing_docs_in_private_items)]
+    Second,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Minute,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Hour,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Day,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Week,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Month,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Year,
+}
+
+/// Which occurrence of a unit, weekday, or month is being referred to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    /// The previous occurrence.
+    Last,
+    /// The current occurrence.
+    This,
+    /// The following occurrence.
+    Next,
+}
+
+/// What the portion of an expression before any time of day resolved to.
+#[derive(Debug, Clone, Copy)]
+enum Resolved {
+    /// A single moment.
+    Moment(PrimitiveDateTime),
+    /// A full day.
+    Day(Date),
+    /// A period longer than a day, from the start (inclusive) to the end (exclusive).
+    Period(PrimitiveDateTime, PrimitiveDateTime),
+}
+
+/// Parse a relative expression, returning the start and end of the interval it describes.
+fn parse(
+    input: &[u8],
+    anchor: PrimitiveDateTime,
+) -> Result<(PrimitiveDateTime, PrimitiveDateTime), error::Parse> {
+    let position = |remaining: &[u8]| input.len() - remaining.len();
+    let invalid = |remaining: &[u8], component| {
+        error::Parse::from(ParseFromDescription::InvalidComponent(component))
+            .with_location(position(remaining), None)
+    };
+
+    let remaining = whitespace(input);
+    let (remaining, resolved) = match resolve_day(remaining, anchor) {
+        Ok(Some(ParsedItem(remaining, resolved))) => (remaining, Some(resolved)),
+        Ok(None) => (remaining, None),
+        Err(component) => return Err(invalid(remaining, component)),
+    };
+
+    let remaining = whitespace(remaining);
+    let time_start = remaining;
+    let time = match keyword(remaining, "at") {
+        Some(after_at) => {
+            let after_at = whitespace(after_at);
+            Some(time_of_day(after_at).ok_or_else(|| invalid(after_at, "time"))?)
+        }
+        None => time_of_day(remaining),
+    };
+    let remaining = match time {
+        Some(ParsedItem(remaining, _)) => whitespace(remaining),
+        None => remaining,
+    };
+    if !remaining.is_empty() {
+        return Err(if resolved.is_none() && time.is_none() {
+            invalid(remaining, "relative date")
+        } else {
+            error::Parse::from(ParseFromDescription::UnexpectedTrailingCharacters)
+                .with_location(position(remaining), None)
+        });
+    }
+
+    let time = time.map(|ParsedItem(_, time)| time);
+    match (resolved, time) {
+        (None, None) => Err(invalid(remaining, "relative date")),
+        (Some(Resolved::Moment(moment)), None) => Ok((moment, moment)),
+        (Some(Resolved::Moment(moment)), Some(time)) => {
+            let moment = moment.replace_time(time);
+            Ok((moment, moment))
+        }
+        (Some(Resolved::Day(date)), None) => Ok((
+            date.midnight(),
+            add_days(date, 1).map_err(out_of_range)?.midnight(),
+        )),
+        (Some(Resolved::Day(date)), Some(time)) => {
+            let moment = date.with_time(time);
+            Ok((moment, moment))
+        }
+        (Some(Resolved::Period(start, end)), None) => Ok((start, end)),
+        (Some(Resolved::Period(..)), Some(_)) => Err(invalid(time_start, "time")),
+        (None, Some(time)) => {
+            let moment = anchor.date().with_time(time);
+            Ok((moment, moment))
+        }
+    }
+}
+
+/// Resolve the portion of the expression before any time of day. If the input does not start
+/// with a recognized expression, `Ok(None)` is returned. If it starts with a recognized
+/// expression that is incomplete or cannot be resolved, the name of the invalid component is
+/// returned.
+fn resolve_day(
+    input: &[u8],
+    anchor: PrimitiveDateTime,
+) -> Result<Option<ParsedItem<'_, Resolved>>, &'static str> {
+    let today = anchor.date();
+    let day_relative_to_today = |days| add_days(today, days).map(Resolved::Day);
+
+    if let Some(ParsedItem(remaining, word)) = word_match(
+        input,
+        &[
+            ("now", None),
+            ("today", Some(0)),
+            ("yesterday", Some(-1)),
+            ("tomorrow", Some(1)),
+        ],
+    ) {
+        let resolved = match word {
+            None => Resolved::Moment(anchor),
+            Some(days) => day_relative_to_today(days).map_err(|_| "day")?,
+        };
+        return Ok(Some(ParsedItem(remaining, resolved)));
+    }
+
+    if let Some(ParsedItem(remaining, direction)) = word_match(
+        input,
+        &[
+            ("last", Direction::Last),
+            ("this", Direction::This),
+            ("next", Direction::Next),
+        ],
+    ) {
+        let remaining = whitespace(remaining);
+        if let Some(ParsedItem(remaining, weekday)) = weekday(remaining) {
+            let date = nth_weekday(today, weekday, direction).map_err(|_| "weekday")?;
+            return Ok(Some(ParsedItem(remaining, Resolved::Day(date))));
+        }
+        if let Some(ParsedItem(remaining, month)) = month(remaining) {
+            let (start, end) = nth_month(today, month, direction).map_err(|_| "month")?;
+            return Ok(Some(ParsedItem(remaining, Resolved::Period(start, end))));
+        }
+        let ParsedItem(remaining, unit) = unit(remaining).ok_or("unit")?;
+        let amount = match direction {
+            Direction::Last => -1,
+            Direction::This => 0,
+            Direction::Next => 1,
+        };
+        let start = shift(truncate(anchor, unit)?, unit, amount).map_err(|_| "unit")?;
+        let end = shift(start, unit, 1).map_err(|_| "unit")?;
+        let resolved = match unit {
+            Unit::Day => Resolved::Day(start.date()),
+            _ => Resolved::Period(start, end),
+        };
+        return Ok(Some(ParsedItem(remaining, resolved)));
+    }
+
+    if let Some(ParsedItem(remaining, weekday)) = weekday(input) {
+        let date = nth_weekday(today, weekday, Direction::This).map_err(|_| "weekday")?;
+        return Ok(Some(ParsedItem(remaining, Resolved::Day(date))));
+    }
+    if let Some(ParsedItem(remaining, month)) = month(input) {
+        let (start, end) = nth_month(today, month, Direction::This).map_err(|_| "month")?;
+        return Ok(Some(ParsedItem(remaining, Resolved::Period(start, end))));
+    }
+
+    // "in 3 days"
+    if let Some(remaining) = keyword(input, "in") {
+        let remaining = whitespace(remaining);
+        let ParsedItem(remaining, amount) = amount(remaining).ok_or("number")?;
+        let ParsedItem(remaining, unit) = unit(whitespace(remaining)).ok_or("unit")?;
+        let moment = shift(anchor, unit, amount).map_err(|_| "number")?;
+        return Ok(Some(ParsedItem(remaining, Resolved::Moment(moment))));
+    }
+
+    // "3 days ago" or "3 days from now". A number that is not followed by a unit may be a time of
+    // day, so it is not an error.
+    let (remaining, amount, unit) = match amount(input) {
+        Some(ParsedItem(remaining, amount)) => match unit(whitespace(remaining)) {
+            Some(ParsedItem(remaining, unit)) => (whitespace(remaining), amount, unit),
+            None => return Ok(None),
+        },
+        None => return Ok(None),
+    };
+    let (remaining, sign) = if let Some(remaining) = keyword(remaining, "ago") {
+        (remaining, -1)
+    } else if let Some(remaining) = keyword(remaining, "from") {
+        (
+            keyword(whitespace(remaining), "now").ok_or("relative date")?,
+            1,
+        )
+    } else {
+        return Err("relative date");
+    };
+    let moment = shift(anchor, unit, sign * amount).map_err(|_| "number")?;
+    Ok(Some(ParsedItem(remaining, Resolved::Moment(moment))))
+}
+
+// region: tokens
+/// Consume any leading ASCII whitespace.
+fn whitespace(input: &[u8]) -> &[u8] {
+    let start = input
+        .iter()
+        .position(|c| !c.is_ascii_whitespace())
+        .unwrap_or(input.len());
+    &input[start..]
+}
+
+/// Whether the input is at the end of a word.
+#[allow(clippy::missing_const_for_fn)] // u8::is_ascii_alphanumeric is not const on the MSRV
+fn is_word_boundary(input: &[u8]) -> bool {
+    !matches!(input.first(), Some(c) if c.is_ascii_alphanumeric())
+}
+
+/// Consume the provided word, ignoring case.
+fn keyword<'a>(input: &'a [u8], word: &str) -> Option<&'a [u8]> {
+    word_match(input, &[(word, ())]).map(|ParsedItem(remaining, ())| remaining)
+}
+
+/// Consume the first of the provided words that is present as a full word, ignoring case.
+fn word_match<'a, T: Copy>(input: &'a [u8], words: &[(&str, T)]) -> Option<ParsedItem<'a, T>> {
+    words.iter().find_map(|&(word, value)| {
+        let word = word.as_bytes();
+        if input.len() < word.len() || !input[..word.len()].eq_ignore_ascii_case(word) {
+            return None;
+        }
+        let remaining = &input[word.len()..];
+        if is_word_boundary(remaining) {
+            Some(ParsedItem(remaining, value))
+        } else {
+            None
+        }
+    })
+}
+
+/// Consume the name of a weekday, either in full or abbreviated, ignoring case.
+fn weekday(input: &[u8]) -> Option<ParsedItem<'_, Weekday>> {
+    [modifier::WeekdayRepr::Long, modifier::WeekdayRepr::Short]
+        .iter()
+        .find_map(|&repr| {
+            let modifiers = modifier::Weekday {
+                repr,
+                one_indexed: true,
+                case_sensitive: false,
+                locale: modifier::Locale::En,
+            };
+            parse_weekday(input, modifiers).filter(|parsed| is_word_boundary(parsed.0))
+        })
+}
+
+/// Consume the name of a month, either in full or abbreviated, ignoring case.
+fn month(input: &[u8]) -> Option<ParsedItem<'_, Month>> {
+    [modifier::MonthRepr::Long, modifier::MonthRepr::Short]
+        .iter()
+        .find_map(|&repr| {
+            let modifiers = modifier::Month {
+                padding: modifier::Padding::None,
+                repr,
+                case_sensitive: false,
+                locale: modifier::Locale::En,
+                standalone: false,
+            };
+            parse_month(input, modifiers).filter(|parsed| is_word_boundary(parsed.0))
+        })
+}
+
+/// Consume a unit of time, optionally pluralized.
+fn unit(input: &[u8]) -> Option<ParsedItem<'_, Unit>> {
+    word_match(
+        input,
+        &[
+            ("second", Unit::Second),
+            ("seconds", Unit::Second),
+            ("minute", Unit::Minute),
+            ("minutes", Unit::Minute),
+            ("hour", Unit::Hour),
+            ("hours", Unit::Hour),
+            ("day", Unit::Day),
+            ("days", Unit::Day),
+            ("week", Unit::Week),
+            ("weeks", Unit::Week),
+            ("month", Unit::Month),
+            ("months", Unit::Month),
+            ("year", Unit::Year),
+            ("years", Unit::Year),
+        ],
+    )
+}
+
+/// Consume a positive number of units, either as digits or as "a" or "an".
+fn amount(input: &[u8]) -> Option<ParsedItem<'_, i64>> {
+    if let Some(parsed) = word_match(input, &[("a", 1), ("an", 1)]) {
+        return Some(parsed);
+    }
+    n_to_m_digits::<u32>(1, 9)(input)
+        .filter(|parsed| is_word_boundary(parsed.0))
+        .map(|parsed| parsed.map(i64::from))
+}
+
+/// Consume a time of day.
+fn time_of_day(input: &[u8]) -> Option<ParsedItem<'_, Time>> {
+    if let Some(parsed) = word_match(input, &[("noon", 12), ("midnight", 0)]) {
+        return Some(parsed.map(|hour| Time::__from_hms_nanos_unchecked(hour, 0, 0, 0)));
+    }
+
+    let ParsedItem(remaining, hour) = n_to_m_digits::<u8>(1, 2)(input)?;
+    let (remaining, minute) = match ascii_char(b':')(remaining) {
+        Some(ParsedItem(remaining, ())) => {
+            let ParsedItem(remaining, minute) = n_to_m_digits::<u8>(2, 2)(remaining)?;
+            (remaining, Some(minute))
+        }
+        None => (remaining, None),
+    };
+    let ParsedItem(remaining, is_pm) =
+        opt(|input| word_match(whitespace(input), &[("am", false), ("pm", true)]))(remaining);
+
+    let hour = match is_pm {
+        Some(_) if hour == 0 || hour > 12 => return None,
+        Some(is_pm) => hour % 12 + if is_pm { 12 } else { 0 },
+        // A bare number is not a time of day.
+        None if minute.is_none() => return None,
+        None => hour,
+    };
+    if !is_word_boundary(remaining) {
+        return None;
+    }
+    Time::from_hms(hour, minute.unwrap_or(0), 0)
+        .ok()
+        .map(|time| ParsedItem(remaining, time))
+}
+// endregion tokens
+
+// region: arithmetic
+/// Convert an error indicating that a value is out of range.
+fn out_of_range(err: ComponentRange) -> error::Parse {
+    TryFromParsed::ComponentRange(err).into()
+}
+
+/// Add the provided number of days to the date.
+fn add_days(date: Date, days: i64) -> Result<Date, ComponentRange> {
+    let julian_day = i64::from(date.to_julian_day()).saturating_add(days);
+    let julian_day =
+        i32::try_from(julian_day).unwrap_or(if julian_day < 0 { i32::MIN } else { i32::MAX });
+    Date::from_julian_day(julian_day)
+}
+
+/// Add the provided number of months to the date, clamping the day to the end of the month.
+fn add_months(date: Date, months: i64) -> Result<Date, ComponentRange> {
+    let (year, month, day) = date.to_calendar_date();
+    let months = (i64::from(year) * 12 + month as i64 - 1).saturating_add(months);
+    let year = i32::try_from(months.div_euclid(12)).unwrap_or(if months < 0 {
+        i32::MIN
+    } else {
+        i32::MAX
+    });
+    let month = (0..months.rem_euclid(12)).fold(Month::January, |month, _| month.next());
+    Date::from_calendar_date(year, month, day.min(days_in_year_month(year, month)))
+}
+
+/// Move the moment by the provided number of units.
+fn shift(
+    datetime: PrimitiveDateTime,
+    unit: Unit,
+    amount: i64,
+) -> Result<PrimitiveDateTime, ComponentRange> {
+    let duration = match unit {
+        Unit::Second => Duration::seconds(amount),
+        Unit::Minute => Duration::minutes(amount),
+        Unit::Hour => Duration::hours(amount),
+        Unit::Day => Duration::days(amount),
+        Unit::Week => Duration::weeks(amount),
+        Unit::Month => return Ok(add_months(datetime.date(), amount)?.with_time(datetime.time())),
+        Unit::Year => {
+            return Ok(
+                add_months(datetime.date(), amount.saturating_mul(12))?.with_time(datetime.time())
+            );
+        }
+    };
+
+    let (adjustment, time) = datetime.time().adjusting_add(duration);
+    let days = duration.whole_days()
+        + match adjustment {
+            DateAdjustment::Previous => -1,
+            DateAdjustment::None => 0,
+            DateAdjustment::Next => 1,
+        };
+    Ok(add_days(datetime.date(), days)?.with_time(time))
+}
+
+/// Obtain the start of the unit containing the moment. Weeks start on Monday.
+fn truncate(datetime: PrimitiveDateTime, unit: Unit) -> Result<PrimitiveDateTime, &'static str> {
+    let date = datetime.date();
+    let (hour, minute, second) = datetime.as_hms();
+    let time = |hour, minute, second| Time::__from_hms_nanos_unchecked(hour, minute, second, 0);
+    Ok(match unit {
+        Unit::Second => date.with_time(time(hour, minute, second)),
+        Unit::Minute => date.with_time(time(hour, minute, 0)),
+        Unit::Hour => date.with_time(time(hour, 0, 0)),
+        Unit::Day => date.midnight(),
+        Unit::Week => add_days(date, -i64::from(date.weekday().number_days_from_monday()))
+            .map_err(|_| "unit")?
+            .midnight(),
+        Unit::Month => Date::from_calendar_date(date.year(), date.month(), 1)
+            .map_err(|_| "unit")?
+            .midnight(),
+        Unit::Year => Date::__from_ordinal_date_unchecked(date.year(), 1).midnight(),
+    })
+}
+
+/// Find the day with the provided weekday relative to the date.
+fn nth_weekday(date: Date, weekday: Weekday, direction: Direction) -> Result<Date, ComponentRange> {
+    let current = i64::from(date.weekday().number_days_from_monday());
+    let target = i64::from(weekday.number_days_from_monday());
+    let days = match direction {
+        Direction::Last => -(current - target - 1).rem_euclid(7) - 1,
+        Direction::This => target - current,
+        Direction::Next => (target - current - 1).rem_euclid(7) + 1,
+    };
+    add_days(date, days)
+}
+
+/// Find the month with the provided name relative to the date, returning its start and end.
+fn nth_month(
+    date: Date,
+    month: Month,
+    direction: Direction,
+) -> Result<(PrimitiveDateTime, PrimitiveDateTime), ComponentRange> {
+    let current = i64::from(date.month() as u8);
+    let target = i64::from(month as u8);
+    let months = match direction {
+        Direction::Last => -(current - target - 1).rem_euclid(12) - 1,
+        Direction::This => target - current,
+        Direction::Next => (target - current - 1).rem_euclid(12) + 1,
+    };
+    let start = add_months(
+        Date::from_calendar_date(date.year(), date.month(), 1)?,
+        months,
+    )?;
+    let end = add_months(start, 1)?;
+    Ok((start.midnight(), end.midnight()))
+}
+// endregion arithmetic
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "relative-parsing")))]
+impl PrimitiveDateTime {
+    /// Parse an expression relative to the provided anchor, such as "3 days ago" or "next friday
+    /// 9am", returning the start of the described interval. See [`Interval::parse_relative`] for
+    /// the accepted expressions.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, PrimitiveDateTime};
+    /// let anchor = datetime!(2021-05-12 15:30);
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_relative("3 days ago", anchor)?,
+    ///     datetime!(2021-05-09 15:30)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_relative(input: &str, anchor: Self) -> Result<Self, error::Parse> {
+        parse(input.as_bytes(), anchor).map(|(start, _)| start)
+    }
+}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "relative-parsing")))]
+impl OffsetDateTime {
+    /// Parse an expression relative to the provided anchor, such as "3 days ago" or "next friday
+    /// 9am", returning the start of the described interval. The expression is resolved using the
+    /// anchor's UTC offset. See [`Interval::parse_relative`] for the accepted expressions.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, OffsetDateTime};
+    /// let anchor = datetime!(2021-05-12 15:30 -4);
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_relative("tomorrow at noon", anchor)?,
+    ///     datetime!(2021-05-13 12:00 -4)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_relative(input: &str, anchor: Self) -> Result<Self, error::Parse> {
+        Interval::parse_relative(input, anchor).map(|interval| interval.start)
+    }
+}
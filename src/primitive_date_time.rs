@@ -1,15 +1,22 @@
 use core::fmt;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting", feature = "std"))]
 use std::io;
 
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 use crate::error;
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
+
+#[cfg(all(feature = "formatting", feature = "std"))]
+use crate::formatting::output::IoOutput;
+#[cfg(feature = "formatting")]
+use crate::formatting::output::{FmtOutput, SliceOutput, StrOutput};
 #[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::formatting::{Display, Formattable};
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{Parsable, Parsed};
 use crate::{util, Date, Duration, Month, OffsetDateTime, Time, UtcOffset, Weekday};
 
 /// Combined date and time.
@@ -326,7 +333,8 @@ impl PrimitiveDateTime {
 
     /// Get the second within the minute.
     ///
-    /// The returned value will always be in the range `0..60`.
+    /// The returned value will always be in the range `0..60`, unless the time is a
+    /// [leap second](Time::leap_second), in which case it is `60`.
     ///
     /// ```rust
     /// # use time::macros::datetime;
@@ -466,8 +474,16 @@ impl PrimitiveDateTime {
 impl PrimitiveDateTime {
     /// Assuming that the current [`PrimitiveDateTime`] is a value in the provided [`UtcOffset`],
     /// obtain the equivalent value in the UTC.
+    ///
+    /// A leap second is preserved if the resulting value is also at the end of a minute. Otherwise
+    /// it is folded into the following second.
     pub(crate) const fn offset_to_utc(self, offset: UtcOffset) -> Self {
-        let mut second = self.second() as i8 - offset.seconds_past_minute();
+        let is_leap_second = self.time.is_leap_second();
+        let mut second = if is_leap_second {
+            59
+        } else {
+            self.second() as i8
+        } - offset.seconds_past_minute();
         let mut minute = self.minute() as i8 - offset.minutes_past_hour();
         let mut hour = self.hour() as i8 - offset.whole_hours();
         let (mut year, mut ordinal) = self.date.to_ordinal_date();
@@ -477,6 +493,12 @@ impl PrimitiveDateTime {
         cascade!(hour in 0..24 => ordinal);
         cascade!(ordinal => year);
 
+        // The leap second was treated as the 59th second above. Adding the second back either
+        // restores the leap second or moves the value forward, neither of which can cascade.
+        if is_leap_second {
+            second += 1;
+        }
+
         Self {
             date: Date::__from_ordinal_date_unchecked(year, ordinal),
             time: Time::__from_hms_nanos_unchecked(
@@ -507,12 +529,124 @@ impl PrimitiveDateTime {
     /// Format the `PrimitiveDateTime` using the provided format description. The formatted value
     /// will be output to the provided writer. The format description will typically be parsed by
     /// using [`format_description::parse`](crate::format_description::parse()).
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(all(feature = "formatting", feature = "std"))))]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
         format: &impl Formattable,
     ) -> Result<usize, error::Format> {
-        format.format_into(output, Some(self.date), Some(self.time), None)
+        format.format_into(
+            &mut IoOutput(output),
+            Some(self.date),
+            Some(self.time),
+            None,
+        )
+    }
+
+    /// Format the `PrimitiveDateTime` using the provided format description. The formatted value
+    /// will be output to the provided [`fmt::Write`](core::fmt::Write) implementor, returning the
+    /// number of bytes written. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime};
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// let mut output = String::new();
+    /// datetime!(2020-01-02 03:04:05).format_into_fmt(&mut output, &format)?;
+    /// assert_eq!(output, "2020-01-02 03:04:05");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        format.format_into(
+            &mut FmtOutput(output),
+            Some(self.date),
+            Some(self.time),
+            None,
+        )
+    }
+
+    /// Format the `PrimitiveDateTime` using the provided format description. The formatted value
+    /// will be written to the start of the provided buffer, returning the number of bytes written.
+    /// If the buffer is not large enough to hold the formatted value,
+    /// [`error::Format::InsufficientBufferSize`](crate::error::Format::InsufficientBufferSize) is
+    /// returned. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime};
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// let mut buf = [0; 32];
+    /// let len = datetime!(2020-01-02 03:04:05).format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], "2020-01-02 03:04:05".as_bytes());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        format.format_into(
+            &mut SliceOutput::new(output),
+            Some(self.date),
+            Some(self.time),
+            None,
+        )
+    }
+
+    /// Format the `PrimitiveDateTime` into the provided buffer using the provided format
+    /// description, returning the formatted value as a string slice. A buffer that is always large
+    /// enough can be sized at compile time using
+    /// [`max_formatted_len`](crate::formatting::max_formatted_len).
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime};
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// let mut buf = [0; 32];
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05).format_to_buf(&mut buf, &format)?,
+    ///     "2020-01-02 03:04:05"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_buf<'a>(
+        self,
+        buf: &'a mut [u8],
+        format: &impl Formattable,
+    ) -> Result<&'a str, error::Format> {
+        let mut output = StrOutput::new(buf);
+        format.format_into(&mut output, Some(self.date), Some(self.time), None)?;
+        Ok(output.into_str())
+    }
+
+    /// Obtain a value that formats the `PrimitiveDateTime` using the provided format description
+    /// when displayed, such as via `write!` or `format_args!`. No allocation is performed. If the
+    /// value cannot be formatted, [`fmt::Error`] is returned when displaying it.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime};
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05).display(&format).to_string(),
+    ///     "2020-01-02 03:04:05"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    pub fn display<F: Formattable + ?Sized>(self, format: &F) -> Display<'_, F> {
+        Display::new(format, Some(self.date), Some(self.time), None)
+    }
+
+    /// Obtain a value that formats the `PrimitiveDateTime` using the provided format description
+    /// when displayed. Unlike [`display`](Self::display), an error is returned immediately if the
+    /// value cannot be formatted.
+    pub fn try_display<F: Formattable + ?Sized>(
+        self,
+        format: &F,
+    ) -> Result<Display<'_, F>, error::Format> {
+        self.display(format).validate()
     }
 
     /// Format the `PrimitiveDateTime` using the provided format description. The format description
@@ -528,6 +662,11 @@ impl PrimitiveDateTime {
     /// );
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(
+        __time_03_docs,
+        doc(cfg(all(feature = "formatting", feature = "alloc")))
+    )]
     pub fn format(self, format: &impl Formattable) -> Result<String, error::Format> {
         format.format(Some(self.date), Some(self.time), None)
     }
@@ -552,6 +691,49 @@ impl PrimitiveDateTime {
     pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
         description.parse_date_time(input.as_bytes())
     }
+
+    /// Parse a `PrimitiveDateTime` from the start of the input using the provided format
+    /// description, returning it along with the remaining input. Unlike [`parse`](Self::parse),
+    /// characters following the parsed value are not an error.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, PrimitiveDateTime};
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_prefix("2020-01-02 03:04:05 rest", &format)?,
+    ///     (datetime!(2020-01-02 03:04:05), " rest")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &impl Parsable,
+    ) -> Result<(Self, &'a str), error::Parse> {
+        description.parse_prefix(input)
+    }
+
+    /// Parse a `PrimitiveDateTime` from the input using the provided format description, using the
+    /// provided defaults for any values that are not present in the input. See
+    /// [`Parsed::with_defaults`](crate::parsing::Parsed::with_defaults) for details on how the
+    /// defaults are applied.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::{date, datetime}, PrimitiveDateTime};
+    /// let format = format_description::parse("[hour]:[minute]")?;
+    /// let reference = date!(2021 - 05 - 03);
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_with_defaults("14:30", &format, reference.into())?,
+    ///     datetime!(2021-05-03 14:30)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_defaults(
+        input: &str,
+        description: &impl Parsable,
+        defaults: Parsed,
+    ) -> Result<Self, error::Parse> {
+        description.parse_with_defaults(input.as_bytes(), defaults)
+    }
 }
 
 impl fmt::Display for PrimitiveDateTime {
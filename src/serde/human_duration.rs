@@ -0,0 +1,61 @@
+//! Treat a [`Duration`] as a human-friendly string, such as `1h30m`, for the purposes of serde.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! The string is formatted and parsed using the default
+//! [`DurationFormat`](crate::humanize::DurationFormat), which is also used by the `Display` and
+//! `FromStr` implementations of `Duration`.
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+use crate::Duration;
+
+/// Serialize a `Duration` as a human-friendly string
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(duration)
+}
+
+/// Deserialize a `Duration` from a human-friendly string
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Duration, D::Error> {
+    let s = <&str>::deserialize(deserializer)?;
+    s.parse().map_err(|_| {
+        de::Error::invalid_value(de::Unexpected::Str(s), &"a duration such as `1h30m`")
+    })
+}
+
+/// Treat an `Option<Duration>` as a human-friendly string, such as `1h30m`, for the purposes of
+/// serde.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Serialize an `Option<Duration>` as a human-friendly string
+    pub fn serialize<S: Serializer>(
+        option: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match option {
+            Some(duration) => serializer.serialize_some(&format_args!("{}", duration)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an `Option<Duration>` from a human-friendly string
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::<&str>::deserialize(deserializer)?
+            .map(|s| {
+                s.parse().map_err(|_| {
+                    de::Error::invalid_value(de::Unexpected::Str(s), &"a duration such as `1h30m`")
+                })
+            })
+            .transpose()
+    }
+}
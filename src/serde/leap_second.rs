@@ -0,0 +1,109 @@
+//! Accept a leap second when deserializing a [`Time`], [`PrimitiveDateTime`], or
+//! [`OffsetDateTime`].
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! By default, a second of `60` is rejected when deserializing. Using this module, it is instead
+//! preserved as a leap second, as with [`Time::leap_second`]. Serialization is unchanged, so a leap
+//! second round-trips.
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{OffsetDateTime, PrimitiveDateTime, Time};
+
+/// A type that can contain a leap second: [`Time`], [`PrimitiveDateTime`], or [`OffsetDateTime`].
+pub trait LeapSecondCapable: sealed::Sealed {}
+impl LeapSecondCapable for Time {}
+impl LeapSecondCapable for PrimitiveDateTime {}
+impl LeapSecondCapable for OffsetDateTime {}
+
+/// Seal the trait to prevent downstream users from implementing it, while still allowing it to
+/// exist in generic bounds.
+mod sealed {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Deserialize the value, accepting a leap second.
+    pub trait Sealed: Serialize + Sized {
+        /// Deserialize the value, accepting a leap second.
+        fn deserialize_leap_second<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error>;
+    }
+
+    impl Sealed for Time {
+        fn deserialize_leap_second<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            super::super::deserialize_time(deserializer, true)
+        }
+    }
+
+    impl Sealed for PrimitiveDateTime {
+        fn deserialize_leap_second<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            super::super::deserialize_primitive_date_time(deserializer, true)
+        }
+    }
+
+    impl Sealed for OffsetDateTime {
+        fn deserialize_leap_second<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            super::super::deserialize_offset_date_time(deserializer, true)
+        }
+    }
+}
+
+/// Serialize a value that may contain a leap second
+pub fn serialize<S: Serializer, T: LeapSecondCapable>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.serialize(serializer)
+}
+
+/// Deserialize a value, accepting a leap second
+pub fn deserialize<'a, D: Deserializer<'a>, T: LeapSecondCapable>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::deserialize_leap_second(deserializer)
+}
+
+/// Accept a leap second when deserializing an `Option` of a [`Time`], [`PrimitiveDateTime`], or
+/// [`OffsetDateTime`].
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// A value that may contain a leap second, deserialized as such.
+    struct LeapSecond<T>(T);
+
+    impl<'a, T: LeapSecondCapable> Deserialize<'a> for LeapSecond<T> {
+        fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+            T::deserialize_leap_second(deserializer).map(Self)
+        }
+    }
+
+    /// Serialize an `Option` of a value that may contain a leap second
+    pub fn serialize<S: Serializer, T: LeapSecondCapable>(
+        option: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option.serialize(serializer)
+    }
+
+    /// Deserialize an `Option` of a value, accepting a leap second
+    pub fn deserialize<'a, D: Deserializer<'a>, T: LeapSecondCapable>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        Ok(Option::<LeapSecond<T>>::deserialize(deserializer)?.map(|LeapSecond(value)| value))
+    }
+}
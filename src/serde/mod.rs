@@ -5,6 +5,10 @@
 // Types with guaranteed stable serde representations. Strings are avoided to allow for optimal
 // representations in various binary forms.
 
+#[cfg(feature = "parsing")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+pub mod human_duration;
+pub mod leap_second;
 pub mod timestamp;
 
 use serde::de::Error as _;
@@ -19,9 +23,31 @@ use crate::error::ComponentRange;
 use crate::{
     error,
     format_description::{modifier, Component, FormatItem},
+    parsing::{LeapSecond, Parsed},
 };
 use crate::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
+/// The configuration used when deserializing a human-readable value that may contain a leap
+/// second. The leap second is preserved, allowing it to round-trip.
+#[cfg(feature = "serde-human-readable")]
+const LEAP_SECOND_CONFIGURATION: Parsed = Parsed::new().with_leap_second(LeapSecond::Preserve);
+
+/// Create a `Time` from its components. A second of `60` is only accepted as a leap second if
+/// `accept_leap_second` is true.
+const fn time_from_hms_nano(
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+    accept_leap_second: bool,
+) -> Result<Time, ComponentRange> {
+    if accept_leap_second && second == 60 {
+        Time::leap_second(hour, minute, nanosecond)
+    } else {
+        Time::from_hms_nano(hour, minute, second, nanosecond)
+    }
+}
+
 // region: Date
 /// The format used when serializing and deserializing a human-readable `Date`.
 #[cfg(feature = "serde-human-readable")]
@@ -31,16 +57,20 @@ const DATE_FORMAT: &[FormatItem<'_>] = &[
         iso_week_based: false,
         sign_is_mandatory: false,
         padding: modifier::Padding::Zero,
+        width: None,
     })),
     FormatItem::Literal(b"-"),
     FormatItem::Component(Component::Month(modifier::Month {
         repr: modifier::MonthRepr::Numerical,
         padding: modifier::Padding::Zero,
         case_sensitive: true,
+        locale: modifier::Locale::En,
+        standalone: false,
     })),
     FormatItem::Literal(b"-"),
     FormatItem::Component(Component::Day(modifier::Day {
         padding: modifier::Padding::Zero,
+        ordinal_suffix: false,
     })),
 ];
 
@@ -158,33 +188,52 @@ impl Serialize for OffsetDateTime {
 
 impl<'a> Deserialize<'a> for OffsetDateTime {
     fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
-        #[cfg(feature = "serde-human-readable")]
-        if deserializer.is_human_readable() {
-            return Self::parse(<&str>::deserialize(deserializer)?, &OFFSET_DATE_TIME_FORMAT)
-                .map_err(error::Parse::to_invalid_serde_value::<D>);
-        }
+        deserialize_offset_date_time(deserializer, false)
+    }
+}
 
-        let (
-            year,
-            ordinal,
-            hour,
-            minute,
-            second,
-            nanosecond,
-            offset_hours,
-            offset_minutes,
-            offset_seconds,
-        ) = Deserialize::deserialize(deserializer)?;
-
-        Ok(Date::from_ordinal_date(year, ordinal)
-            .map_err(ComponentRange::to_invalid_serde_value::<D>)?
-            .with_hms_nano(hour, minute, second, nanosecond)
-            .map_err(ComponentRange::to_invalid_serde_value::<D>)?
-            .assume_offset(
-                UtcOffset::from_hms(offset_hours, offset_minutes, offset_seconds)
-                    .map_err(ComponentRange::to_invalid_serde_value::<D>)?,
-            ))
+/// Deserialize an `OffsetDateTime`. A leap second is only accepted if `accept_leap_second` is true.
+fn deserialize_offset_date_time<'a, D: Deserializer<'a>>(
+    deserializer: D,
+    accept_leap_second: bool,
+) -> Result<OffsetDateTime, D::Error> {
+    #[cfg(feature = "serde-human-readable")]
+    if deserializer.is_human_readable() {
+        let input = <&str>::deserialize(deserializer)?;
+        return if accept_leap_second {
+            OffsetDateTime::parse_with_defaults(
+                input,
+                &OFFSET_DATE_TIME_FORMAT,
+                LEAP_SECOND_CONFIGURATION,
+            )
+        } else {
+            OffsetDateTime::parse(input, &OFFSET_DATE_TIME_FORMAT)
+        }
+        .map_err(error::Parse::to_invalid_serde_value::<D>);
     }
+
+    let (
+        year,
+        ordinal,
+        hour,
+        minute,
+        second,
+        nanosecond,
+        offset_hours,
+        offset_minutes,
+        offset_seconds,
+    ) = Deserialize::deserialize(deserializer)?;
+
+    Ok(Date::from_ordinal_date(year, ordinal)
+        .map_err(ComponentRange::to_invalid_serde_value::<D>)?
+        .with_time(
+            time_from_hms_nano(hour, minute, second, nanosecond, accept_leap_second)
+                .map_err(ComponentRange::to_invalid_serde_value::<D>)?,
+        )
+        .assume_offset(
+            UtcOffset::from_hms(offset_hours, offset_minutes, offset_seconds)
+                .map_err(ComponentRange::to_invalid_serde_value::<D>)?,
+        ))
 }
 // endregion OffsetDateTime
 
@@ -221,22 +270,38 @@ impl Serialize for PrimitiveDateTime {
 
 impl<'a> Deserialize<'a> for PrimitiveDateTime {
     fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
-        #[cfg(feature = "serde-human-readable")]
-        if deserializer.is_human_readable() {
-            return Self::parse(
-                <&str>::deserialize(deserializer)?,
+        deserialize_primitive_date_time(deserializer, false)
+    }
+}
+
+/// Deserialize a `PrimitiveDateTime`. A leap second is only accepted if `accept_leap_second` is
+/// true.
+fn deserialize_primitive_date_time<'a, D: Deserializer<'a>>(
+    deserializer: D,
+    accept_leap_second: bool,
+) -> Result<PrimitiveDateTime, D::Error> {
+    #[cfg(feature = "serde-human-readable")]
+    if deserializer.is_human_readable() {
+        let input = <&str>::deserialize(deserializer)?;
+        return if accept_leap_second {
+            PrimitiveDateTime::parse_with_defaults(
+                input,
                 &PRIMITIVE_DATE_TIME_FORMAT,
+                LEAP_SECOND_CONFIGURATION,
             )
-            .map_err(error::Parse::to_invalid_serde_value::<D>);
+        } else {
+            PrimitiveDateTime::parse(input, &PRIMITIVE_DATE_TIME_FORMAT)
         }
-
-        let (year, ordinal, hour, minute, second, nanosecond) =
-            Deserialize::deserialize(deserializer)?;
-        Date::from_ordinal_date(year, ordinal)
-            .map_err(ComponentRange::to_invalid_serde_value::<D>)?
-            .with_hms_nano(hour, minute, second, nanosecond)
-            .map_err(ComponentRange::to_invalid_serde_value::<D>)
+        .map_err(error::Parse::to_invalid_serde_value::<D>);
     }
+
+    let (year, ordinal, hour, minute, second, nanosecond) = Deserialize::deserialize(deserializer)?;
+    Ok(Date::from_ordinal_date(year, ordinal)
+        .map_err(ComponentRange::to_invalid_serde_value::<D>)?
+        .with_time(
+            time_from_hms_nano(hour, minute, second, nanosecond, accept_leap_second)
+                .map_err(ComponentRange::to_invalid_serde_value::<D>)?,
+        ))
 }
 // endregion PrimitiveDateTime
 
@@ -259,6 +324,8 @@ const TIME_FORMAT: &[FormatItem<'_>] = &[
     FormatItem::Literal(b"."),
     FormatItem::Component(Component::Subsecond(modifier::Subsecond {
         digits: modifier::SubsecondDigits::OneOrMore,
+        trim_trailing_zeros: false,
+        rounding: modifier::SubsecondRounding::Truncate,
     })),
 ];
 
@@ -278,16 +345,29 @@ impl Serialize for Time {
 
 impl<'a> Deserialize<'a> for Time {
     fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
-        #[cfg(feature = "serde-human-readable")]
-        if deserializer.is_human_readable() {
-            return Self::parse(<&str>::deserialize(deserializer)?, &TIME_FORMAT)
-                .map_err(error::Parse::to_invalid_serde_value::<D>);
-        }
+        deserialize_time(deserializer, false)
+    }
+}
 
-        let (hour, minute, second, nanosecond) = Deserialize::deserialize(deserializer)?;
-        Self::from_hms_nano(hour, minute, second, nanosecond)
-            .map_err(ComponentRange::to_invalid_serde_value::<D>)
+/// Deserialize a `Time`. A leap second is only accepted if `accept_leap_second` is true.
+fn deserialize_time<'a, D: Deserializer<'a>>(
+    deserializer: D,
+    accept_leap_second: bool,
+) -> Result<Time, D::Error> {
+    #[cfg(feature = "serde-human-readable")]
+    if deserializer.is_human_readable() {
+        let input = <&str>::deserialize(deserializer)?;
+        return if accept_leap_second {
+            Time::parse_with_defaults(input, &TIME_FORMAT, LEAP_SECOND_CONFIGURATION)
+        } else {
+            Time::parse(input, &TIME_FORMAT)
+        }
+        .map_err(error::Parse::to_invalid_serde_value::<D>);
     }
+
+    let (hour, minute, second, nanosecond) = Deserialize::deserialize(deserializer)?;
+    time_from_hms_nano(hour, minute, second, nanosecond, accept_leap_second)
+        .map_err(ComponentRange::to_invalid_serde_value::<D>)
 }
 // endregion Time
 
@@ -298,6 +378,8 @@ const UTC_OFFSET_FORMAT: &[FormatItem<'_>] = &[
     FormatItem::Component(Component::OffsetHour(modifier::OffsetHour {
         sign_is_mandatory: true,
         padding: modifier::Padding::Zero,
+        z_for_utc: false,
+        width: None,
     })),
     FormatItem::Literal(b":"),
     FormatItem::Component(Component::OffsetMinute(modifier::OffsetMinute {
@@ -398,7 +480,7 @@ impl Serialize for Month {
         #[cfg(feature = "serde-human-readable")]
         if serializer.is_human_readable() {
             #[cfg(not(feature = "std"))]
-            use alloc::string::String;
+            use alloc::string::ToString;
             return self.to_string().serialize(serializer);
         }
 
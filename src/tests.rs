@@ -7,6 +7,7 @@
 
 use std::num::NonZeroU8;
 
+#[cfg(feature = "alloc")]
 use crate::format_description::modifier::Modifiers;
 use crate::formatting::DigitCount;
 use crate::{duration, Month};
@@ -69,5 +70,6 @@ fn default() {
 #[test]
 fn debug() {
     let _ = format!("{:?}", duration::Padding::Optimize);
+    #[cfg(feature = "alloc")]
     let _ = format!("{:?}", Modifiers::default());
 }
@@ -1,13 +1,20 @@
 use core::fmt;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting", feature = "std"))]
 use std::io;
 
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
+
+#[cfg(all(feature = "formatting", feature = "std"))]
+use crate::formatting::output::IoOutput;
 #[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::formatting::output::{FmtOutput, SliceOutput, StrOutput};
+#[cfg(feature = "formatting")]
+use crate::formatting::{Display, Formattable};
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{Parsable, Parsed};
 use crate::util::DateAdjustment;
 use crate::{error, Duration};
 
@@ -22,8 +29,10 @@ enum Padding {
 
 /// The clock time within a given date. Nanosecond precision.
 ///
-/// All minutes are assumed to have exactly 60 seconds; no attempt is made to handle leap seconds
-/// (either positive or negative).
+/// Minutes are assumed to have 60 seconds, with the exception of a positive leap second, which
+/// can be represented explicitly using [`Time::leap_second`]. A leap second is stored as the
+/// second `60`; see that method for how it behaves in arithmetic and comparisons. Negative leap
+/// seconds are not handled.
 ///
 /// When comparing two `Time`s, they are assumed to be in the same calendar date.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -190,6 +199,56 @@ impl Time {
             hour, minute, second, nanosecond,
         ))
     }
+
+    /// Attempt to create a `Time` representing a positive leap second, which has the second `60`.
+    ///
+    /// No check is performed as to whether a leap second actually occurred at the given time.
+    ///
+    /// Arithmetic treats a leap second as the second `59` plus one second, which is the same
+    /// instant as the start of the following minute. Adding one second to `23:59:60` results in
+    /// `00:00:01`, and the difference between `23:59:60` and `00:00:00` of the following day is
+    /// zero. Use a [`LeapSecondTable`](crate::leap_seconds::LeapSecondTable) to count a leap second
+    /// as elapsed time.
+    ///
+    /// Comparisons are unaffected: a leap second is greater than any other time in its minute and
+    /// less than the start of the following minute, despite there being no difference between
+    /// the two.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, macros::time, Time};
+    /// let time = Time::leap_second(23, 59, 0)?;
+    /// assert_eq!(time + 1.seconds(), time!(0:00:01));
+    /// assert_eq!(time - 1.seconds(), time!(23:59:59));
+    /// assert!(time > time!(23:59:59.999));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// let time = Time::leap_second(23, 59, 0)?;
+    /// assert_eq!(time.as_hms(), (23, 59, 60));
+    /// assert!(time.is_leap_second());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// assert!(Time::leap_second(24, 59, 0).is_err()); // 24 isn't a valid hour.
+    /// assert!(Time::leap_second(23, 60, 0).is_err()); // 60 isn't a valid minute.
+    /// assert!(Time::leap_second(23, 59, 1_000_000_000).is_err()); // 1_000_000_000 isn't a valid nanosecond.
+    /// ```
+    pub const fn leap_second(
+        hour: u8,
+        minute: u8,
+        nanosecond: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(hour in 0 => 23);
+        ensure_value_in_range!(minute in 0 => 59);
+        ensure_value_in_range!(nanosecond in 0 => 999_999_999);
+        Ok(Self::__from_hms_nanos_unchecked(
+            hour, minute, 60, nanosecond,
+        ))
+    }
     // endregion constructors
 
     // region: getters
@@ -276,7 +335,8 @@ impl Time {
 
     /// Get the second within the minute.
     ///
-    /// The returned value will always be in the range `0..60`.
+    /// The returned value will always be in the range `0..60`, unless the `Time` is a
+    /// [leap second](Time::leap_second), in which case it is `60`.
     ///
     /// ```rust
     /// # use time::macros::time;
@@ -287,6 +347,18 @@ impl Time {
         self.second
     }
 
+    /// Get whether the `Time` is a positive leap second, having the second `60`.
+    ///
+    /// ```rust
+    /// # use time::{Time, macros::time};
+    /// assert!(!time!(23:59:59).is_leap_second());
+    /// assert!(Time::leap_second(23, 59, 0)?.is_leap_second());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn is_leap_second(self) -> bool {
+        self.second == 60
+    }
+
     /// Get the milliseconds within the second.
     ///
     /// The returned value will always be in the range `0..1_000`.
@@ -414,12 +486,102 @@ impl Time {
     /// Format the `Time` using the provided format description. The formatted value will be output
     /// to the provided writer. The format description will typically be parsed by using
     /// [`format_description::parse`](crate::format_description::parse()).
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(all(feature = "formatting", feature = "std"))))]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
         format: &impl Formattable,
     ) -> Result<usize, crate::error::Format> {
-        format.format_into(output, None, Some(self), None)
+        format.format_into(&mut IoOutput(output), None, Some(self), None)
+    }
+
+    /// Format the `Time` using the provided format description. The formatted value will be
+    /// output to the provided [`fmt::Write`](core::fmt::Write) implementor, returning the number
+    /// of bytes written. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time};
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// let mut output = String::new();
+    /// time!(12:00).format_into_fmt(&mut output, &format)?;
+    /// assert_eq!(output, "12:00:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut FmtOutput(output), None, Some(self), None)
+    }
+
+    /// Format the `Time` using the provided format description. The formatted value will be
+    /// written to the start of the provided buffer, returning the number of bytes written. If the
+    /// buffer is not large enough to hold the formatted value,
+    /// [`error::Format::InsufficientBufferSize`](crate::error::Format::InsufficientBufferSize) is
+    /// returned. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time};
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// let mut buf = [0; 32];
+    /// let len = time!(12:00).format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], "12:00:00".as_bytes());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut SliceOutput::new(output), None, Some(self), None)
+    }
+
+    /// Format the `Time` into the provided buffer using the provided format description,
+    /// returning the formatted value as a string slice. A buffer that is always large enough can be
+    /// sized at compile time using [`max_formatted_len`](crate::formatting::max_formatted_len).
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time};
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// let mut buf = [0; 32];
+    /// assert_eq!(time!(12:00).format_to_buf(&mut buf, &format)?, "12:00:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_buf<'a>(
+        self,
+        buf: &'a mut [u8],
+        format: &impl Formattable,
+    ) -> Result<&'a str, error::Format> {
+        let mut output = StrOutput::new(buf);
+        format.format_into(&mut output, None, Some(self), None)?;
+        Ok(output.into_str())
+    }
+
+    /// Obtain a value that formats the `Time` using the provided format description when
+    /// displayed, such as via `write!` or `format_args!`. No allocation is performed. If the value
+    /// cannot be formatted, [`fmt::Error`] is returned when displaying it.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time};
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// assert_eq!(time!(12:00).display(&format).to_string(), "12:00:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    pub fn display<F: Formattable + ?Sized>(self, format: &F) -> Display<'_, F> {
+        Display::new(format, None, Some(self), None)
+    }
+
+    /// Obtain a value that formats the `Time` using the provided format description when
+    /// displayed. Unlike [`display`](Self::display), an error is returned immediately if the value
+    /// cannot be formatted.
+    pub fn try_display<F: Formattable + ?Sized>(
+        self,
+        format: &F,
+    ) -> Result<Display<'_, F>, error::Format> {
+        self.display(format).validate()
     }
 
     /// Format the `Time` using the provided format description. The format description will
@@ -432,6 +594,11 @@ impl Time {
     /// assert_eq!(time!(12:00).format(&format)?, "12:00:00");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(
+        __time_03_docs,
+        doc(cfg(all(feature = "formatting", feature = "alloc")))
+    )]
     pub fn format(self, format: &impl Formattable) -> Result<String, crate::error::Format> {
         format.format(None, Some(self), None)
     }
@@ -453,6 +620,49 @@ impl Time {
     pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
         description.parse_time(input.as_bytes())
     }
+
+    /// Parse a `Time` from the start of the input using the provided format description,
+    /// returning it along with the remaining input. Unlike [`parse`](Self::parse), characters
+    /// following the parsed value are not an error.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time, Time};
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// assert_eq!(
+    ///     Time::parse_prefix("12:00:00 rest", &format)?,
+    ///     (time!(12:00), " rest")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &impl Parsable,
+    ) -> Result<(Self, &'a str), error::Parse> {
+        description.parse_prefix(input)
+    }
+
+    /// Parse a `Time` from the input using the provided format description, using the provided
+    /// defaults for any values that are not present in the input. See
+    /// [`Parsed::with_defaults`](crate::parsing::Parsed::with_defaults) for details on how the
+    /// defaults are applied.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time, parsing::Parsed, Time};
+    /// let format = format_description::parse("[hour]")?;
+    /// let defaults = Parsed::new().with_minute(30);
+    /// assert_eq!(
+    ///     Time::parse_with_defaults("14", &format, defaults)?,
+    ///     time!(14:30)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_defaults(
+        input: &str,
+        description: &impl Parsable,
+        defaults: Parsed,
+    ) -> Result<Self, error::Parse> {
+        description.parse_with_defaults(input.as_bytes(), defaults)
+    }
 }
 
 impl fmt::Display for Time {
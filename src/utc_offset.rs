@@ -1,13 +1,20 @@
 use core::fmt;
 use core::ops::Neg;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting", feature = "std"))]
 use std::io;
 
 use crate::error;
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
+
+#[cfg(all(feature = "formatting", feature = "std"))]
+use crate::formatting::output::IoOutput;
 #[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::formatting::output::{FmtOutput, SliceOutput, StrOutput};
+#[cfg(feature = "formatting")]
+use crate::formatting::{Display, Formattable};
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{Parsable, Parsed};
 #[cfg(feature = "local-offset")]
 use crate::OffsetDateTime;
 
@@ -262,12 +269,102 @@ impl UtcOffset {
     /// Format the `UtcOffset` using the provided format description. The formatted value will be
     /// output to the provided writer. The format description will typically be parsed by using
     /// [`format_description::parse`](crate::format_description::parse()).
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(all(feature = "formatting", feature = "std"))))]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
         format: &impl Formattable,
     ) -> Result<usize, error::Format> {
-        format.format_into(output, None, None, Some(self))
+        format.format_into(&mut IoOutput(output), None, None, Some(self))
+    }
+
+    /// Format the `UtcOffset` using the provided format description. The formatted value will be
+    /// output to the provided [`fmt::Write`](core::fmt::Write) implementor, returning the number
+    /// of bytes written. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::offset};
+    /// let format = format_description::parse("[offset_hour sign:mandatory]:[offset_minute]")?;
+    /// let mut output = String::new();
+    /// offset!(+1).format_into_fmt(&mut output, &format)?;
+    /// assert_eq!(output, "+01:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut FmtOutput(output), None, None, Some(self))
+    }
+
+    /// Format the `UtcOffset` using the provided format description. The formatted value will be
+    /// written to the start of the provided buffer, returning the number of bytes written. If the
+    /// buffer is not large enough to hold the formatted value,
+    /// [`error::Format::InsufficientBufferSize`](crate::error::Format::InsufficientBufferSize) is
+    /// returned. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::offset};
+    /// let format = format_description::parse("[offset_hour sign:mandatory]:[offset_minute]")?;
+    /// let mut buf = [0; 32];
+    /// let len = offset!(+1).format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], "+01:00".as_bytes());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut SliceOutput::new(output), None, None, Some(self))
+    }
+
+    /// Format the `UtcOffset` into the provided buffer using the provided format description,
+    /// returning the formatted value as a string slice. A buffer that is always large enough can be
+    /// sized at compile time using [`max_formatted_len`](crate::formatting::max_formatted_len).
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::offset};
+    /// let format = format_description::parse("[offset_hour sign:mandatory]:[offset_minute]")?;
+    /// let mut buf = [0; 32];
+    /// assert_eq!(offset!(+1).format_to_buf(&mut buf, &format)?, "+01:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_buf<'a>(
+        self,
+        buf: &'a mut [u8],
+        format: &impl Formattable,
+    ) -> Result<&'a str, error::Format> {
+        let mut output = StrOutput::new(buf);
+        format.format_into(&mut output, None, None, Some(self))?;
+        Ok(output.into_str())
+    }
+
+    /// Obtain a value that formats the `UtcOffset` using the provided format description when
+    /// displayed, such as via `write!` or `format_args!`. No allocation is performed. If the value
+    /// cannot be formatted, [`fmt::Error`] is returned when displaying it.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::offset};
+    /// let format = format_description::parse("[offset_hour sign:mandatory]:[offset_minute]")?;
+    /// assert_eq!(offset!(+1).display(&format).to_string(), "+01:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    pub fn display<F: Formattable + ?Sized>(self, format: &F) -> Display<'_, F> {
+        Display::new(format, None, None, Some(self))
+    }
+
+    /// Obtain a value that formats the `UtcOffset` using the provided format description when
+    /// displayed. Unlike [`display`](Self::display), an error is returned immediately if the value
+    /// cannot be formatted.
+    pub fn try_display<F: Formattable + ?Sized>(
+        self,
+        format: &F,
+    ) -> Result<Display<'_, F>, error::Format> {
+        self.display(format).validate()
     }
 
     /// Format the `UtcOffset` using the provided format description. The format description will
@@ -280,6 +377,11 @@ impl UtcOffset {
     /// assert_eq!(offset!(+1).format(&format)?, "+01:00");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(
+        __time_03_docs,
+        doc(cfg(all(feature = "formatting", feature = "alloc")))
+    )]
     pub fn format(self, format: &impl Formattable) -> Result<String, error::Format> {
         format.format(None, None, Some(self))
     }
@@ -301,6 +403,48 @@ impl UtcOffset {
     pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
         description.parse_offset(input.as_bytes())
     }
+
+    /// Parse a `UtcOffset` from the start of the input using the provided format description,
+    /// returning it along with the remaining input. Unlike [`parse`](Self::parse), characters
+    /// following the parsed value are not an error.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::offset, UtcOffset};
+    /// let format = format_description::parse("[offset_hour]:[offset_minute]")?;
+    /// assert_eq!(
+    ///     UtcOffset::parse_prefix("-03:42 rest", &format)?,
+    ///     (offset!(-3:42), " rest")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &impl Parsable,
+    ) -> Result<(Self, &'a str), error::Parse> {
+        description.parse_prefix(input)
+    }
+
+    /// Parse a `UtcOffset` from the input using the provided format description, using the provided
+    /// defaults for any values that are not present in the input. See
+    /// [`Parsed::with_defaults`](crate::parsing::Parsed::with_defaults) for details on how the
+    /// defaults are applied.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::offset, UtcOffset};
+    /// let format = format_description::parse("")?;
+    /// assert_eq!(
+    ///     UtcOffset::parse_with_defaults("", &format, offset!(+2).into())?,
+    ///     offset!(+2)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_defaults(
+        input: &str,
+        description: &impl Parsable,
+        defaults: Parsed,
+    ) -> Result<Self, error::Parse> {
+        description.parse_with_defaults(input.as_bytes(), defaults)
+    }
 }
 
 impl fmt::Display for UtcOffset {
@@ -12,6 +12,18 @@ pub(crate) enum DateAdjustment {
     None,
 }
 
+/// The English ordinal suffix of the number (e.g. "st" for 1 and 21, but "th" for 11).
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub(crate) const fn ordinal_suffix(n: u8) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
 /// Get the number of days in the month of a given year.
 ///
 /// ```rust
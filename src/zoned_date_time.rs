@@ -0,0 +1,290 @@
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::vec::Vec;
+#[cfg(feature = "formatting")]
+use core::fmt;
+#[cfg(all(feature = "formatting", feature = "std"))]
+use std::io;
+
+#[cfg(feature = "formatting")]
+use crate::error;
+#[cfg(feature = "formatting")]
+use crate::formatting::formattable::Zone;
+#[cfg(all(feature = "formatting", feature = "std"))]
+use crate::formatting::output::IoOutput;
+#[cfg(feature = "formatting")]
+use crate::formatting::output::{FmtOutput, Output, SliceOutput, StrOutput};
+#[cfg(feature = "formatting")]
+use crate::formatting::{Display, Formattable};
+use crate::OffsetDateTime;
+
+/// An [`OffsetDateTime`] along with the time zone it is in.
+///
+/// The time crate does not contain the time zone database. The name and abbreviation of the time
+/// zone are provided by the caller, and are only used when formatting with the `time_zone`
+/// component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZonedDateTime<'a> {
+    /// The date, time, and UTC offset.
+    datetime: OffsetDateTime,
+    /// The name of the time zone in the IANA time zone database.
+    name: &'a str,
+    /// The abbreviation of the time zone in effect.
+    abbreviation: &'a str,
+}
+
+impl<'a> ZonedDateTime<'a> {
+    /// Create a `ZonedDateTime` from the provided [`OffsetDateTime`], along with the IANA name
+    /// and abbreviation of the time zone.
+    ///
+    /// The offset of the `OffsetDateTime` is assumed to be the one in effect in the time zone.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, ZonedDateTime};
+    /// let value = ZonedDateTime::new(
+    ///     datetime!(2021-03-04 10:00 -8),
+    ///     "America/Los_Angeles",
+    ///     "PST",
+    /// );
+    /// assert_eq!(value.name(), "America/Los_Angeles");
+    /// ```
+    pub const fn new(datetime: OffsetDateTime, name: &'a str, abbreviation: &'a str) -> Self {
+        Self {
+            datetime,
+            name,
+            abbreviation,
+        }
+    }
+
+    /// Get the [`OffsetDateTime`] of the value.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, ZonedDateTime};
+    /// let value = ZonedDateTime::new(datetime!(2021-01-01 0:00 UTC), "Etc/UTC", "UTC");
+    /// assert_eq!(value.datetime(), datetime!(2021-01-01 0:00 UTC));
+    /// ```
+    pub const fn datetime(self) -> OffsetDateTime {
+        self.datetime
+    }
+
+    /// Get the name of the time zone in the IANA time zone database.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, ZonedDateTime};
+    /// let value = ZonedDateTime::new(datetime!(2021-01-01 0:00 UTC), "Etc/UTC", "UTC");
+    /// assert_eq!(value.name(), "Etc/UTC");
+    /// ```
+    pub const fn name(self) -> &'a str {
+        self.name
+    }
+
+    /// Get the abbreviation of the time zone in effect.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, ZonedDateTime};
+    /// let value = ZonedDateTime::new(datetime!(2021-01-01 0:00 UTC), "Etc/UTC", "UTC");
+    /// assert_eq!(value.abbreviation(), "UTC");
+    /// ```
+    pub const fn abbreviation(self) -> &'a str {
+        self.abbreviation
+    }
+}
+
+// region: formatting
+#[cfg(feature = "formatting")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+impl<'a> ZonedDateTime<'a> {
+    /// The name and abbreviation of the time zone, as used when formatting.
+    const fn zone(self) -> Zone<'a> {
+        Zone {
+            name: self.name,
+            abbreviation: self.abbreviation,
+        }
+    }
+
+    /// Format the value into the provided output.
+    fn format_into_output(
+        self,
+        output: &mut impl Output,
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        let local = self
+            .datetime
+            .utc_datetime
+            .utc_to_offset(self.datetime.offset);
+        format.format_into_zoned(
+            output,
+            Some(local.date),
+            Some(local.time),
+            Some(self.datetime.offset),
+            Some(self.zone()),
+        )
+    }
+
+    /// Format the `ZonedDateTime` using the provided format description. The formatted value will
+    /// be output to the provided writer. The format description will typically be parsed by using
+    /// [`format_description::parse`](crate::format_description::parse()).
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(all(feature = "formatting", feature = "std"))))]
+    pub fn format_into(
+        self,
+        output: &mut impl io::Write,
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        self.format_into_output(&mut IoOutput(output), format)
+    }
+
+    /// Format the `ZonedDateTime` using the provided format description. The formatted value will
+    /// be output to the provided [`fmt::Write`](core::fmt::Write) implementor, returning the
+    /// number of bytes written. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, ZonedDateTime};
+    /// let format = format_description::parse("[hour]:[minute] [time_zone]")?;
+    /// let value = ZonedDateTime::new(
+    ///     datetime!(2021-03-04 10:00 -8),
+    ///     "America/Los_Angeles",
+    ///     "PST",
+    /// );
+    /// let mut output = String::new();
+    /// value.format_into_fmt(&mut output, &format)?;
+    /// assert_eq!(output, "10:00 PST");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        self.format_into_output(&mut FmtOutput(output), format)
+    }
+
+    /// Format the `ZonedDateTime` using the provided format description. The formatted value will
+    /// be written to the start of the provided buffer, returning the number of bytes written. If
+    /// the buffer is not large enough to hold the formatted value,
+    /// [`error::Format::InsufficientBufferSize`](crate::error::Format::InsufficientBufferSize) is
+    /// returned. This method is available without the standard library.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, ZonedDateTime};
+    /// let format = format_description::parse("[hour]:[minute] [time_zone]")?;
+    /// let value = ZonedDateTime::new(
+    ///     datetime!(2021-03-04 10:00 -8),
+    ///     "America/Los_Angeles",
+    ///     "PST",
+    /// );
+    /// let mut buf = [0; 16];
+    /// let len = value.format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], b"10:00 PST");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &impl Formattable,
+    ) -> Result<usize, error::Format> {
+        self.format_into_output(&mut SliceOutput::new(output), format)
+    }
+
+    /// Format the `ZonedDateTime` into the provided buffer using the provided format description,
+    /// returning the formatted value as a string slice. A buffer that is always large enough can be
+    /// sized at compile time using [`max_formatted_len`](crate::formatting::max_formatted_len).
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, ZonedDateTime};
+    /// let format = format_description::parse("[hour]:[minute] [time_zone repr:name]")?;
+    /// let value = ZonedDateTime::new(
+    ///     datetime!(2021-03-04 10:00 -8),
+    ///     "America/Los_Angeles",
+    ///     "PST",
+    /// );
+    /// let mut buf = [0; 32];
+    /// assert_eq!(
+    ///     value.format_to_buf(&mut buf, &format)?,
+    ///     "10:00 America/Los_Angeles"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_buf<'b>(
+        self,
+        buf: &'b mut [u8],
+        format: &impl Formattable,
+    ) -> Result<&'b str, error::Format> {
+        let mut output = StrOutput::new(buf);
+        self.format_into_output(&mut output, format)?;
+        Ok(output.into_str())
+    }
+
+    /// Obtain a value that formats the `ZonedDateTime` using the provided format description
+    /// when displayed, such as via `write!` or `format_args!`. No allocation is performed. If the
+    /// value cannot be formatted, [`fmt::Error`] is returned when displaying it.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, ZonedDateTime};
+    /// let format = format_description::parse("[hour]:[minute] [time_zone]")?;
+    /// let value = ZonedDateTime::new(
+    ///     datetime!(2021-03-04 10:00 -8),
+    ///     "America/Los_Angeles",
+    ///     "PST",
+    /// );
+    /// assert_eq!(value.display(&format).to_string(), "10:00 PST");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // trait bounds on const fn are unstable
+    pub fn display<F: Formattable + ?Sized>(self, format: &'a F) -> Display<'a, F> {
+        let local = self
+            .datetime
+            .utc_datetime
+            .utc_to_offset(self.datetime.offset);
+        Display::new(
+            format,
+            Some(local.date),
+            Some(local.time),
+            Some(self.datetime.offset),
+        )
+        .with_zone(self.zone())
+    }
+
+    /// Obtain a value that formats the `ZonedDateTime` using the provided format description
+    /// when displayed. Unlike [`display`](Self::display), an error is returned immediately if the
+    /// value cannot be formatted.
+    pub fn try_display<F: Formattable + ?Sized>(
+        self,
+        format: &'a F,
+    ) -> Result<Display<'a, F>, error::Format> {
+        self.display(format).validate()
+    }
+
+    /// Format the `ZonedDateTime` using the provided format description. The format description
+    /// will typically be parsed by using
+    /// [`format_description::parse`](crate::format_description::parse()).
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, ZonedDateTime};
+    /// let format = format_description::parse(
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second] [time_zone] [[[time_zone repr:name]]",
+    /// )?;
+    /// let value = ZonedDateTime::new(
+    ///     datetime!(2021-03-04 10:00 -8),
+    ///     "America/Los_Angeles",
+    ///     "PST",
+    /// );
+    /// assert_eq!(
+    ///     value.format(&format)?,
+    ///     "2021-03-04 10:00:00 PST [America/Los_Angeles]"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(
+        __time_03_docs,
+        doc(cfg(all(feature = "formatting", feature = "alloc")))
+    )]
+    pub fn format(self, format: &impl Formattable) -> Result<String, error::Format> {
+        let mut buf = Vec::new();
+        self.format_into_output(&mut buf, format)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+}
+// endregion formatting
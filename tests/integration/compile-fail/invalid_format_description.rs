@@ -9,4 +9,15 @@ fn main() {
     let _ = format_description!("" x); //~ERROR unexpected token: x
     let _ = format_description!(x); //~ERROR expected string
     let _ = format_description!(0); //~ERROR expected string
+    let _ = format_description!(version = 3, ""); //~ERROR invalid component: version was 3
+    let _ = format_description!(version = 2, "\\a"); //~ERROR expected valid escape sequence at byte index 0
+    let _ = format_description!(version = 2, "]"); //~ERROR unexpected closing bracket at byte index 0
+    let _ = format_description!(version = 2, "[optional]"); //~ERROR expected opening bracket at byte index 9
+    let _ = format_description!(version = 2, "[case_insensitive [[hour]]]"); //~ERROR expected literal at byte index 19
+    let _ = format_description!(target = Foo, ""); //~ERROR invalid component: target was Foo
+    let _ = format_description!(target = Date, "[year]-[month]"); //~ERROR `Date` cannot be parsed with this format description, as it does not always contain a complete date
+    let _ = format_description!(target = Date, "[hour]"); //~ERROR `Date` cannot be formatted with this format description, as it requires a time
+    let _ = format_description!(target = Time, "[hour]:[minute]:[second]:[second]"); //~ERROR component `second` may replace a value set by an earlier component
+    let _ = format_description!(version = 2, target = Time, "[hour][optional [:[minute]]]"); //~ERROR `Time` cannot be parsed with this format description, as it does not always contain a complete time
+    let _ = format_description!(version = 2, target = Date, "[year]-[first [[ordinal]] [[month]]]"); //~ERROR `Date` cannot be parsed with this format description, as it does not always contain a complete date
 }
@@ -732,3 +732,70 @@ fn arithmetic_regression() {
     assert_eq!(subtracted.whole_seconds(), 3);
     assert_eq!(subtracted.subsec_milliseconds(), 200);
 }
+
+#[test]
+fn display() {
+    assert_eq!(0.seconds().to_string(), "0s");
+    assert_eq!(1.nanoseconds().to_string(), "1ns");
+    assert_eq!(1_500.microseconds().to_string(), "1ms500µs");
+    assert_eq!(250.milliseconds().to_string(), "250ms");
+    assert_eq!((1.hours() + 30.minutes()).to_string(), "1h30m");
+    assert_eq!((-90).minutes().to_string(), "-1h30m");
+    assert_eq!((2.days() + 4.hours()).to_string(), "2d4h");
+    assert_eq!(3.weeks().to_string(), "21d");
+    assert_eq!((1.hours() + 1.nanoseconds()).to_string(), "1h1ns");
+    assert_eq!(
+        Duration::MAX.to_string(),
+        "106751991167300d15h30m7s999ms999µs999ns"
+    );
+    assert_eq!(
+        Duration::MIN.to_string(),
+        "-106751991167300d15h30m8s999ms999µs999ns"
+    );
+}
+
+#[cfg(feature = "parsing")]
+#[test]
+fn from_str() -> Result<(), error::Parse> {
+    assert_eq!("0".parse::<Duration>()?, Duration::ZERO);
+    assert_eq!("0s".parse::<Duration>()?, Duration::ZERO);
+    assert_eq!("1h30m".parse::<Duration>()?, 90.minutes());
+    assert_eq!("250ms".parse::<Duration>()?, 250.milliseconds());
+    assert_eq!("2d 4h".parse::<Duration>()?, 52.hours());
+    assert_eq!("2 days 3 hours".parse::<Duration>()?, 51.hours());
+    assert_eq!(" -1.5 Hours ".parse::<Duration>()?, (-90).minutes());
+    assert_eq!("+1m1s".parse::<Duration>()?, 61.seconds());
+    assert_eq!(".5s".parse::<Duration>()?, 500.milliseconds());
+    assert_eq!("1.0000000001s".parse::<Duration>()?, 1.seconds());
+    assert_eq!("10us".parse::<Duration>()?, 10.microseconds());
+    assert_eq!("10µs".parse::<Duration>()?, 10.microseconds());
+    for duration in &[
+        Duration::MAX,
+        Duration::MIN,
+        (-1).nanoseconds(),
+        1_234.days(),
+    ] {
+        assert_eq!(duration.to_string().parse::<Duration>()?, *duration);
+    }
+
+    assert!("".parse::<Duration>().is_err());
+    assert!("1".parse::<Duration>().is_err());
+    assert!("1h 30".parse::<Duration>().is_err());
+    assert!("1x".parse::<Duration>().is_err());
+    assert!("1w".parse::<Duration>().is_err());
+    assert!("1h, 30m".parse::<Duration>().is_err());
+    assert!("10us 10µs".parse::<Duration>().is_err());
+    assert!("1h1h".parse::<Duration>().is_err());
+    assert!("1.h".parse::<Duration>().is_err());
+    assert!("999999999999999999999d".parse::<Duration>().is_err());
+    assert!("106751991167301d".parse::<Duration>().is_err());
+
+    let err = "1h 30x".parse::<Duration>().unwrap_err();
+    assert_eq!(
+        err.expected(),
+        Some(error::ParseFromDescription::InvalidComponent("unit"))
+    );
+    assert_eq!(err.position(), Some(5));
+
+    Ok(())
+}
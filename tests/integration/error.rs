@@ -1,9 +1,9 @@
 use std::error::Error as _;
-use std::io;
+use std::{fmt, io};
 
 use time::error::{
     ComponentRange, ConversionRange, Error, Format, IndeterminateOffset, InvalidFormatDescription,
-    Parse, ParseFromDescription, TryFromParsed,
+    InvalidLeapSecondTable, Parse, ParseFromDescription, TryFromParsed,
 };
 use time::format_description::{modifier, Component, FormatItem};
 use time::macros::format_description;
@@ -38,6 +38,16 @@ fn unexpected_trailing_characters() -> Parse {
     Time::parse("a", &format_description!("")).unwrap_err()
 }
 
+fn located() -> Parse {
+    Time::parse("a", &format_description!("[hour]")).unwrap_err()
+}
+
+fn insufficient_buffer_size() -> Format {
+    Time::MIDNIGHT
+        .format_into_slice(&mut [0; 2], &format_description!("[hour]:[minute]"))
+        .unwrap_err()
+}
+
 fn io_error() -> io::Error {
     io::Error::last_os_error()
 }
@@ -99,11 +109,29 @@ fn display() {
         unexpected_trailing_characters(),
         Error::from(unexpected_trailing_characters()),
     );
+    assert_display_eq!(
+        located(),
+        "the 'hour' component could not be parsed at byte index 0"
+    );
+    assert_display_eq!(
+        unexpected_trailing_characters(),
+        "unexpected trailing characters at byte index 0"
+    );
+    assert_display_eq!(located(), Error::from(located()));
     assert_display_eq!(
         InvalidFormatDescription::UnclosedOpeningBracket { index: 0 },
         Error::from(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 })
     );
+    assert_display_eq!(
+        InvalidLeapSecondTable::Unsorted { index: 1 },
+        Error::from(InvalidLeapSecondTable::Unsorted { index: 1 })
+    );
     assert_display_eq!(io_error(), Format::from(io_error()));
+    assert_display_eq!(fmt::Error, Format::from(fmt::Error));
+    assert_display_eq!(
+        insufficient_buffer_size(),
+        Error::from(insufficient_buffer_size())
+    );
 }
 
 #[test]
@@ -112,6 +140,10 @@ fn source() {
     assert_source!(Error::from(component_range()), ComponentRange);
     assert_source!(TryFromParsed::from(component_range()), ComponentRange);
     assert_source!(TryFromParsed::InsufficientInformation, None);
+    assert_source!(
+        TryFromParsed::InconsistentInformation { component: "a" },
+        None
+    );
     assert_source!(insufficient_type_information(), None);
     assert_source!(Format::InvalidComponent("a"), None);
     assert_source!(Error::from(insufficient_type_information()), Format);
@@ -132,11 +164,22 @@ fn source() {
         Error::from(ParseFromDescription::InvalidComponent("a")),
         ParseFromDescription
     );
-    assert_source!(unexpected_trailing_characters(), None);
-    assert_source!(Error::from(unexpected_trailing_characters()), None);
+    assert_source!(located(), ParseFromDescription);
+    assert_source!(Error::from(located()), ParseFromDescription);
+    assert_source!(unexpected_trailing_characters(), ParseFromDescription);
+    assert_source!(
+        Error::from(unexpected_trailing_characters()),
+        ParseFromDescription
+    );
     assert_source!(
         Error::from(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 }),
         InvalidFormatDescription
     );
+    assert_source!(
+        Error::from(InvalidLeapSecondTable::InvalidLine { line: 1 }),
+        InvalidLeapSecondTable
+    );
     assert_source!(Format::from(io_error()), io::Error);
+    assert_source!(Format::from(fmt::Error), fmt::Error);
+    assert_source!(insufficient_buffer_size(), None);
 }
@@ -1,8 +1,9 @@
-use std::io;
+use std::{fmt, io};
 
 use time::format_description::well_known::Rfc3339;
+use time::format_description::{self, FormatItem, Version};
 use time::macros::{date, datetime, format_description as fd, offset, time};
-use time::{format_description, Time};
+use time::{Date, Month, Time, ZonedDateTime};
 
 #[test]
 fn rfc_3339() -> time::Result<()> {
@@ -192,6 +193,13 @@ fn format_date() -> time::Result<()> {
         (fd!("[year base:iso_week sign:mandatory]"), "+2020"),
         (fd!("[year repr:last_two]"), "19"),
         (fd!("[year base:iso_week repr:last_two]"), "20"),
+        (fd!("[day ordinal_suffix:true]"), "31st"),
+        (fd!("[quarter]"), "4"),
+        (fd!("[quarter repr:short]"), "Q4"),
+        (fd!("[century]"), "20"),
+        (fd!("[century sign:mandatory]"), "+20"),
+        (fd!("[year repr:of_era] [era]"), "2019 AD"),
+        (fd!("[era repr:ce]"), "CE"),
     ];
 
     for &(format_description, output) in &format_output {
@@ -330,10 +338,643 @@ fn display_odt() {
     );
 }
 
+#[test]
+fn format_trimmed_subsecond() -> time::Result<()> {
+    let format_description = fd!("[second].[subsecond digits:3 trim_trailing_zeros:true]");
+    assert_eq!(time!(0:00:01.12).format(&format_description)?, "01.12");
+    assert_eq!(time!(0:00:01.1).format(&format_description)?, "01.1");
+    assert_eq!(time!(0:00:01.000_9).format(&format_description)?, "01");
+    assert_eq!(time!(0:00:01).format(&format_description)?, "01");
+    assert_eq!(
+        time!(0:00:01.123_456_789).format(&fd!("[subsecond trim_trailing_zeros:true]"))?,
+        "123456789"
+    );
+    assert_eq!(
+        time!(0:00:01).format(&fd!("[subsecond trim_trailing_zeros:true]"))?,
+        ""
+    );
+    Ok(())
+}
+
+#[test]
+fn format_width_and_rounding() -> time::Result<()> {
+    assert_eq!(
+        date!(2021 - 01 - 02).format(&fd!("[year width:6]"))?,
+        "002021"
+    );
+    assert_eq!(
+        date!(-0021 - 01 - 02).format(&fd!("[year width:6 padding:space]"))?,
+        "-    21"
+    );
+    assert_eq!(date!(0005 - 01 - 02).format(&fd!("[year width:2]"))?, "05");
+    assert_eq!(
+        date!(2021 - 01 - 02).format(&fd!("[year width:1]"))?,
+        "2021"
+    );
+    assert_eq!(
+        date!(2005 - 01 - 02).format(&fd!("[year repr:last_two width:3]"))?,
+        "005"
+    );
+    assert_eq!(
+        offset!(+1).format(&fd!("[offset_hour sign:mandatory width:1]"))?,
+        "+1"
+    );
+    assert_eq!(offset!(-1).format(&fd!("[offset_hour width:3]"))?, "-001");
+    assert_eq!(
+        date!(2021 - 01 - 02).format(&fd!("[century width:3]"))?,
+        "020"
+    );
+    assert_eq!(
+        date!(-0021 - 01 - 02).format(&fd!("[century width:1]"))?,
+        "-0"
+    );
+
+    let truncate = fd!("[subsecond digits:3]");
+    let nearest = fd!("[subsecond digits:3 rounding:nearest_saturating]");
+    assert_eq!(time!(0:00:00.123_499).format(&truncate)?, "123");
+    assert_eq!(time!(0:00:00.123_499).format(&nearest)?, "123");
+    assert_eq!(time!(0:00:00.123_5).format(&truncate)?, "123");
+    assert_eq!(time!(0:00:00.123_5).format(&nearest)?, "124");
+    assert_eq!(time!(0:00:00.999_9).format(&nearest)?, "999");
+    let second = fd!("[second].[subsecond digits:3 rounding:nearest_saturating]");
+    assert_eq!(time!(0:00:01.999_6).format(&second)?, "01.999");
+    assert_eq!(time!(0:00:59.999_6).format(&second)?, "59.999");
+    assert_eq!(
+        datetime!(2021-12-31 23:59:59.999_6).format(&fd!(
+            "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond digits:3 \
+             rounding:nearest_saturating]"
+        ))?,
+        "2021-12-31 23:59:59.999"
+    );
+    assert_eq!(
+        time!(0:00:00.123_456_789).format(&fd!("[subsecond rounding:nearest_saturating]"))?,
+        "123456789"
+    );
+    assert_eq!(
+        time!(0:00:00.149_6).format(&fd!(
+            "[subsecond digits:3 rounding:nearest_saturating trim_trailing_zeros:true]"
+        ))?,
+        "15"
+    );
+    Ok(())
+}
+
+#[test]
+fn format_offset_z_for_utc() -> time::Result<()> {
+    let format_description =
+        fd!("[offset_hour sign:mandatory z_for_utc:true]:[offset_minute]:[offset_second]");
+    assert_eq!(offset!(UTC).format(&format_description)?, "Z");
+    assert_eq!(offset!(+1).format(&format_description)?, "+01:00:00");
+    assert_eq!(offset!(-1:02:03).format(&format_description)?, "-01:02:03");
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 UTC).format(&fd!(
+            "[hour]:[minute] [offset_hour z_for_utc:true][offset_minute] UTC"
+        ))?,
+        "03:04 Z UTC"
+    );
+    Ok(())
+}
+
+#[test]
+fn format_offset_component() -> time::Result<()> {
+    let format_description = fd!("[offset]");
+    assert_eq!(offset!(UTC).format(&format_description)?, "Z");
+    assert_eq!(offset!(+1).format(&format_description)?, "+01:00");
+    assert_eq!(offset!(-1:02).format(&format_description)?, "-01:02");
+    assert_eq!(offset!(-0:00:03).format(&format_description)?, "-00:00:03");
+
+    assert_eq!(
+        offset!(UTC).format(&fd!("[offset z_for_utc:false colon:false]"))?,
+        "+0000"
+    );
+    let format_description = fd!("[offset minute:optional second:omitted]");
+    assert_eq!(offset!(+5).format(&format_description)?, "+05");
+    assert_eq!(offset!(+5:30).format(&format_description)?, "+05:30");
+    assert!(matches!(
+        offset!(+5:30:01).format(&format_description),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+    assert_eq!(
+        offset!(+5:30).format(&fd!("[offset colon:false second:mandatory]"))?,
+        "+053000"
+    );
+    assert!(matches!(
+        offset!(+5:30).format(&fd!("[offset minute:omitted]")),
+        Err(time::error::Format::InvalidComponent("offset_minute"))
+    ));
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 +01:00).format(&fd!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second][offset]"
+        ))?,
+        "2021-01-02T03:04:05+01:00"
+    );
+
+    assert_eq!(FormatItem::Compound(fd!("[offset]")).max_formatted_len(), 9);
+    assert_eq!(
+        FormatItem::Compound(fd!("[offset colon:false second:omitted]")).max_formatted_len(),
+        5
+    );
+    Ok(())
+}
+
+#[test]
+fn format_era() -> time::Result<()> {
+    let format_description = fd!("[year repr:of_era padding:none] [era]");
+    assert_eq!(date!(1 - 01 - 01).format(&format_description)?, "1 AD");
+    assert_eq!(date!(0 - 01 - 01).format(&format_description)?, "1 BC");
+    assert_eq!(date!(-43 - 03 - 15).format(&format_description)?, "44 BC");
+    assert_eq!(date!(-43 - 03 - 15).format(&fd!("[era repr:ce]"))?, "BCE");
+    assert_eq!(date!(-2021 - 01 - 01).format(&fd!("[century]"))?, "-20");
+    assert_eq!(date!(-50 - 01 - 01).format(&fd!("[century]"))?, "-00");
+    if cfg!(feature = "large-dates") {
+        let date = Date::from_calendar_date(123_456, Month::May, 3)?;
+        assert!(matches!(
+            date.format(&fd!("[century]")),
+            Err(time::error::Format::InvalidComponent("century"))
+        ));
+        assert_eq!(date.format(&fd!("[century width:4]"))?, "1234");
+    }
+
+    let format_description = fd!("[month repr:long] [day padding:none ordinal_suffix:true]");
+    for &(day, output) in &[
+        (1, "January 1st"),
+        (2, "January 2nd"),
+        (3, "January 3rd"),
+        (4, "January 4th"),
+        (11, "January 11th"),
+        (12, "January 12th"),
+        (13, "January 13th"),
+        (21, "January 21st"),
+        (22, "January 22nd"),
+        (23, "January 23rd"),
+        (31, "January 31st"),
+    ] {
+        assert_eq!(
+            Date::from_calendar_date(2021, Month::January, day)?.format(&format_description)?,
+            output
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn format_locale() -> time::Result<()> {
+    let format_output = [
+        (
+            fd!("[weekday locale:fr] [day] [month repr:long locale:fr]"),
+            "lundi 03 février",
+        ),
+        (
+            fd!("[weekday repr:short locale:de] [month repr:short locale:de]"),
+            "Mo. Feb.",
+        ),
+        (fd!("[month repr:short locale:de standalone:true]"), "Feb"),
+        (fd!("[month repr:long locale:ru]"), "февраля"),
+        (
+            fd!("[month repr:long locale:ru standalone:true]"),
+            "февраль",
+        ),
+        (
+            fd!("[year]年[month repr:long locale:ja][day]日 [weekday locale:ja]"),
+            "2020年2月03日 月曜日",
+        ),
+        (
+            fd!("[weekday repr:short locale:es] [month repr:short locale:es]"),
+            "lun feb",
+        ),
+        (fd!("[weekday locale:it]"), "lunedì"),
+        (fd!("[hour repr:12] [period locale:ja]"), "03 午後"),
+        (fd!("[hour repr:12] [period locale:es]"), "03 p.\u{a0}m."),
+        (fd!("[hour repr:12] [period locale:de case:lower]"), "03 pm"),
+    ];
+
+    for &(format_description, output) in &format_output {
+        assert_eq!(
+            datetime!(2020-02-03 15:00).format(&format_description)?,
+            output
+        );
+    }
+
+    let format_description = format_description::parse("[month repr:long locale:fr]")?;
+    assert_eq!(date!(2021 - 08 - 01).format(&format_description)?, "août");
+
+    Ok(())
+}
+
+#[test]
+fn format_time_zone() -> time::Result<()> {
+    let value = ZonedDateTime::new(
+        datetime!(2021-03-04 10:00 -08:00),
+        "America/Los_Angeles",
+        "PST",
+    );
+    assert_eq!(
+        value.format(&fd!(
+            "[year]-[month]-[day] [hour]:[minute]:[second] [time_zone]"
+        ))?,
+        "2021-03-04 10:00:00 PST"
+    );
+    assert_eq!(
+        value.format(&fd!("[hour]:[minute][offset_hour][[[time_zone repr:name]]"))?,
+        "10:00-08[America/Los_Angeles]"
+    );
+    assert_eq!(
+        value.format(&format_description::parse_go_layout("15:04 MST")?)?,
+        "10:00 PST"
+    );
+
+    let mut output = String::new();
+    value.format_into_fmt(&mut output, &fd!("[time_zone]"))?;
+    assert_eq!(output, "PST");
+
+    let mut buf = Vec::new();
+    value.format_into(&mut buf, &fd!("[time_zone repr:name]"))?;
+    assert_eq!(buf, b"America/Los_Angeles");
+
+    let mut buf = [0; 9];
+    let len = value.format_into_slice(&mut buf, &fd!("[hour]:[minute] [time_zone]"))?;
+    assert_eq!(&buf[..len], b"10:00 PST");
+    assert!(matches!(
+        value.format_into_slice(&mut buf, &fd!("[time_zone repr:name]")),
+        Err(time::error::Format::InsufficientBufferSize { .. })
+    ));
+    let mut buf = [0; 32];
+    assert_eq!(
+        value.format_to_buf(&mut buf, &fd!("[time_zone] [time_zone repr:name]"))?,
+        "PST America/Los_Angeles"
+    );
+    assert_eq!(
+        value.display(&fd!("[time_zone repr:name]")).to_string(),
+        "America/Los_Angeles"
+    );
+    assert_eq!(value.try_display(&fd!("[time_zone]"))?.to_string(), "PST");
+
+    let too_long = ZonedDateTime::new(datetime!(2021-03-04 10:00 UTC), "Etc/UTC", "TOOLONG");
+    assert!(matches!(
+        too_long.format(&fd!("[time_zone]")),
+        Err(time::error::Format::InvalidComponent("time zone"))
+    ));
+    assert!(matches!(
+        too_long.try_display(&fd!("[time_zone]")),
+        Err(time::error::Format::InvalidComponent("time zone"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn format_input_components() -> time::Result<()> {
+    assert_eq!(
+        date!(2021 - 03 - 04).format(&fd!(
+            "[year][whitespace][ignore count:3][month][ignore]-[day][end]"
+        ))?,
+        "2021 03-04"
+    );
+    Ok(())
+}
+
+#[test]
+fn format_nested_items() -> time::Result<()> {
+    let format = fd!(version = 2, "[hour]:[minute][optional [:[second]]]");
+    assert_eq!(time!(13:02:03).format(&format)?, "13:02:03");
+
+    let format = format_description::parse_owned(
+        Version::V2,
+        r"[first [[hour]h] [[minute]m]] \[[optional [[year]]]\]",
+    )?;
+    assert_eq!(datetime!(2021-01-02 03:04).format(&format)?, "03h [2021]");
+    assert_eq!(
+        datetime!(2021-01-02 03:04).format(&vec![format.clone(), format])?,
+        "03h [2021]03h [2021]"
+    );
+
+    assert!(matches!(
+        time!(0:00).format(&fd!(version = 2, "[optional [[year]]]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    // Case-insensitive literals are formatted as written.
+    let format = fd!(version = 2, "[hour][case_insensitive [h]][minute]");
+    assert_eq!(time!(13:02).format(&format)?, "13h02");
+    assert_eq!(
+        time!(13:02).format(&FormatItem::CaseInsensitiveLiteral(b"T"))?,
+        "T"
+    );
+    assert_eq!(
+        FormatItem::CaseInsensitiveLiteral(b"abc").max_formatted_len(),
+        3
+    );
+    Ok(())
+}
+
+#[test]
+fn format_go_layout() -> time::Result<()> {
+    let layout = format_description::parse_go_layout("Mon Jan _2 15:04:05.000 2006")?;
+    assert_eq!(
+        datetime!(2021-05-03 14:05:06.1).format(&layout)?,
+        "Mon May  3 14:05:06.100 2021"
+    );
+
+    let layout = format_description::parse_go_layout("2006-01-02T15:04:05.999999999Z07:00")?;
+    assert_eq!(
+        datetime!(2021-05-03 14:05:06 UTC).format(&layout)?,
+        "2021-05-03T14:05:06Z"
+    );
+    assert_eq!(
+        datetime!(2021-05-03 14:05:06.12 -07:00).format(&layout)?,
+        "2021-05-03T14:05:06.12-07:00"
+    );
+
+    let layout = format_description::parse_go_layout("Monday, 02-Jan-06 3:4:5 pm -0700")?;
+    assert_eq!(
+        datetime!(2021-05-03 14:05:06 +01:30).format(&layout)?,
+        "Monday, 03-May-21 2:5:6 pm +0130"
+    );
+
+    let layout = format_description::parse_go_layout("January __2 002 _2006")?;
+    assert_eq!(
+        date!(2021 - 02 - 03).format(&layout)?,
+        "February  34 034 _2021"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn format_into_fmt() -> time::Result<()> {
+    let mut output = String::new();
+    assert_eq!(
+        date!(2021 - 01 - 02).format_into_fmt(&mut output, &fd!("[year]-[month]-[day]"))?,
+        10
+    );
+    assert_eq!(output, "2021-01-02");
+
+    let mut output = String::new();
+    time!(13:02:03.456).format_into_fmt(&mut output, &fd!("[hour]:[minute]:[second]"))?;
+    datetime!(2021-01-02 03:04:05).format_into_fmt(&mut output, &fd!(" [year] [hour]"))?;
+    offset!(-1).format_into_fmt(&mut output, &fd!(" [offset_hour] "))?;
+    datetime!(2021-01-02 03:04:05 +1).format_into_fmt(&mut output, &Rfc3339)?;
+    assert_eq!(output, "13:02:03 2021 03 -01 2021-01-02T03:04:05+01:00");
+
+    // Invalid UTF-8 is replaced, as it is when formatting to a `String`.
+    let mut output = String::new();
+    assert_eq!(
+        Time::MIDNIGHT.format_into_fmt(&mut output, &FormatItem::Literal(b"a\xFFb"))?,
+        5
+    );
+    assert_eq!(output, "a\u{FFFD}b");
+    assert_eq!(
+        Time::MIDNIGHT.format(&FormatItem::Literal(b"a\xFFb"))?,
+        "a\u{FFFD}b"
+    );
+
+    struct AlwaysFails;
+    impl fmt::Write for AlwaysFails {
+        fn write_str(&mut self, _: &str) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+    assert!(matches!(
+        Time::MIDNIGHT.format_into_fmt(&mut AlwaysFails, &fd!("[hour]")),
+        Err(time::error::Format::Fmt(fmt::Error))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn format_into_slice() -> time::Result<()> {
+    let mut buf = [0; 10];
+    assert_eq!(
+        date!(2021 - 01 - 02).format_into_slice(&mut buf, &fd!("[year]-[month]-[day]"))?,
+        10
+    );
+    assert_eq!(&buf, b"2021-01-02");
+
+    let mut buf = [0; 32];
+    let len = datetime!(2021-01-02 03:04:05 +1).format_into_slice(&mut buf, &Rfc3339)?;
+    assert_eq!(&buf[..len], b"2021-01-02T03:04:05+01:00");
+    let len = datetime!(2021-01-02 03:04:05).format_into_slice(&mut buf, &fd!("[hour]"))?;
+    assert_eq!(&buf[..len], b"03");
+    let len = time!(13:02:03).format_into_slice(&mut buf, &fd!("[minute]"))?;
+    assert_eq!(&buf[..len], b"02");
+    let len = offset!(+1:02).format_into_slice(&mut buf, &fd!("[offset_minute]"))?;
+    assert_eq!(&buf[..len], b"02");
+
+    assert!(matches!(
+        date!(2021 - 01 - 02).format_into_slice(&mut [0; 9], &fd!("[year]-[month]-[day]")),
+        Err(time::error::Format::InsufficientBufferSize { .. })
+    ));
+    assert!(matches!(
+        Time::MIDNIGHT.format_into_slice(&mut [], &fd!("[hour]")),
+        Err(time::error::Format::InsufficientBufferSize { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn format_to_buf() -> time::Result<()> {
+    let mut buf = [0; 32];
+    assert_eq!(
+        date!(2021 - 01 - 02).format_to_buf(&mut buf, &fd!("[year]-[month]-[day]"))?,
+        "2021-01-02"
+    );
+    assert_eq!(
+        time!(13:02:03).format_to_buf(&mut buf, &fd!("[hour]:[minute]:[second]"))?,
+        "13:02:03"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05).format_to_buf(&mut buf, &fd!("[year] [hour]"))?,
+        "2021 03"
+    );
+    assert_eq!(
+        offset!(-1).format_to_buf(&mut buf, &fd!("[offset_hour]"))?,
+        "-01"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 +1).format_to_buf(&mut buf, &Rfc3339)?,
+        "2021-01-02T03:04:05+01:00"
+    );
+    assert_eq!(
+        Time::MIDNIGHT.format_to_buf(&mut buf, &FormatItem::Literal(b"a\xFFb"))?,
+        "a\u{FFFD}b"
+    );
+    assert!(matches!(
+        date!(2021 - 01 - 02).format_to_buf(&mut [0; 9], &fd!("[year]-[month]-[day]")),
+        Err(time::error::Format::InsufficientBufferSize { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn display_adapter() -> time::Result<()> {
+    use std::fmt::Write;
+
+    let format = fd!("[year]-[month]-[day]");
+    assert_eq!(
+        date!(2021 - 01 - 02).display(&format).to_string(),
+        "2021-01-02"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            time!(13:02:03).display(&fd!("[hour]:[minute]:[second]"))
+        ),
+        "13:02:03"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            datetime!(2021-01-02 03:04:05).display(&fd!("[year] [hour]"))
+        ),
+        "2021 03"
+    );
+    assert_eq!(
+        format!("{}", offset!(-1).display(&fd!("[offset_hour]"))),
+        "-01"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 +1)
+            .try_display(&Rfc3339)?
+            .to_string(),
+        "2021-01-02T03:04:05+01:00"
+    );
+    assert_eq!(
+        Time::MIDNIGHT
+            .display(&FormatItem::Literal(b"a\xFFb"))
+            .to_string(),
+        "a\u{FFFD}b"
+    );
+
+    let mut s = String::new();
+    assert_eq!(
+        write!(s, "{}", Time::MIDNIGHT.display(&fd!("[year]"))),
+        Err(fmt::Error)
+    );
+    assert_eq!(
+        write!(s, "{}", Time::MIDNIGHT.display(&Rfc3339)),
+        Err(fmt::Error)
+    );
+    assert!(matches!(
+        Time::MIDNIGHT.try_display(&fd!("[year]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert!(matches!(
+        datetime!(2021-01-02 03:04:05 +00:00:01).try_display(&Rfc3339),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn max_formatted_len() -> time::Result<()> {
+    use time::formatting::max_formatted_len;
+
+    const DATE: &[FormatItem<'_>] = fd!("[year]-[month]-[day]");
+    assert_eq!(
+        max_formatted_len(DATE),
+        if cfg!(feature = "large-dates") {
+            13
+        } else {
+            11
+        }
+    );
+    let mut buf = [0; max_formatted_len(DATE)];
+    assert_eq!(
+        date!(-2021 - 01 - 02).format_to_buf(&mut buf, &DATE)?,
+        "-2021-01-02"
+    );
+
+    const CENTURY: &[FormatItem<'_>] = fd!("[century sign:mandatory]");
+    assert_eq!(max_formatted_len(CENTURY), 3);
+    let mut buf = [0; max_formatted_len(CENTURY)];
+    assert_eq!(date!(2021 - 01 - 02).format_to_buf(&mut buf, &CENTURY)?, "+20");
+    let mut buf = [0; max_formatted_len(fd!("[century]"))];
+    assert_eq!(
+        date!(-9999 - 01 - 02).format_to_buf(&mut buf, &fd!("[century]"))?,
+        "-99"
+    );
+    assert_eq!(
+        max_formatted_len(fd!("[century sign:mandatory width:4]")),
+        5
+    );
+
+    assert_eq!(max_formatted_len(&[]), 0);
+    assert_eq!(FormatItem::Literal(b"foo").max_formatted_len(), 3);
+    assert_eq!(
+        FormatItem::Compound(fd!("[hour]:[minute] [period]")).max_formatted_len(),
+        8
+    );
+    assert_eq!(
+        FormatItem::Compound(fd!("[month repr:long locale:ru] [period locale:ja]"))
+            .max_formatted_len(),
+        23
+    );
+    assert_eq!(
+        FormatItem::Compound(fd!("[time_zone] [time_zone repr:name]")).max_formatted_len(),
+        39
+    );
+    assert_eq!(
+        FormatItem::Compound(fd!("[whitespace][ignore count:3][end]")).max_formatted_len(),
+        1
+    );
+    assert_eq!(
+        FormatItem::Compound(fd!(
+            version = 2,
+            "[optional [[hour]:]][first [[period]] [x]]"
+        ))
+        .max_formatted_len(),
+        5
+    );
+    assert_eq!(FormatItem::First(&[]).max_formatted_len(), 0);
+    assert_eq!(
+        FormatItem::Compound(fd!(
+            "[year width:9][year repr:last_two width:3][offset_hour width:4]"
+        ))
+        .max_formatted_len(),
+        18
+    );
+    assert_eq!(Rfc3339.max_formatted_len(), 35);
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.123_456_789 -01:02)
+            .format(&Rfc3339)?
+            .len(),
+        35
+    );
+
+    let descriptions = [
+        fd!("[month repr:long] [month repr:short] [weekday] [weekday repr:short]"),
+        fd!("[weekday repr:sunday] [weekday repr:monday] [ordinal] [week_number]"),
+        fd!("[year repr:last_two] [year sign:mandatory] [year base:iso_week]"),
+        fd!("[subsecond] [subsecond digits:1] [subsecond digits:6]"),
+        fd!("[offset_hour sign:mandatory]:[offset_minute]:[offset_second]"),
+        fd!("[hour padding:space] [hour repr:12] [period case:lower]"),
+    ];
+    let datetimes = [
+        datetime!(2021-09-29 23:59:59.999_999_999 -23:59:59),
+        datetime!(-9999-01-01 00:00 +23:59:59),
+        datetime!(9999-12-31 12:00 UTC),
+    ];
+    for description in &descriptions {
+        for datetime in &datetimes {
+            assert!(datetime.format(description)?.len() <= max_formatted_len(description));
+        }
+    }
+
+    Ok(())
+}
+
 #[test]
 fn insufficient_type_information() {
     assert!(matches!(
         Time::MIDNIGHT.format(&fd!("[year]")),
         Err(time::error::Format::InsufficientTypeInformation { .. })
     ));
+    assert!(matches!(
+        datetime!(2021-01-01 0:00 UTC).format(&fd!("[time_zone]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
 }
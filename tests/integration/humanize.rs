@@ -0,0 +1,282 @@
+use core::fmt;
+
+use time::ext::NumericalDuration;
+use time::humanize::{
+    DurationFormat, English, Language, RelativeFormat, RelativeUnit, Rounding, Tense, Unit,
+};
+use time::macros::datetime;
+use time::Duration;
+
+#[test]
+fn unit() {
+    assert_eq!(Unit::Nanosecond.symbol(), "ns");
+    assert_eq!(Unit::Microsecond.symbol(), "µs");
+    assert_eq!(Unit::Millisecond.symbol(), "ms");
+    assert_eq!(Unit::Second.symbol(), "s");
+    assert_eq!(Unit::Minute.symbol(), "m");
+    assert_eq!(Unit::Hour.symbol(), "h");
+    assert_eq!(Unit::Day.symbol(), "d");
+    assert_eq!(Unit::Week.symbol(), "w");
+    assert_eq!(Unit::Week.to_string(), "w");
+
+    assert_eq!(Unit::Nanosecond.duration(), 1.nanoseconds());
+    assert_eq!(Unit::Minute.duration(), 1.minutes());
+    assert_eq!(Unit::Week.duration(), 1.weeks());
+    assert!(Unit::Nanosecond < Unit::Week);
+}
+
+#[test]
+fn duration_format_accessors() {
+    let format = DurationFormat::new();
+    assert_eq!(format, DurationFormat::default());
+    assert_eq!(format.largest_unit(), Unit::Day);
+    assert_eq!(format.smallest_unit(), Unit::Nanosecond);
+    assert_eq!(format.max_units(), None);
+    assert_eq!(format.separator(), "");
+
+    let format = format.with_smallest_unit(Unit::Week);
+    assert_eq!(format.largest_unit(), Unit::Week);
+    assert_eq!(format.smallest_unit(), Unit::Week);
+    let format = format.with_largest_unit(Unit::Second);
+    assert_eq!(format.largest_unit(), Unit::Second);
+    assert_eq!(format.smallest_unit(), Unit::Second);
+    assert_eq!(format.with_max_units(0).max_units(), Some(1));
+    assert_eq!(format.with_separator(", ").separator(), ", ");
+}
+
+#[test]
+fn duration_format_display() {
+    let duration = 1.weeks() + 1.hours() + 5.seconds() + 1.milliseconds();
+    let display = |format: DurationFormat| format.display(duration).to_string();
+
+    assert_eq!(display(DurationFormat::new()), "7d1h5s1ms");
+    assert_eq!(
+        display(DurationFormat::new().with_largest_unit(Unit::Week)),
+        "1w1h5s1ms"
+    );
+    assert_eq!(
+        display(DurationFormat::new().with_largest_unit(Unit::Hour)),
+        "169h5s1ms"
+    );
+    assert_eq!(
+        display(DurationFormat::new().with_smallest_unit(Unit::Second)),
+        "7d1h5s"
+    );
+    assert_eq!(
+        display(DurationFormat::new().with_separator(" ")),
+        "7d 1h 5s 1ms"
+    );
+    assert_eq!(display(DurationFormat::new().with_max_units(1)), "7d");
+    assert_eq!(display(DurationFormat::new().with_max_units(2)), "7d1h");
+    assert_eq!(display(DurationFormat::new().with_max_units(3)), "7d1h");
+    assert_eq!(display(DurationFormat::new().with_max_units(4)), "7d1h5s");
+
+    let format = DurationFormat::new().with_smallest_unit(Unit::Minute);
+    assert_eq!(format.display(59.seconds()).to_string(), "0m");
+    assert_eq!(format.display((-61).seconds()).to_string(), "-1m");
+    let format = DurationFormat::new().with_largest_unit(Unit::Millisecond);
+    assert_eq!(format.display(0.seconds()).to_string(), "0ms");
+    assert_eq!(format.display(1.seconds()).to_string(), "1000ms");
+
+    let duration = 90.minutes();
+    assert_eq!(format!("{:8}|", duration), "1h30m   |");
+    assert_eq!(format!("{:>8}", duration), "   1h30m");
+    assert_eq!(format!("{:*^9}", duration), "**1h30m**");
+    assert_eq!(format!("{:.3}", duration), "1h3");
+    assert_eq!(format!("{:>4.2}", duration), "  1h");
+    assert_eq!(format!("{:3}", duration), "1h30m");
+    assert_eq!(
+        format!("{:>6}", DurationFormat::new().display(1.microseconds())),
+        "   1µs"
+    );
+}
+
+#[cfg(feature = "parsing")]
+#[test]
+fn duration_format_parse() -> Result<(), time::error::Parse> {
+    let format = DurationFormat::new()
+        .with_largest_unit(Unit::Week)
+        .with_smallest_unit(Unit::Second);
+    assert_eq!(format.parse("2w")?, 2.weeks());
+    assert_eq!(format.parse("1 week 2 days")?, 9.days());
+    assert_eq!(format.parse("1.5 min")?, 90.seconds());
+    assert!(format.parse("1s500ms").is_err());
+
+    let format = DurationFormat::new();
+    assert_eq!(format.parse("1h 30m 15s")?, 5_415.seconds());
+    assert_eq!(format.parse(".5h")?, 30.minutes());
+    assert!(matches!(
+        format.parse("1h1h"),
+        Err(time::error::Parse::Located {
+            error: time::error::ParseFromDescription::InvalidComponent("unit"),
+            position: 3,
+            ..
+        })
+    ));
+    assert!(format.parse("1h 2 hours").is_err());
+    assert!(format.parse("30m1h").is_err());
+    assert!(format.parse("1s1d").is_err());
+    assert!(matches!(
+        format.parse("1.h"),
+        Err(time::error::Parse::Located {
+            error: time::error::ParseFromDescription::InvalidComponent("duration"),
+            position: 0,
+            ..
+        })
+    ));
+    assert!(format.parse("1h 2.m").is_err());
+    assert!(format.parse(".h").is_err());
+
+    let format = DurationFormat::new().with_largest_unit(Unit::Millisecond);
+    assert_eq!(format.parse("1500ms")?, 1_500.milliseconds());
+    assert!(format.parse("1s").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn relative_format_accessors() {
+    let format = RelativeFormat::new();
+    assert_eq!(format, RelativeFormat::default());
+    assert_eq!(format.threshold(RelativeUnit::Second), Some(45));
+    assert_eq!(format.threshold(RelativeUnit::Minute), Some(45));
+    assert_eq!(format.threshold(RelativeUnit::Hour), Some(22));
+    assert_eq!(format.threshold(RelativeUnit::Day), Some(7));
+    assert_eq!(format.threshold(RelativeUnit::Week), Some(4));
+    assert_eq!(format.threshold(RelativeUnit::Month), Some(11));
+    assert_eq!(format.threshold(RelativeUnit::Year), None);
+    assert_eq!(format.rounding(), Rounding::Nearest);
+    assert_eq!(format.now_threshold(), 1.seconds());
+    assert_eq!(format.language(), &English);
+
+    let format = format
+        .with_threshold(RelativeUnit::Week, 0)
+        .with_threshold(RelativeUnit::Year, 5)
+        .with_rounding(Rounding::Up)
+        .with_now_threshold(Duration::ZERO);
+    assert_eq!(format.threshold(RelativeUnit::Week), Some(0));
+    assert_eq!(format.threshold(RelativeUnit::Year), None);
+    assert_eq!(format.rounding(), Rounding::Up);
+    assert_eq!(format.now_threshold(), Duration::ZERO);
+}
+
+#[test]
+fn relative_format_display() {
+    let format = RelativeFormat::new();
+    let display = |duration: Duration| format.display(duration).to_string();
+
+    assert_eq!(display(Duration::ZERO), "now");
+    assert_eq!(display(999.milliseconds()), "now");
+    assert_eq!(display((-999).milliseconds()), "now");
+    assert_eq!(display(1.seconds()), "in 1 second");
+    assert_eq!(display((-44).seconds()), "44 seconds ago");
+    assert_eq!(display(45.seconds()), "in 1 minute");
+    assert_eq!(display((-3).minutes()), "3 minutes ago");
+    assert_eq!(display(44.minutes()), "in 44 minutes");
+    assert_eq!(display(45.minutes()), "in 1 hour");
+    assert_eq!(display((-21).hours()), "21 hours ago");
+    assert_eq!(display(22.hours()), "in 1 day");
+    assert_eq!(display(6.days()), "in 6 days");
+    assert_eq!(display((-7).days()), "1 week ago");
+    assert_eq!(display(3.weeks()), "in 3 weeks");
+    assert_eq!(display(30.days()), "in 1 month");
+    assert_eq!(display((-300).days()), "10 months ago");
+    assert_eq!(display(335.days()), "in 1 year");
+    assert_eq!(display((-1000).days()), "3 years ago");
+    assert_eq!(display(Duration::MIN), "292277024627 years ago");
+
+    let format = RelativeFormat::new()
+        .with_threshold(RelativeUnit::Week, 0)
+        .with_threshold(RelativeUnit::Day, 30);
+    assert_eq!(format.display(20.days()).to_string(), "in 20 days");
+    let format = RelativeFormat::new().with_threshold(RelativeUnit::Week, 0);
+    assert_eq!(format.display(20.days()).to_string(), "in 1 month");
+
+    let duration = 1.hours() + 31.minutes();
+    let format = RelativeFormat::new().with_threshold(RelativeUnit::Hour, 24);
+    assert_eq!(format.display(duration).to_string(), "in 2 hours");
+    let format = format.with_rounding(Rounding::Truncate);
+    assert_eq!(format.display(duration).to_string(), "in 1 hour");
+    assert_eq!(format.display(44.seconds()).to_string(), "in 44 seconds");
+    let format = format.with_rounding(Rounding::Up);
+    assert_eq!(format.display(61.minutes()).to_string(), "in 2 hours");
+
+    let format = RelativeFormat::new()
+        .with_now_threshold(Duration::ZERO)
+        .with_rounding(Rounding::Truncate);
+    assert_eq!(format.display(500.milliseconds()).to_string(), "now");
+    let format = RelativeFormat::new().with_now_threshold(10.seconds());
+    assert_eq!(format.display((-9).seconds()).to_string(), "now");
+    assert_eq!(
+        format.display((-10).seconds()).to_string(),
+        "10 seconds ago"
+    );
+}
+
+#[test]
+fn relative_format_display_relative() {
+    let format = RelativeFormat::new();
+    let anchor = datetime!(2021-05-12 12:00 UTC);
+    assert_eq!(
+        format
+            .display_relative(datetime!(2021-05-12 9:10 -3), anchor)
+            .to_string(),
+        "in 10 minutes"
+    );
+    assert_eq!(
+        format
+            .display_relative(datetime!(2021-05-10 12:00 +0), anchor)
+            .to_string(),
+        "2 days ago"
+    );
+    assert_eq!(
+        format
+            .display_from_now(time::OffsetDateTime::now_utc() - 5.minutes())
+            .to_string(),
+        "5 minutes ago"
+    );
+}
+
+#[test]
+fn relative_format_language() {
+    #[derive(Debug)]
+    struct Test;
+
+    impl Language for Test {
+        fn write_relative(
+            &self,
+            f: &mut fmt::Formatter<'_>,
+            amount: u64,
+            unit: RelativeUnit,
+            tense: Tense,
+        ) -> fmt::Result {
+            let tense = match tense {
+                Tense::Past => "-",
+                _ => "+",
+            };
+            let unit = match unit {
+                RelativeUnit::Day => "d",
+                RelativeUnit::Week => "w",
+                _ => "?",
+            };
+            write!(f, "{}{}{}", tense, amount, unit)
+        }
+
+        fn write_now(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("0")
+        }
+    }
+
+    let format = RelativeFormat::new().with_language(Test);
+    assert_eq!(format.display(Duration::ZERO).to_string(), "0");
+    assert_eq!(format.display((-2).days()).to_string(), "-2d");
+    assert_eq!(format.display(3.weeks()).to_string(), "+3w");
+    assert_eq!(
+        format
+            .with_rounding(Rounding::Truncate)
+            .with_language(English)
+            .display(90.minutes())
+            .to_string(),
+        "in 1 hour"
+    );
+}
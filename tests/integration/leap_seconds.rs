@@ -0,0 +1,317 @@
+use time::error::InvalidLeapSecondTable;
+use time::ext::NumericalDuration;
+use time::leap_seconds::{Entry, LeapSecondTable};
+use time::macros::{date, datetime};
+use time::{Date, PrimitiveDateTime, Time};
+
+/// The leap second inserted at the end of 2016.
+fn leap_second() -> PrimitiveDateTime {
+    date!(2016 - 12 - 31).with_time(Time::leap_second(23, 59, 500_000_000).unwrap())
+}
+
+#[test]
+fn builtin() {
+    let table = LeapSecondTable::BUILTIN;
+    assert_eq!(table.entries().len(), 28);
+    assert_eq!(table.entries()[0].date(), date!(1972 - 01 - 01));
+    assert_eq!(table.entries()[1].date(), date!(1972 - 07 - 01));
+    assert_eq!(table.entries()[27].tai_minus_utc(), 37.seconds());
+    assert_eq!(table.expires(), Some(date!(2026 - 12 - 28)));
+    assert!(!table.is_expired(date!(2026 - 12 - 27)));
+    assert!(table.is_expired(date!(2026 - 12 - 28)));
+    assert!(!LeapSecondTable::new(Vec::new())
+        .unwrap()
+        .is_expired(date!(9999 - 12 - 31)));
+}
+
+#[test]
+fn new() {
+    let entries = [
+        Entry::new(date!(2015 - 07 - 01), 36),
+        Entry::new(date!(2017 - 01 - 01), 37),
+    ];
+    assert_eq!(
+        LeapSecondTable::new(&entries[..]).map(|table| table.entries().len()),
+        Ok(2)
+    );
+    assert_eq!(
+        LeapSecondTable::new(&[entries[0], entries[0]][..]),
+        Err(InvalidLeapSecondTable::Unsorted { index: 1 })
+    );
+    assert_eq!(
+        LeapSecondTable::new(&entries[..])
+            .map(|table| table.with_expiration(date!(2021 - 12 - 28)).expires()),
+        Ok(Some(date!(2021 - 12 - 28)))
+    );
+}
+
+#[test]
+fn tai_minus_utc() {
+    let table = LeapSecondTable::BUILTIN;
+    assert_eq!(
+        table.tai_minus_utc(datetime!(1960-01-01 0:00 UTC)),
+        10.seconds()
+    );
+    assert_eq!(
+        table.tai_minus_utc(datetime!(1972-06-30 23:59:59 UTC)),
+        10.seconds()
+    );
+    assert_eq!(
+        table.tai_minus_utc(datetime!(1972-07-01 0:00 UTC)),
+        11.seconds()
+    );
+    assert_eq!(
+        table.tai_minus_utc(leap_second().assume_utc()),
+        36.seconds()
+    );
+    assert_eq!(
+        table.tai_minus_utc(datetime!(2017-01-01 8:00 +9)),
+        36.seconds()
+    );
+    assert_eq!(
+        table.tai_minus_utc(datetime!(2021-01-01 0:00 UTC)),
+        37.seconds()
+    );
+
+    let empty = LeapSecondTable::new(Vec::new()).unwrap();
+    assert_eq!(
+        empty.tai_minus_utc(datetime!(2021-01-01 0:00 UTC)),
+        0.seconds()
+    );
+}
+
+#[test]
+fn tai() {
+    let table = LeapSecondTable::BUILTIN;
+    let moments = [
+        (
+            datetime!(2016-12-31 23:59:59.5 UTC),
+            datetime!(2017-01-01 0:00:35.5),
+        ),
+        (leap_second().assume_utc(), datetime!(2017-01-01 0:00:36.5)),
+        (
+            datetime!(2017-01-01 0:00:00.5 UTC),
+            datetime!(2017-01-01 0:00:37.5),
+        ),
+        (
+            datetime!(2017-01-01 9:00:00.5 +9),
+            datetime!(2017-01-01 0:00:37.5),
+        ),
+        (
+            datetime!(1970-01-01 0:00 UTC),
+            datetime!(1970-01-01 0:00:10),
+        ),
+    ];
+    for &(utc, tai) in &moments {
+        assert_eq!(table.to_tai(utc), tai);
+        assert_eq!(table.from_tai(tai), utc);
+    }
+    assert_eq!(
+        table.to_tai(
+            leap_second()
+                .assume_utc()
+                .to_offset(time::macros::offset!(+9))
+        ),
+        datetime!(2017-01-01 0:00:36.5)
+    );
+    assert_eq!(
+        table.from_tai(datetime!(2017-01-01 0:00:36)).time(),
+        Time::leap_second(23, 59, 0).unwrap()
+    );
+}
+
+#[test]
+fn tai_irregular_steps() {
+    // Only a single leap second can be represented, so the rest of a larger step is clamped.
+    let entries = [
+        Entry::new(date!(2015 - 07 - 01), 36),
+        Entry::new(date!(2017 - 01 - 01), 38),
+    ];
+    let table = LeapSecondTable::new(&entries[..]).unwrap();
+    assert_eq!(
+        table.from_tai(datetime!(2017-01-01 0:00:36.5)),
+        leap_second().assume_utc()
+    );
+    assert_eq!(
+        table.from_tai(datetime!(2017-01-01 0:00:37.5)),
+        date!(2016 - 12 - 31)
+            .with_time(Time::leap_second(23, 59, 999_999_999).unwrap())
+            .assume_utc()
+    );
+    assert_eq!(
+        table.from_tai(datetime!(2017-01-01 0:00:38)),
+        datetime!(2017-01-01 0:00 UTC)
+    );
+
+    // A negative step skips the final second of the day.
+    let entries = [
+        Entry::new(date!(2015 - 07 - 01), 36),
+        Entry::new(date!(2017 - 01 - 01), 35),
+    ];
+    let table = LeapSecondTable::new(&entries[..]).unwrap();
+    let moments = [
+        (
+            datetime!(2016-12-31 23:59:58.5 UTC),
+            datetime!(2017-01-01 0:00:34.5),
+        ),
+        (
+            datetime!(2017-01-01 0:00 UTC),
+            datetime!(2017-01-01 0:00:35),
+        ),
+    ];
+    for &(utc, tai) in &moments {
+        assert_eq!(table.to_tai(utc), tai);
+        assert_eq!(table.from_tai(tai), utc);
+    }
+}
+
+#[test]
+fn gps() {
+    let table = LeapSecondTable::BUILTIN;
+    assert_eq!(
+        table.to_gps(datetime!(1980-01-06 0:00 UTC)),
+        datetime!(1980-01-06 0:00)
+    );
+    assert_eq!(
+        table.to_gps(datetime!(2021-01-01 0:00 UTC)),
+        datetime!(2021-01-01 0:00:18)
+    );
+    assert_eq!(
+        table.from_gps(datetime!(2021-01-01 0:00:18)),
+        datetime!(2021-01-01 0:00 UTC)
+    );
+    assert_eq!(
+        table.from_gps(datetime!(2017-01-01 0:00:17.5)),
+        leap_second().assume_utc()
+    );
+}
+
+#[test]
+fn tt() {
+    let table = LeapSecondTable::BUILTIN;
+    assert_eq!(
+        table.to_tt(datetime!(2000-01-01 11:58:55.816 UTC)),
+        datetime!(2000-01-01 12:00)
+    );
+    assert_eq!(
+        table.from_tt(datetime!(2000-01-01 12:00)),
+        datetime!(2000-01-01 11:58:55.816 UTC)
+    );
+}
+
+#[test]
+fn elapsed() {
+    let table = LeapSecondTable::BUILTIN;
+    let start = datetime!(2016-12-31 23:59:59 UTC);
+    assert_eq!(
+        table.elapsed(start, leap_second().assume_utc()),
+        1.5.seconds()
+    );
+    assert_eq!(
+        table.elapsed(start, datetime!(2017-01-01 0:00 UTC)),
+        2.seconds()
+    );
+    assert_eq!(
+        table.elapsed(datetime!(2017-01-01 0:00 UTC), start),
+        (-2).seconds()
+    );
+    assert_eq!(
+        table.elapsed(
+            datetime!(1972-01-01 0:00 UTC),
+            datetime!(2017-01-01 0:00 UTC)
+        ),
+        (datetime!(2017-01-01 0:00 UTC) - datetime!(1972-01-01 0:00 UTC)) + 27.seconds()
+    );
+}
+
+#[test]
+fn range_boundaries() {
+    let table = LeapSecondTable::BUILTIN;
+    let max = Date::MAX.with_time(Time::from_hms(23, 58, 0).unwrap());
+    assert_eq!(table.to_tai(max.assume_utc()), max + 37.seconds());
+    assert_eq!(table.to_gps(max.assume_utc()), max + 18.seconds());
+    assert_eq!(table.to_tt(max.assume_utc()), max + 69_184.milliseconds());
+    assert_eq!(table.from_tai(max + 37.seconds()), max.assume_utc());
+    assert_eq!(table.from_gps(max + 18.seconds()), max.assume_utc());
+    assert_eq!(table.from_tt(max + 69_184.milliseconds()), max.assume_utc());
+
+    let min = Date::MIN.with_time(Time::from_hms(0, 1, 0).unwrap());
+    assert_eq!(table.to_tai(min.assume_utc()), min + 10.seconds());
+    assert_eq!(table.from_tai(min + 10.seconds()), min.assume_utc());
+    assert_eq!(table.from_tt(min + 42_184.milliseconds()), min.assume_utc());
+    assert_eq!(
+        table.elapsed(min.assume_utc(), max.assume_utc()),
+        max - min + 27.seconds()
+    );
+}
+
+#[test]
+#[should_panic(expected = "resulting value is out of range")]
+fn to_tai_after_max() {
+    let max = Date::MAX.with_time(Time::from_hms(23, 59, 59).unwrap());
+    let _ = LeapSecondTable::BUILTIN.to_tai(max.assume_utc());
+}
+
+#[test]
+#[should_panic(expected = "resulting value is out of range")]
+fn from_tai_before_min() {
+    let _ = LeapSecondTable::BUILTIN.from_tai(Date::MIN.midnight());
+}
+
+#[test]
+fn parse_leap_seconds_list() {
+    let list = "\
+#	Updated through IERS Bulletin C 71
+#
+#$	 3676924800
+#@	4007404800
+#
+2272060800	10	# 1 Jan 1972
+2287785600	11	# 1 Jul 1972
+
+3692217600	37	# 1 Jan 2017
+#h	16edd0f0 3666784f 37db6bdd e74ced87 59af48f1
+";
+    let table = LeapSecondTable::parse_leap_seconds_list(list).unwrap();
+    assert_eq!(
+        table.entries(),
+        &[
+            Entry::new(date!(1972 - 01 - 01), 10),
+            Entry::new(date!(1972 - 07 - 01), 11),
+            Entry::new(date!(2017 - 01 - 01), 37),
+        ]
+    );
+    assert_eq!(table.expires(), Some(date!(2026 - 12 - 28)));
+    assert_eq!(
+        table.to_tai(datetime!(2017-01-01 0:00 UTC)),
+        datetime!(2017-01-01 0:00:37)
+    );
+
+    let table = LeapSecondTable::parse_leap_seconds_list("2272060800 10").unwrap();
+    assert_eq!(table.expires(), None);
+
+    assert_eq!(
+        LeapSecondTable::parse_leap_seconds_list("# comment\n2272060801\t10\n"),
+        Err(InvalidLeapSecondTable::InvalidLine { line: 2 })
+    );
+    assert_eq!(
+        LeapSecondTable::parse_leap_seconds_list("2272060800\n"),
+        Err(InvalidLeapSecondTable::InvalidLine { line: 1 })
+    );
+    assert_eq!(
+        LeapSecondTable::parse_leap_seconds_list("2272060800 10 11\n"),
+        Err(InvalidLeapSecondTable::InvalidLine { line: 1 })
+    );
+    assert_eq!(
+        LeapSecondTable::parse_leap_seconds_list("2272060800 ten\n"),
+        Err(InvalidLeapSecondTable::InvalidLine { line: 1 })
+    );
+    assert_eq!(
+        LeapSecondTable::parse_leap_seconds_list("#@ never\n"),
+        Err(InvalidLeapSecondTable::InvalidLine { line: 1 })
+    );
+    assert_eq!(
+        LeapSecondTable::parse_leap_seconds_list("2287785600 11\n2272060800 10\n"),
+        Err(InvalidLeapSecondTable::Unsorted { index: 1 })
+    );
+}
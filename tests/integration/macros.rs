@@ -128,6 +128,84 @@ fn format_description_coverage() {
     );
 }
 
+#[test]
+fn format_description_version() {
+    assert_eq!(
+        format_description!(version = 1, "[[[hour]"),
+        format_description!("[[[hour]")
+    );
+    assert_eq!(
+        format_description!(version = 2, r"\\\[[hour]\]"),
+        &[
+            FormatItem::Literal(b"\\"),
+            FormatItem::Literal(b"["),
+            FormatItem::Component(Component::Hour(modifier::Hour::default())),
+            FormatItem::Literal(b"]"),
+        ]
+    );
+    assert_eq!(
+        format_description!(version = 2, "[hour][optional [:[minute]]]"),
+        &[
+            FormatItem::Component(Component::Hour(modifier::Hour::default())),
+            FormatItem::Optional(&FormatItem::Compound(&[
+                FormatItem::Literal(b":"),
+                FormatItem::Component(Component::Minute(modifier::Minute::default())),
+            ])),
+        ]
+    );
+    assert_eq!(
+        format_description!(version = 2, "[first [[hour]] [x]]"),
+        &[FormatItem::First(&[
+            FormatItem::Compound(&[FormatItem::Component(Component::Hour(
+                modifier::Hour::default()
+            ))]),
+            FormatItem::Compound(&[FormatItem::Literal(b"x")]),
+        ])]
+    );
+    assert_eq!(
+        format_description!(version = 2, r"[case_insensitive [T\]z]]"),
+        &[FormatItem::CaseInsensitiveLiteral(b"T]z")]
+    );
+}
+
+#[test]
+fn format_description_target() {
+    assert_eq!(
+        format_description!(target = Date, "[year]-[month]-[day]"),
+        format_description!("[year]-[month]-[day]")
+    );
+    assert_eq!(
+        format_description!(
+            version = 2,
+            target = Time,
+            "[hour]:[minute][optional [:[second]]]"
+        ),
+        format_description!(version = 2, "[hour]:[minute][optional [:[second]]]")
+    );
+    assert_eq!(
+        format_description!(
+            version = 2,
+            target = OffsetDateTime,
+            "[year]-[ordinal]T[hour repr:12]:[minute] [period] [offset]"
+        )
+        .len(),
+        11
+    );
+    assert_eq!(
+        format_description!(
+            version = 2,
+            target = Date,
+            "[year]-[first [[ordinal]] [[month]-[day]]]"
+        )
+        .len(),
+        3
+    );
+    assert_eq!(
+        format_description!(target = ZonedDateTime, "[year] [time_zone]").len(),
+        3
+    );
+}
+
 #[test]
 fn date_coverage() {
     assert_eq!(Ok(date!(2000 - 001)), Date::from_ordinal_date(2000, 1));
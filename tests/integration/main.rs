@@ -67,7 +67,9 @@ mod duration;
 mod error;
 mod ext;
 mod formatting;
+mod humanize;
 mod instant;
+mod leap_seconds;
 mod macros;
 mod month;
 mod offset_date_time;
@@ -3,7 +3,7 @@ use std::time::{Duration as StdDuration, SystemTime};
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time};
-use time::{Date, Duration, Month, OffsetDateTime, Weekday};
+use time::{Date, Duration, Month, OffsetDateTime, Time, Weekday};
 
 #[test]
 fn now_utc() {
@@ -39,6 +39,25 @@ fn to_offset() {
     assert_eq!(los_angeles.day(), 31);
 }
 
+#[test]
+fn to_offset_leap_second() -> time::Result<()> {
+    let leap_second = date!(2016 - 12 - 31).with_time(Time::leap_second(23, 59, 0)?);
+    let tokyo = leap_second.assume_utc().to_offset(offset!(+9));
+    assert_eq!(tokyo.date(), date!(2017 - 01 - 01));
+    assert_eq!(tokyo.time(), Time::leap_second(8, 59, 0)?);
+    assert_eq!(
+        tokyo.to_offset(offset!(UTC)).time(),
+        Time::leap_second(23, 59, 0)?
+    );
+
+    // A leap second is folded when it would not be at the end of a minute.
+    assert_eq!(
+        leap_second.assume_utc().to_offset(offset!(+0:00:30)).time(),
+        time!(0:00:30)
+    );
+    Ok(())
+}
+
 #[test]
 fn from_unix_timestamp() {
     assert_eq!(
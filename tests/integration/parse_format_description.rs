@@ -86,13 +86,27 @@ mod iterator {
             (false, "case_sensitive:false"),
         ])
     }
+
+    pub(super) fn trim_trailing_zeros() -> impl Iterator<Item = (bool, &'static str)> {
+        IntoIter::new([
+            (true, "trim_trailing_zeros:true"),
+            (false, "trim_trailing_zeros:false"),
+        ])
+    }
+
+    pub(super) fn z_for_utc() -> impl Iterator<Item = (bool, &'static str)> {
+        IntoIter::new([(true, "z_for_utc:true"), (false, "z_for_utc:false")])
+    }
 }
 
 use time::error::InvalidFormatDescription;
 use time::format_description::modifier::{
-    self, MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
+    self, EraRepr, Locale, MonthRepr, Padding, Presence, QuarterRepr, SubsecondDigits,
+    SubsecondRounding, TimeZoneRepr, WeekNumberRepr, WeekdayRepr, YearRepr,
+};
+use time::format_description::{
+    self, Component, FixedFormatDescription, FormatItem, OwnedFormatItem, TargetType, Version,
 };
-use time::format_description::{self, Component, FormatItem};
 
 #[test]
 fn empty() {
@@ -141,6 +155,32 @@ fn simple_component() {
             }
         )))])
     );
+    assert_eq!(
+        format_description::parse("[quarter]"),
+        Ok(vec![FormatItem::Component(Component::Quarter(modifier!(
+            Quarter {
+                repr: QuarterRepr::Numerical
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[century]"),
+        Ok(vec![FormatItem::Component(Component::Century(modifier!(
+            Century {
+                padding: Padding::Zero,
+                sign_is_mandatory: false
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[era]"),
+        Ok(vec![FormatItem::Component(Component::Era(modifier!(
+            Era {
+                repr: EraRepr::Ad,
+                case_sensitive: true
+            }
+        )))])
+    );
     assert_eq!(
         format_description::parse("[hour]"),
         Ok(vec![FormatItem::Component(Component::Hour(modifier!(
@@ -222,6 +262,14 @@ fn simple_component() {
             })
         ))])
     );
+    assert_eq!(
+        format_description::parse("[time_zone]"),
+        Ok(vec![FormatItem::Component(Component::TimeZone(modifier!(
+            TimeZone {
+                repr: TimeZoneRepr::Abbreviation
+            }
+        )))])
+    );
     assert_eq!(
         format_description::parse("[weekday]"),
         Ok(vec![FormatItem::Component(Component::Weekday(modifier!(
@@ -277,6 +325,20 @@ fn errors() {
             index: 5
         })
     );
+    assert_eq!(
+        format_description::parse("[month locale:xx]"),
+        Err(InvalidFormatDescription::InvalidModifier {
+            value: "locale:xx".to_owned(),
+            index: 7
+        })
+    );
+    assert_eq!(
+        format_description::parse("[ignore count:0]"),
+        Err(InvalidFormatDescription::InvalidModifier {
+            value: "count:0".to_owned(),
+            index: 8
+        })
+    );
 }
 
 #[test]
@@ -431,6 +493,261 @@ fn component_with_modifiers() {
                 modifier!(Subsecond { digits })
             ))])
         );
+        for (trim_trailing_zeros, trim_trailing_zeros_str) in iterator::trim_trailing_zeros() {
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[subsecond {} {}]",
+                    digits_str, trim_trailing_zeros_str
+                )),
+                Ok(vec![FormatItem::Component(Component::Subsecond(
+                    modifier!(Subsecond {
+                        digits,
+                        trim_trailing_zeros
+                    })
+                ))])
+            );
+        }
+    }
+
+    for (z_for_utc, z_for_utc_str) in iterator::z_for_utc() {
+        assert_eq!(
+            format_description::parse(&format!("[offset_hour {}]", z_for_utc_str)),
+            Ok(vec![FormatItem::Component(Component::OffsetHour(
+                modifier!(OffsetHour {
+                    sign_is_mandatory: false,
+                    z_for_utc
+                })
+            ))])
+        );
+    }
+}
+
+#[test]
+fn date_modifiers() {
+    assert_eq!(
+        format_description::parse("[day ordinal_suffix:true]"),
+        Ok(vec![FormatItem::Component(Component::Day(modifier!(
+            Day {
+                ordinal_suffix: true
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[quarter repr:short]"),
+        Ok(vec![FormatItem::Component(Component::Quarter(modifier!(
+            Quarter {
+                repr: QuarterRepr::Short
+            }
+        )))])
+    );
+    for (padding, padding_str) in iterator::padding() {
+        for (sign_is_mandatory, sign_is_mandatory_str) in iterator::sign_is_mandatory() {
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[century {} {}]",
+                    padding_str, sign_is_mandatory_str
+                )),
+                Ok(vec![FormatItem::Component(Component::Century(modifier!(
+                    Century {
+                        padding,
+                        sign_is_mandatory
+                    }
+                )))])
+            );
+        }
+    }
+    for (case_sensitive, case_sensitive_str) in iterator::case_sensitive() {
+        for &(repr, repr_str) in &[(EraRepr::Ad, "repr:ad"), (EraRepr::Ce, "repr:ce")] {
+            assert_eq!(
+                format_description::parse(&format!("[era {} {}]", repr_str, case_sensitive_str)),
+                Ok(vec![FormatItem::Component(Component::Era(modifier!(
+                    Era {
+                        repr,
+                        case_sensitive
+                    }
+                )))])
+            );
+        }
+    }
+    assert_eq!(
+        format_description::parse("[year repr:of_era]"),
+        Ok(vec![FormatItem::Component(Component::Year(modifier!(
+            Year {
+                repr: YearRepr::OfEra
+            }
+        )))])
+    );
+}
+
+#[test]
+fn time_zone_modifiers() {
+    for &(repr, repr_str) in &[
+        (TimeZoneRepr::Abbreviation, "repr:abbreviation"),
+        (TimeZoneRepr::Name, "repr:name"),
+    ] {
+        assert_eq!(
+            format_description::parse(&format!("[time_zone {}]", repr_str)),
+            Ok(vec![FormatItem::Component(Component::TimeZone(modifier!(
+                TimeZone { repr }
+            )))])
+        );
+    }
+}
+
+#[test]
+fn width_and_rounding_modifiers() {
+    assert_eq!(
+        format_description::parse("[year width:6] [century width:4] [offset_hour width:1]"),
+        Ok(vec![
+            FormatItem::Component(Component::Year(modifier!(Year { width: Some(6) }))),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::Century(modifier!(Century {
+                width: Some(4)
+            }))),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::OffsetHour(modifier!(OffsetHour {
+                sign_is_mandatory: false,
+                width: Some(1)
+            }))),
+        ])
+    );
+    for &(rounding, rounding_str) in &[
+        (SubsecondRounding::Truncate, "rounding:truncate"),
+        (SubsecondRounding::NearestSaturating, "rounding:nearest_saturating"),
+    ] {
+        assert_eq!(
+            format_description::parse(&format!("[subsecond {}]", rounding_str)),
+            Ok(vec![FormatItem::Component(Component::Subsecond(
+                modifier!(Subsecond { rounding })
+            ))])
+        );
+    }
+    for width in &["width:0", "width:10", "width:x"] {
+        assert_eq!(
+            format_description::parse(&format!("[year {}]", width)),
+            Err(InvalidFormatDescription::InvalidModifier {
+                value: (*width).to_owned(),
+                index: 6
+            })
+        );
+    }
+    assert_eq!(
+        format_description::parse("[month width:2]"),
+        Err(InvalidFormatDescription::InvalidModifier {
+            value: "width:2".to_owned(),
+            index: 7
+        })
+    );
+}
+
+#[test]
+fn offset_modifiers() {
+    assert_eq!(
+        format_description::parse("[offset]"),
+        Ok(vec![FormatItem::Component(Component::Offset(
+            modifier::Offset::default()
+        ))])
+    );
+    assert_eq!(
+        modifier::Offset::default(),
+        modifier!(Offset {
+            z_for_utc: true,
+            colon_separated: true,
+            minute: Presence::Mandatory,
+            second: Presence::Optional,
+        })
+    );
+    for (z_for_utc, z_for_utc_str) in iterator::z_for_utc() {
+        for &(colon_separated, colon_str) in &[(true, "colon:true"), (false, "colon:false")] {
+            assert_eq!(
+                format_description::parse(&format!("[offset {} {}]", z_for_utc_str, colon_str)),
+                Ok(vec![FormatItem::Component(Component::Offset(modifier!(
+                    Offset {
+                        z_for_utc,
+                        colon_separated,
+                    }
+                )))])
+            );
+        }
+    }
+    for &(presence, presence_str) in &[
+        (Presence::Mandatory, "mandatory"),
+        (Presence::Optional, "optional"),
+        (Presence::Omitted, "omitted"),
+    ] {
+        assert_eq!(
+            format_description::parse(&format!("[offset minute:{0} second:{0}]", presence_str)),
+            Ok(vec![FormatItem::Component(Component::Offset(modifier!(
+                Offset {
+                    minute: presence,
+                    second: presence,
+                }
+            )))])
+        );
+    }
+    assert_eq!(
+        format_description::parse("[offset padding:none]"),
+        Err(InvalidFormatDescription::InvalidModifier {
+            value: "padding:none".to_owned(),
+            index: 8
+        })
+    );
+}
+
+#[test]
+fn input_components() {
+    assert_eq!(
+        format_description::parse("[whitespace][ignore][ignore count:3][end]"),
+        Ok(vec![
+            FormatItem::Component(Component::Whitespace(modifier::Whitespace::default())),
+            FormatItem::Component(Component::Ignore(modifier!(Ignore { count: None }))),
+            FormatItem::Component(Component::Ignore(modifier!(Ignore { count: Some(3) }))),
+            FormatItem::Component(Component::End(modifier::End::default())),
+        ])
+    );
+}
+
+#[test]
+fn locale_modifiers() {
+    for &(locale, locale_str) in &[
+        (Locale::En, "locale:en"),
+        (Locale::De, "locale:de"),
+        (Locale::Es, "locale:es"),
+        (Locale::Fr, "locale:fr"),
+        (Locale::It, "locale:it"),
+        (Locale::Ja, "locale:ja"),
+        (Locale::Ru, "locale:ru"),
+    ] {
+        assert_eq!(&locale_str[7..], locale.code());
+        for &(standalone, standalone_str) in
+            &[(true, "standalone:true"), (false, "standalone:false")]
+        {
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[month repr:long {} {}]",
+                    locale_str, standalone_str
+                )),
+                Ok(vec![FormatItem::Component(Component::Month(modifier!(
+                    Month {
+                        repr: MonthRepr::Long,
+                        locale,
+                        standalone
+                    }
+                )))])
+            );
+        }
+        assert_eq!(
+            format_description::parse(&format!("[weekday {}]", locale_str)),
+            Ok(vec![FormatItem::Component(Component::Weekday(modifier!(
+                Weekday { locale }
+            )))])
+        );
+        assert_eq!(
+            format_description::parse(&format!("[period {}]", locale_str)),
+            Ok(vec![FormatItem::Component(Component::Period(modifier!(
+                Period { locale }
+            )))])
+        );
     }
 }
 
@@ -460,6 +777,808 @@ fn error_display() {
         InvalidFormatDescription::MissingComponentName { index: 4 }.to_string(),
         "missing component name at byte index 4"
     );
+    assert_eq!(
+        InvalidFormatDescription::NotSupported {
+            what: "foo",
+            context: "bar",
+            index: 5
+        }
+        .to_string(),
+        "foo is not supported in bar at byte index 5"
+    );
+    assert_eq!(
+        InvalidFormatDescription::UnexpectedClosingBracket { index: 6 }.to_string(),
+        "unexpected closing bracket at byte index 6"
+    );
+    assert_eq!(
+        InvalidFormatDescription::Expected {
+            what: "foo",
+            index: 7
+        }
+        .to_string(),
+        "expected foo at byte index 7"
+    );
+}
+
+#[test]
+fn version_2() {
+    assert_eq!(
+        format_description::parse_borrowed(Version::V1, "[[[year]"),
+        format_description::parse("[[[year]")
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, ""),
+        Ok(vec![])
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, r"a\\b\[[year]\]c"),
+        Ok(vec![
+            FormatItem::Literal(b"a"),
+            FormatItem::Literal(b"\\"),
+            FormatItem::Literal(b"b"),
+            FormatItem::Literal(b"["),
+            FormatItem::Component(Component::Year(modifier::Year::default())),
+            FormatItem::Literal(b"]"),
+            FormatItem::Literal(b"c"),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, "[ hour padding:none ]:[minute]"),
+        Ok(vec![
+            FormatItem::Component(Component::Hour(modifier!(Hour {
+                padding: Padding::None
+            }))),
+            FormatItem::Literal(b":"),
+            FormatItem::Component(Component::Minute(modifier::Minute::default())),
+        ])
+    );
+
+    assert_eq!(
+        format_description::parse_owned(Version::V1, "[year]-"),
+        Ok(OwnedFormatItem::Compound(
+            vec![
+                OwnedFormatItem::Component(Component::Year(modifier::Year::default())),
+                OwnedFormatItem::Literal(b"-".to_vec().into_boxed_slice()),
+            ]
+            .into_boxed_slice()
+        ))
+    );
+    assert_eq!(
+        format_description::parse_owned(Version::V2, "[optional [.[subsecond]]]"),
+        Ok(OwnedFormatItem::Compound(
+            vec![OwnedFormatItem::Optional(Box::new(
+                OwnedFormatItem::Compound(
+                    vec![
+                        OwnedFormatItem::Literal(b".".to_vec().into_boxed_slice()),
+                        OwnedFormatItem::Component(Component::Subsecond(
+                            modifier::Subsecond::default()
+                        )),
+                    ]
+                    .into_boxed_slice()
+                )
+            ))]
+            .into_boxed_slice()
+        ))
+    );
+    assert_eq!(
+        format_description::parse_owned(Version::V2, "[first [[hour]] [x] []]"),
+        Ok(OwnedFormatItem::Compound(
+            vec![OwnedFormatItem::First(
+                vec![
+                    OwnedFormatItem::Compound(
+                        vec![OwnedFormatItem::Component(Component::Hour(
+                            modifier::Hour::default()
+                        ))]
+                        .into_boxed_slice()
+                    ),
+                    OwnedFormatItem::Compound(
+                        vec![OwnedFormatItem::Literal(b"x".to_vec().into_boxed_slice())]
+                            .into_boxed_slice()
+                    ),
+                    OwnedFormatItem::Compound(vec![].into_boxed_slice()),
+                ]
+                .into_boxed_slice()
+            )]
+            .into_boxed_slice()
+        ))
+    );
+    assert_eq!(
+        OwnedFormatItem::from(format_description::parse("[hour]:[minute]").unwrap()),
+        format_description::parse_owned(Version::V2, "[hour]:[minute]").unwrap()
+    );
+}
+
+#[test]
+fn version_2_errors() {
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, r"\a"),
+        Err(InvalidFormatDescription::Expected {
+            what: "valid escape sequence",
+            index: 0
+        })
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, "[year]]"),
+        Err(InvalidFormatDescription::UnexpectedClosingBracket { index: 6 })
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, "[[year]"),
+        Err(InvalidFormatDescription::InvalidComponentName {
+            name: "[year".to_owned(),
+            index: 1
+        })
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, "x[year"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 1 })
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, "x[optional [a]]"),
+        Err(InvalidFormatDescription::NotSupported {
+            what: "optional item",
+            context: "borrowed format descriptions",
+            index: 1
+        })
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, "[first [a]]"),
+        Err(InvalidFormatDescription::NotSupported {
+            what: "first item",
+            context: "borrowed format descriptions",
+            index: 0
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned(Version::V2, "[optional]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "opening bracket",
+            index: 9
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned(Version::V2, "[optional [a] [b]]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "closing bracket",
+            index: 14
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned(Version::V2, "[first [a] b]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "opening bracket",
+            index: 11
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned(Version::V2, "[first [a]"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 })
+    );
+    assert_eq!(
+        format_description::parse_owned(Version::V2, "[first [a"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 7 })
+    );
+    assert_eq!(
+        format_description::parse_owned(Version::V2, "[optional [[invalid]]]"),
+        Err(InvalidFormatDescription::InvalidComponentName {
+            name: "invalid".to_owned(),
+            index: 12
+        })
+    );
+}
+
+#[test]
+fn version_2_case_insensitive() {
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, "[year][case_insensitive [T]][hour]"),
+        Ok(vec![
+            FormatItem::Component(Component::Year(modifier::Year::default())),
+            FormatItem::CaseInsensitiveLiteral(b"T"),
+            FormatItem::Component(Component::Hour(modifier::Hour::default())),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, r"[ case_insensitive [a\]b] ]"),
+        Ok(vec![
+            FormatItem::CaseInsensitiveLiteral(b"a"),
+            FormatItem::CaseInsensitiveLiteral(b"]"),
+            FormatItem::CaseInsensitiveLiteral(b"b"),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_owned(Version::V2, r"[case_insensitive [a\]b]]"),
+        Ok(OwnedFormatItem::Compound(
+            vec![OwnedFormatItem::CaseInsensitiveLiteral(
+                b"a]b".to_vec().into_boxed_slice()
+            )]
+            .into_boxed_slice()
+        ))
+    );
+    assert_eq!(
+        OwnedFormatItem::from(FormatItem::CaseInsensitiveLiteral(b"T")),
+        OwnedFormatItem::CaseInsensitiveLiteral(b"T".to_vec().into_boxed_slice())
+    );
+
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, "[case_insensitive [a[year]]]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "literal",
+            index: 20
+        })
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, "[case_insensitive [a] [b]]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "closing bracket",
+            index: 22
+        })
+    );
+    assert_eq!(
+        format_description::parse_borrowed(Version::V2, "[case_insensitive a]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "opening bracket",
+            index: 18
+        })
+    );
+}
+
+#[test]
+fn analysis() {
+    let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]").unwrap();
+    let analysis = format_description::analyze(&format);
+    assert!(analysis.can_parse(TargetType::Date));
+    assert!(analysis.can_parse(TargetType::Time));
+    assert!(analysis.can_parse(TargetType::PrimitiveDateTime));
+    assert!(!analysis.can_parse(TargetType::UtcOffset));
+    assert!(!analysis.can_parse(TargetType::OffsetDateTime));
+    assert!(!analysis.can_parse(TargetType::ZonedDateTime));
+    assert!(analysis.can_format(TargetType::PrimitiveDateTime));
+    assert!(analysis.can_format(TargetType::OffsetDateTime));
+    assert!(!analysis.can_format(TargetType::Date));
+    assert!(analysis.conflicting().is_empty());
+    assert!(analysis.redundant(TargetType::PrimitiveDateTime).is_empty());
+    assert_eq!(
+        analysis.redundant(TargetType::Date),
+        [
+            Component::Hour(modifier::Hour::default()),
+            Component::Minute(modifier::Minute::default()),
+        ]
+    );
+
+    let format = format_description::parse("[hour]:[minute]").unwrap();
+    let analysis = format_description::analyze(&format);
+    assert!(analysis.can_parse(TargetType::Time));
+    assert!(!analysis.can_parse(TargetType::PrimitiveDateTime));
+    assert!(analysis.can_format(TargetType::Time));
+    assert!(analysis.can_format(TargetType::ZonedDateTime));
+
+    let format = format_description::parse(
+        "[weekday], [day] [month repr:short] [year] [hour]:[minute]:[second] [offset_hour \
+         sign:mandatory][offset_minute] [weekday]",
+    )
+    .unwrap();
+    let analysis = format_description::analyze(&format);
+    assert!(analysis.can_parse(TargetType::OffsetDateTime));
+    assert_eq!(
+        analysis.conflicting(),
+        [Component::Weekday(modifier::Weekday::default())]
+    );
+    assert_eq!(
+        analysis.redundant(TargetType::OffsetDateTime),
+        [
+            Component::Weekday(modifier::Weekday::default()),
+            Component::Weekday(modifier::Weekday::default()),
+        ]
+    );
+
+    let format = format_description::parse("[hour repr:12]:[minute] [time_zone]").unwrap();
+    let analysis = format_description::analyze(&format);
+    assert!(!analysis.can_parse(TargetType::Time));
+    assert!(!analysis.can_format(TargetType::OffsetDateTime));
+    assert!(analysis.can_format(TargetType::ZonedDateTime));
+    let mut hour = modifier::Hour::default();
+    hour.is_12_hour_clock = true;
+    assert_eq!(
+        analysis.redundant(TargetType::Time),
+        [Component::TimeZone(modifier::TimeZone::default())]
+    );
+    assert!(
+        OwnedFormatItem::from(format.as_slice())
+            .analyze()
+            .can_format(TargetType::ZonedDateTime)
+    );
+    assert_eq!(
+        FormatItem::Compound(&format)
+            .analyze()
+            .redundant(TargetType::Date),
+        [
+            Component::Hour(hour),
+            Component::Minute(modifier::Minute::default()),
+            Component::TimeZone(modifier::TimeZone::default()),
+        ]
+    );
+
+    let analysis = format_description::parse_owned(
+        Version::V2,
+        "[year]-[first [[ordinal]] [[month]-[day]]][optional [ [hour]:[minute]]]",
+    )
+    .unwrap()
+    .analyze();
+    assert!(analysis.can_parse(TargetType::Date));
+    assert!(!analysis.can_parse(TargetType::PrimitiveDateTime));
+    assert!(analysis.can_format(TargetType::PrimitiveDateTime));
+    assert!(analysis.conflicting().is_empty());
+
+    let analysis =
+        format_description::parse_owned(Version::V2, "[first [[ordinal]] [[year]]][year]")
+            .unwrap()
+            .analyze();
+    assert_eq!(
+        analysis.conflicting(),
+        [Component::Year(modifier::Year::default())]
+    );
+}
+
+#[test]
+fn display() {
+    for &description in &[
+        "",
+        "[year]-[month]-[day]",
+        "[day padding:space ordinal_suffix:true] [month repr:long case_sensitive:false locale:fr \
+         standalone:true]",
+        "[ordinal padding:none][minute padding:space][second padding:none]",
+        "[weekday repr:short one_indexed:false case_sensitive:false locale:de]",
+        "[weekday repr:sunday][weekday repr:monday]",
+        "[week_number padding:none repr:sunday][week_number repr:monday]",
+        "[year padding:space repr:last_two base:iso_week sign:mandatory width:6]",
+        "[year repr:of_era] [era repr:ce case_sensitive:false]",
+        "[quarter repr:short][century padding:none sign:mandatory width:4]",
+        "[hour repr:12][period case:lower case_sensitive:false locale:ja]",
+        "[subsecond digits:3 trim_trailing_zeros:true rounding:nearest_saturating][subsecond \
+         digits:9]",
+        "[offset_hour sign:mandatory padding:space z_for_utc:true width:3][offset_minute \
+         padding:none][offset_second padding:space]",
+        "[offset z_for_utc:false colon:false minute:optional second:mandatory][offset \
+         minute:omitted second:omitted]",
+        "[time_zone][time_zone repr:name]",
+        "[whitespace][ignore][ignore count:3][end]",
+        "[optional [.[subsecond]]]",
+        "[first [[hour]:[minute]] [[hour]] []]",
+        r"[case_insensitive [T]][case_insensitive [\\\[\]]]",
+        r"\\ \[[hour]\] ü",
+        "[optional [[first [[case_insensitive [Z]]] [[offset_hour]]]]]",
+    ] {
+        assert_eq!(
+            format_description::parse_owned(Version::V2, description)
+                .unwrap()
+                .to_string(),
+            description
+        );
+    }
+
+    assert_eq!(
+        format_description::parse("[hour padding:zero repr:24]:[[[minute] ]")
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<String>(),
+        r"[hour]:\[[minute] \]"
+    );
+    assert_eq!(
+        Component::OffsetHour(modifier::OffsetHour::default()).to_string(),
+        "[offset_hour sign:mandatory]"
+    );
+    assert_eq!(
+        FormatItem::Compound(&[
+            FormatItem::Literal(b"a\xffb"),
+            FormatItem::Optional(&FormatItem::Component(Component::Minute(
+                modifier::Minute::default()
+            ))),
+            FormatItem::First(&[]),
+        ])
+        .to_string(),
+        "a\u{fffd}b[optional [[minute]]][first]"
+    );
+    assert_eq!(
+        OwnedFormatItem::from(FormatItem::CaseInsensitiveLiteral(b"[T]")).to_string(),
+        r"[case_insensitive [\[T\]]]"
+    );
+
+    // Escaped characters are parsed as separate literals, so the bytes are compared.
+    let literal_bytes = |items: &OwnedFormatItem, case_sensitive: bool| match items {
+        OwnedFormatItem::Compound(items) => items
+            .iter()
+            .flat_map(|item| match (item, case_sensitive) {
+                (OwnedFormatItem::Literal(bytes), true)
+                | (OwnedFormatItem::CaseInsensitiveLiteral(bytes), false) => bytes.to_vec(),
+                _ => panic!("unexpected item {:?}", item),
+            })
+            .collect::<Vec<_>>(),
+        _ => panic!("unexpected item {:?}", items),
+    };
+    for &literal in &[&b"["[..], b"]", br"\", br"\[[]]\\", b"a[b]c"] {
+        let item = OwnedFormatItem::Literal(literal.to_vec().into_boxed_slice());
+        let reparsed = format_description::parse_owned(Version::V2, &item.to_string()).unwrap();
+        assert_eq!(literal_bytes(&reparsed, true), literal);
+
+        let item = OwnedFormatItem::CaseInsensitiveLiteral(literal.to_vec().into_boxed_slice());
+        let reparsed = format_description::parse_owned(Version::V2, &item.to_string()).unwrap();
+        assert_eq!(literal_bytes(&reparsed, false), literal);
+    }
+}
+
+#[test]
+fn parse_fixed() {
+    for &description in &[
+        "",
+        "foo bar",
+        "[[",
+        "[year]-[month]-[day]",
+        "  [ day padding:space ordinal_suffix:true  ][month repr:long case_sensitive:false \
+         locale:fr standalone:true]",
+        "[ordinal padding:none][minute padding:space][second padding:none]",
+        "[weekday repr:short one_indexed:false case_sensitive:false locale:de]",
+        "[weekday repr:sunday][weekday repr:monday][weekday repr:long]",
+        "[week_number padding:none repr:sunday][week_number repr:monday][week_number repr:iso]",
+        "[year padding:space repr:last_two base:iso_week sign:mandatory width:6]",
+        "[year repr:of_era base:calendar sign:automatic] [era repr:ce case_sensitive:false]",
+        "[quarter repr:short][quarter repr:numerical][century padding:none sign:mandatory]",
+        "[hour repr:12][hour repr:24][period case:lower case_sensitive:false locale:ja]",
+        "[subsecond digits:3 trim_trailing_zeros:true rounding:nearest_saturating][subsecond \
+         digits:1+ \
+         rounding:truncate]",
+        "[offset_hour sign:mandatory padding:space z_for_utc:true width:3][offset_minute \
+         padding:none][offset_second padding:space]",
+        "[offset z_for_utc:false colon:false minute:optional second:mandatory][offset \
+         minute:omitted second:omitted]",
+        "[time_zone repr:abbreviation][time_zone repr:name]",
+        "[whitespace][ignore][ignore count:3][ignore count:+65535][end]",
+        "[day padding:zero padding:none]",
+    ] {
+        assert_eq!(
+            format_description::parse(description).ok().as_deref(),
+            Some(&*format_description::parse_fixed(description)),
+            "{}",
+            description
+        );
+    }
+
+    const DATE: &str = "[year]-[month]-[day]";
+    const FORMAT: FixedFormatDescription<'_> = format_description::parse_fixed(DATE);
+    assert_eq!(
+        *FORMAT,
+        [
+            FormatItem::Component(Component::Year(modifier::Year::default())),
+            FormatItem::Literal(b"-"),
+            FormatItem::Component(Component::Month(modifier::Month::default())),
+            FormatItem::Literal(b"-"),
+            FormatItem::Component(Component::Day(modifier::Day::default())),
+        ]
+    );
+    assert_eq!(FORMAT.to_string(), DATE);
+    assert_eq!(
+        format!("{:?}", FORMAT),
+        format!("{:?}", format_description::parse(DATE).unwrap())
+    );
+}
+
+#[test]
+fn parse_fixed_matches_parse() {
+    const PADDING: &[&str] = &["", "padding:space", "padding:zero", "padding:none"];
+    const SIGN: &[&str] = &["", "sign:automatic", "sign:mandatory"];
+    const CASE_SENSITIVE: &[&str] = &["", "case_sensitive:true", "case_sensitive:false"];
+    const LOCALE: &[&str] = &[
+        "",
+        "locale:en",
+        "locale:de",
+        "locale:es",
+        "locale:fr",
+        "locale:it",
+        "locale:ja",
+        "locale:ru",
+    ];
+    const Z_FOR_UTC: &[&str] = &["", "z_for_utc:true", "z_for_utc:false"];
+    const WIDTH: &[&str] = &[
+        "", "width:1", "width:2", "width:3", "width:4", "width:5", "width:6", "width:7", "width:8",
+        "width:9",
+    ];
+
+    // Each component is tested with every combination of its modifiers, where an empty string
+    // leaves the modifier at its default.
+    let components: &[(&str, &[&[&str]])] = &[
+        (
+            "day",
+            &[
+                PADDING,
+                &["", "ordinal_suffix:true", "ordinal_suffix:false"],
+            ],
+        ),
+        (
+            "month",
+            &[
+                PADDING,
+                &["", "repr:numerical", "repr:long", "repr:short"],
+                CASE_SENSITIVE,
+                LOCALE,
+                &["", "standalone:true", "standalone:false"],
+            ],
+        ),
+        ("ordinal", &[PADDING]),
+        (
+            "weekday",
+            &[
+                &["", "repr:short", "repr:long", "repr:sunday", "repr:monday"],
+                &["", "one_indexed:true", "one_indexed:false"],
+                CASE_SENSITIVE,
+                LOCALE,
+            ],
+        ),
+        (
+            "week_number",
+            &[PADDING, &["", "repr:iso", "repr:sunday", "repr:monday"]],
+        ),
+        (
+            "year",
+            &[
+                PADDING,
+                &["", "repr:full", "repr:last_two", "repr:of_era"],
+                &["", "base:calendar", "base:iso_week"],
+                SIGN,
+                WIDTH,
+            ],
+        ),
+        ("quarter", &[&["", "repr:numerical", "repr:short"]]),
+        ("century", &[PADDING, SIGN, WIDTH]),
+        ("era", &[&["", "repr:ad", "repr:ce"], CASE_SENSITIVE]),
+        ("hour", &[PADDING, &["", "repr:12", "repr:24"]]),
+        ("minute", &[PADDING]),
+        (
+            "period",
+            &[&["", "case:upper", "case:lower"], CASE_SENSITIVE, LOCALE],
+        ),
+        ("second", &[PADDING]),
+        (
+            "subsecond",
+            &[
+                &[
+                    "",
+                    "digits:1",
+                    "digits:2",
+                    "digits:3",
+                    "digits:4",
+                    "digits:5",
+                    "digits:6",
+                    "digits:7",
+                    "digits:8",
+                    "digits:9",
+                    "digits:1+",
+                ],
+                &["", "trim_trailing_zeros:true", "trim_trailing_zeros:false"],
+                &["", "rounding:truncate", "rounding:nearest_saturating"],
+            ],
+        ),
+        (
+            "offset_hour",
+            &[PADDING, SIGN, Z_FOR_UTC, WIDTH],
+        ),
+        ("offset_minute", &[PADDING]),
+        ("offset_second", &[PADDING]),
+        (
+            "offset",
+            &[
+                Z_FOR_UTC,
+                &["", "colon:true", "colon:false"],
+                &["", "minute:mandatory", "minute:optional", "minute:omitted"],
+                &["", "second:mandatory", "second:optional", "second:omitted"],
+            ],
+        ),
+        ("time_zone", &[&["", "repr:abbreviation", "repr:name"]]),
+        ("whitespace", &[]),
+        ("ignore", &[&["", "count:1", "count:+12", "count:65535"]]),
+        ("end", &[]),
+    ];
+
+    for &(name, modifiers) in components {
+        let mut descriptions = vec![format!("[{}", name)];
+        for &values in modifiers {
+            descriptions = descriptions
+                .iter()
+                .flat_map(|description| {
+                    values.iter().map(move |value| match *value {
+                        "" => description.clone(),
+                        value => format!("{} {}", description, value),
+                    })
+                })
+                .collect();
+        }
+
+        for description in descriptions {
+            let description = description + "]";
+            assert_eq!(
+                format_description::parse(&description).ok().as_deref(),
+                Some(&*format_description::parse_fixed(&description)),
+                "{}",
+                description
+            );
+        }
+    }
+}
+
+#[test]
+fn parse_fixed_rejects_what_parse_rejects() {
+    let descriptions = [
+        "[day ordinal_suffix:yes]",
+        "[century repr:full]",
+        "[century width:0]",
+        "[era repr:bc]",
+        "[year width:0]",
+        "[year width:10]",
+        "[offset_hour width:0]",
+        "[subsecond rounding:up]",
+        "[subsecond rounding:nearest]",
+        "[subsecond rounding:nearest_saturating rounding]",
+        "[offset_hour z_for_utc:yes]",
+        "[offset z_for_utc:1]",
+    ];
+    for &description in &descriptions {
+        assert!(
+            format_description::parse(description).is_err(),
+            "{}",
+            description
+        );
+        assert!(
+            std::panic::catch_unwind(|| format_description::parse_fixed(description)).is_err(),
+            "{}",
+            description
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "unclosed opening bracket in format description")]
+fn parse_fixed_unclosed_bracket() {
+    let _ = format_description::parse_fixed("[year");
+}
+
+#[test]
+#[should_panic(expected = "missing component name in format description")]
+fn parse_fixed_missing_component_name() {
+    let _ = format_description::parse_fixed("[ ]");
+}
+
+#[test]
+#[should_panic(expected = "invalid component name in format description")]
+fn parse_fixed_invalid_component_name() {
+    let _ = format_description::parse_fixed("[foo]");
+}
+
+#[test]
+#[should_panic(expected = "invalid modifier in format description")]
+fn parse_fixed_invalid_modifier() {
+    let _ = format_description::parse_fixed("[ignore count:0]");
+}
+
+#[test]
+#[should_panic(expected = "format description has more items than")]
+fn parse_fixed_too_many_items() {
+    let _ = format_description::parse_fixed(&"[year]".repeat(FixedFormatDescription::CAPACITY + 1));
+}
+
+#[test]
+fn go_layout() {
+    assert_eq!(format_description::parse_go_layout(""), Ok(vec![]));
+    assert_eq!(
+        format_description::parse_go_layout("foo bar"),
+        Ok(vec![FormatItem::Literal(b"foo bar")])
+    );
+    assert_eq!(
+        format_description::parse_go_layout("Jan January Janx Mon Monday Monx"),
+        Ok(vec![
+            FormatItem::Component(Component::Month(modifier!(Month {
+                repr: MonthRepr::Short
+            }))),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::Month(modifier!(Month {
+                repr: MonthRepr::Long
+            }))),
+            FormatItem::Literal(b" Janx "),
+            FormatItem::Component(Component::Weekday(modifier!(Weekday {
+                repr: WeekdayRepr::Short
+            }))),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::Weekday(modifier!(Weekday {
+                repr: WeekdayRepr::Long
+            }))),
+            FormatItem::Literal(b" Monx"),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_go_layout("15:04:05.000"),
+        Ok(vec![
+            FormatItem::Component(Component::Hour(modifier!(Hour {
+                padding: Padding::Zero,
+                is_12_hour_clock: false
+            }))),
+            FormatItem::Literal(b":"),
+            FormatItem::Component(Component::Minute(modifier!(Minute {
+                padding: Padding::Zero
+            }))),
+            FormatItem::Literal(b":"),
+            FormatItem::Component(Component::Second(modifier!(Second {
+                padding: Padding::Zero
+            }))),
+            FormatItem::Literal(b"."),
+            FormatItem::Component(Component::Subsecond(modifier!(Subsecond {
+                digits: SubsecondDigits::Three
+            }))),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_go_layout("5,99 05.9990"),
+        Ok(vec![
+            FormatItem::Component(Component::Second(modifier!(Second {
+                padding: Padding::None
+            }))),
+            FormatItem::Literal(b","),
+            FormatItem::Component(Component::Subsecond(modifier!(Subsecond {
+                digits: SubsecondDigits::Two,
+                trim_trailing_zeros: true
+            }))),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::Second(modifier!(Second {
+                padding: Padding::Zero
+            }))),
+            FormatItem::Literal(b".9990"),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_go_layout("Z07:00:00 -0700"),
+        Ok(vec![
+            FormatItem::Component(Component::OffsetHour(modifier!(OffsetHour {
+                sign_is_mandatory: true,
+                z_for_utc: true
+            }))),
+            FormatItem::Literal(b":"),
+            FormatItem::Component(Component::OffsetMinute(modifier!(OffsetMinute {
+                padding: Padding::Zero
+            }))),
+            FormatItem::Literal(b":"),
+            FormatItem::Component(Component::OffsetSecond(modifier!(OffsetSecond {
+                padding: Padding::Zero
+            }))),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::OffsetHour(modifier!(OffsetHour {
+                sign_is_mandatory: true
+            }))),
+            FormatItem::Component(Component::OffsetMinute(modifier!(OffsetMinute {
+                padding: Padding::Zero
+            }))),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_go_layout("15:04 MST"),
+        Ok(vec![
+            FormatItem::Component(Component::Hour(modifier!(Hour {
+                padding: Padding::Zero,
+                is_12_hour_clock: false
+            }))),
+            FormatItem::Literal(b":"),
+            FormatItem::Component(Component::Minute(modifier!(Minute {
+                padding: Padding::Zero
+            }))),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::TimeZone(modifier!(TimeZone {
+                repr: TimeZoneRepr::Abbreviation
+            }))),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_go_layout("05.0000000000"),
+        Err(InvalidFormatDescription::NotSupported {
+            what: "more than nine fractional digits",
+            context: "Go reference layout",
+            index: 2
+        })
+    );
 }
 
 #[test]
@@ -1,10 +1,14 @@
 use core::convert::{TryFrom, TryInto};
+use core::num::{NonZeroU16, NonZeroU8};
 
 use time::format_description::well_known::Rfc3339;
-use time::format_description::{modifier, Component};
-use time::macros::{date, datetime, time};
-use time::parsing::Parsed;
-use time::{format_description as fd, Date, Month, OffsetDateTime, Time, UtcOffset, Weekday};
+use time::format_description::{modifier, Component, FormatItem};
+use time::macros::{date, datetime, format_description, offset, time};
+use time::parsing::{Interval, Parsed};
+use time::{
+    format_description as fd, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset,
+    Weekday,
+};
 
 #[test]
 fn rfc_3339() -> time::Result<()> {
@@ -71,7 +75,10 @@ fn rfc_3339() -> time::Result<()> {
     );
     assert!(matches!(
         OffsetDateTime::parse("2021-01-02T03:04:05Z ", &Rfc3339),
-        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+        Err(time::error::Parse::Located {
+            error: time::error::ParseFromDescription::UnexpectedTrailingCharacters { .. },
+            ..
+        })
     ));
 
     Ok(())
@@ -115,7 +122,11 @@ fn parse_time() -> time::Result<()> {
     ));
     assert!(matches!(
         Time::parse(" ", &fd::parse("")?),
-        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+        Err(time::error::Parse::Located {
+            error: time::error::ParseFromDescription::UnexpectedTrailingCharacters { .. },
+            position: 0,
+            ..
+        })
     ));
 
     Ok(())
@@ -197,10 +208,726 @@ fn parse_date() -> time::Result<()> {
 fn parse_offset() -> time::Result<()> {
     assert_eq!(
         UtcOffset::parse("01", &fd::parse("[offset_hour sign:mandatory]")?),
-        Err(time::error::Parse::ParseFromDescription(
-            time::error::ParseFromDescription::InvalidComponent("offset hour")
+        Err(time::error::Parse::Located {
+            error: time::error::ParseFromDescription::InvalidComponent("offset hour"),
+            position: 0,
+            item_index: Some(0),
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_prefix() -> time::Result<()> {
+    let format = fd::parse("[year]-[month]-[day]")?;
+    assert_eq!(
+        Date::parse_prefix("2021-01-02 rest of line", &format)?,
+        (date!(2021 - 01 - 02), " rest of line")
+    );
+    assert_eq!(
+        Date::parse_prefix("2021-01-02", &format)?,
+        (date!(2021 - 01 - 02), "")
+    );
+    assert_eq!(
+        Time::parse_prefix("13:02:03.456é", &fd::parse("[hour]:[minute]:[second]")?)?,
+        (time!(13:02:03), ".456é")
+    );
+    assert_eq!(
+        UtcOffset::parse_prefix("+01:02]", &fd::parse("[offset_hour]:[offset_minute]")?)?,
+        (offset!(+1:02), "]")
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_prefix(
+            "2021-01-02 03:04:05 INFO",
+            &fd::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?
+        )?,
+        (datetime!(2021-01-02 03:04:05), " INFO")
+    );
+    assert_eq!(
+        OffsetDateTime::parse_prefix("2021-01-02T03:04:05.6Z INFO", &Rfc3339)?,
+        (datetime!(2021-01-02 03:04:05.6 UTC), " INFO")
+    );
+
+    assert!(matches!(
+        Date::parse_prefix("2021-01 rest", &format),
+        Err(time::error::Parse::Located { position: 7, .. })
+    ));
+    assert!(matches!(
+        Time::parse_prefix("12 rest", &fd::parse("[hour]")?),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InsufficientInformation { .. }
         ))
+    ));
+    assert!(matches!(
+        Time::parse_prefix("é", &fd::FormatItem::Literal(b"\xC3")),
+        Err(time::error::Parse::Located { position: 1, .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_error_location() -> time::Result<()> {
+    use time::error::{Parse, ParseFromDescription};
+
+    let format = fd::parse("[year]-[month]-[day]")?;
+    let err = Date::parse("2021-13-01", &format).unwrap_err();
+    assert_eq!(
+        err,
+        Parse::Located {
+            error: ParseFromDescription::InvalidComponent("month"),
+            position: 5,
+            item_index: Some(2),
+        }
+    );
+    assert_eq!(err.position(), Some(5));
+    assert_eq!(err.item_index(), Some(2));
+    assert_eq!(
+        err.expected(),
+        Some(ParseFromDescription::InvalidComponent("month"))
+    );
+
+    let err = Date::parse("2021-01/01", &format).unwrap_err();
+    assert_eq!(err.position(), Some(7));
+    assert_eq!(err.item_index(), Some(3));
+    assert!(matches!(
+        err.expected(),
+        Some(ParseFromDescription::InvalidLiteral { .. })
+    ));
+
+    // Nested items report the index of the outermost item.
+    let nested: &[fd::FormatItem<'_>] = &[
+        fd::FormatItem::Literal(b"date: "),
+        fd::FormatItem::Compound(&format),
+    ];
+    let err = Date::parse("date: 2021-01-xx", &nested).unwrap_err();
+    assert_eq!(err.position(), Some(14));
+    assert_eq!(err.item_index(), Some(1));
+
+    let err = Date::parse("2021-01-01 ", &format).unwrap_err();
+    assert!(matches!(
+        err,
+        Parse::Located {
+            error: ParseFromDescription::UnexpectedTrailingCharacters { .. },
+            position: 10,
+            item_index: None,
+        }
+    ));
+    assert_eq!(err.position(), Some(10));
+    assert_eq!(err.to_string(), "unexpected trailing characters at byte index 10");
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z ", &Rfc3339)
+            .unwrap_err()
+            .position(),
+        Some(20)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:0405Z", &Rfc3339)
+            .unwrap_err()
+            .position(),
+        Some(16)
+    );
+    let err = PrimitiveDateTime::parse("2021-01-02T03:04:05.Z", &Rfc3339).unwrap_err();
+    assert_eq!(
+        err,
+        Parse::Located {
+            error: ParseFromDescription::InvalidComponent("subsecond"),
+            position: 20,
+            item_index: None,
+        }
+    );
+    assert!(matches!(
+        time::Error::from(err),
+        time::Error::Parse(err) if err.position() == Some(20)
+    ));
+    assert!(matches!(
+        time::Error::from(Date::parse("2021-01-01 ", &format).unwrap_err()),
+        time::Error::Parse(Parse::Located { position: 10, .. })
+    ));
+
+    let err = Date::parse("2021-13-01", &format).unwrap_err();
+    assert_eq!(
+        err.diagnostic("2021-13-01").to_string(),
+        "2021-13-01\n     ^ the 'month' component could not be parsed"
+    );
+    assert_eq!(
+        Date::parse("2021-01-01 ", &format)
+            .unwrap_err()
+            .diagnostic("2021-01-01 ")
+            .to_string(),
+        "2021-01-01 \n          ^ unexpected trailing characters"
+    );
+    assert_eq!(
+        Time::parse("12", &fd::parse("[hour]")?)
+            .unwrap_err()
+            .diagnostic("12")
+            .to_string(),
+        "the `Parsed` struct did not include enough information to construct the type"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_trimmed_subsecond() -> time::Result<()> {
+    let format_description =
+        fd::parse("[hour]:[minute]:[second].[subsecond digits:3 trim_trailing_zeros:true]")?;
+    assert_eq!(
+        Time::parse("00:00:01.12", &format_description)?,
+        time!(0:00:01.12)
+    );
+    assert_eq!(
+        Time::parse("00:00:01", &format_description)?,
+        time!(0:00:01)
+    );
+    assert!(Time::parse("00:00:01.1234", &format_description).is_err());
+
+    let format_description =
+        fd::parse("[hour padding:none]:[minute]:[second].[subsecond trim_trailing_zeros:true]")?;
+    assert_eq!(Time::parse("0:00:01", &format_description)?, time!(0:00:01));
+    assert_eq!(
+        Time::parse("0:00:01.5", &format_description)?,
+        time!(0:00:01.5)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_width() -> time::Result<()> {
+    let format_description = fd::parse("[year width:6]-[month]-[day]")?;
+    assert_eq!(
+        Date::parse("002021-01-02", &format_description)?,
+        date!(2021 - 01 - 02)
+    );
+    assert_eq!(
+        Date::parse("-000021-01-02", &format_description)?,
+        date!(-0021 - 01 - 02)
+    );
+    assert!(Date::parse("2021-01-02", &format_description).is_err());
+
+    assert_eq!(
+        Date::parse("21-01-02", &fd::parse("[year width:2]-[month]-[day]")?)?,
+        date!(0021 - 01 - 02)
+    );
+    assert!(
+        Date::parse(
+            "0121",
+            &fd::parse("[year repr:last_two width:4][month][day]")?
+        )
+        .is_err()
+    );
+
+    let format_description = fd::parse("[offset_hour width:1]")?;
+    assert_eq!(UtcOffset::parse("-1", &format_description)?, offset!(-1));
+    assert_eq!(UtcOffset::parse("+12", &format_description)?, offset!(+12));
+    assert_eq!(
+        UtcOffset::parse("-001", &fd::parse("[offset_hour width:3]")?)?,
+        offset!(-1)
+    );
+    let format_description = fd::parse("[offset_hour width:3]")?;
+    assert!(UtcOffset::parse("-128", &format_description).is_err());
+    assert!(UtcOffset::parse("+128", &format_description).is_err());
+    assert!(UtcOffset::parse("-127", &format_description).is_err());
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"-127", &format_description)?;
+    assert_eq!(parsed.offset_hour(), Some(-127));
+    assert!(parsed.parse_items(b"-128", &format_description).is_err());
+
+    assert_eq!(
+        Time::parse(
+            "00:00:01.123",
+            &fd::parse(
+                "[hour]:[minute]:[second].[subsecond digits:3 rounding:nearest_saturating]"
+            )?
+        )?,
+        time!(0:00:01.123)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_offset_z_for_utc() -> time::Result<()> {
+    let format_description =
+        fd::parse("[offset_hour sign:mandatory z_for_utc:true]:[offset_minute]")?;
+    assert_eq!(UtcOffset::parse("Z", &format_description)?, UtcOffset::UTC);
+    assert_eq!(UtcOffset::parse("z", &format_description)?, UtcOffset::UTC);
+    assert_eq!(
+        UtcOffset::parse("+01:30", &format_description)?,
+        UtcOffset::from_hms(1, 30, 0)?
+    );
+    assert_eq!(
+        UtcOffset::parse("+00:00", &format_description)?,
+        UtcOffset::UTC
+    );
+    assert!(UtcOffset::parse("Z:00", &format_description).is_err());
+    assert!(UtcOffset::parse("z:00", &format_description).is_err());
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2021-01-02T03:04:05z",
+            &fd::parse(
+                "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory \
+                 z_for_utc:true]:[offset_minute]"
+            )?
+        )?,
+        datetime!(2021-01-02 03:04:05 UTC)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_offset_component() -> time::Result<()> {
+    let format_description = fd::parse("[offset]")?;
+    assert_eq!(UtcOffset::parse("Z", &format_description)?, UtcOffset::UTC);
+    assert_eq!(UtcOffset::parse("z", &format_description)?, UtcOffset::UTC);
+    assert_eq!(
+        UtcOffset::parse("-01:30", &format_description)?,
+        offset!(-1:30)
+    );
+    assert_eq!(
+        UtcOffset::parse("+01:30:15", &format_description)?,
+        offset!(+1:30:15)
+    );
+    assert!(UtcOffset::parse("+01", &format_description).is_err());
+    assert!(UtcOffset::parse("01:30", &format_description).is_err());
+
+    let format_description = fd::parse("[offset z_for_utc:false colon:false minute:optional]")?;
+    assert_eq!(UtcOffset::parse("+05", &format_description)?, offset!(+5));
+    assert_eq!(
+        UtcOffset::parse("+0530", &format_description)?,
+        offset!(+5:30)
+    );
+    assert!(UtcOffset::parse("Z", &format_description).is_err());
+    assert!(UtcOffset::parse("+05:30", &format_description).is_err());
+
+    let format_description = fd::parse("[offset minute:omitted][end]")?;
+    assert_eq!(UtcOffset::parse("-05", &format_description)?, offset!(-5));
+    assert!(UtcOffset::parse("-05:30", &format_description).is_err());
+
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2021-01-02T03:04:05-07:00",
+            &fd::parse("[year]-[month]-[day]T[hour]:[minute]:[second][offset]")?
+        )?,
+        datetime!(2021-01-02 03:04:05 -07:00)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_offset_negative_zero_hour() -> time::Result<()> {
+    let format_description = fd::parse("[offset]")?;
+    for (offset, formatted) in &[
+        (offset!(-0:30), "-00:30"),
+        (offset!(-0:00:30), "-00:00:30"),
+        (offset!(+0:30), "+00:30"),
+    ] {
+        assert_eq!(offset.format(&format_description)?, *formatted);
+        assert_eq!(UtcOffset::parse(formatted, &format_description)?, *offset);
+    }
+
+    let format_description =
+        fd::parse("[offset_hour sign:mandatory]:[offset_minute]:[offset_second]")?;
+    for (offset, formatted) in &[
+        (offset!(-0:30), "-00:30:00"),
+        (offset!(-0:00:30), "-00:00:30"),
+        (offset!(+0:30), "+00:30:00"),
+    ] {
+        assert_eq!(offset.format(&format_description)?, *formatted);
+        assert_eq!(UtcOffset::parse(formatted, &format_description)?, *offset);
+    }
+    Ok(())
+}
+
+#[test]
+fn parse_go_layout() -> time::Result<()> {
+    let layout = fd::parse_go_layout("2006-01-02T15:04:05.999999999Z07:00")?;
+    assert_eq!(
+        OffsetDateTime::parse("2021-05-03T14:05:06Z", &layout)?,
+        datetime!(2021-05-03 14:05:06 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-05-03T14:05:06z", &layout)?,
+        datetime!(2021-05-03 14:05:06 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-05-03T14:05:06.12-07:00", &layout)?,
+        datetime!(2021-05-03 14:05:06.12 -07:00)
+    );
+
+    let layout = fd::parse_go_layout("Mon Jan _2 3:04:05.000 PM 2006 -07")?;
+    assert_eq!(
+        OffsetDateTime::parse("Mon May  3 2:05:06.100 PM 2021 +01", &layout)?,
+        datetime!(2021-05-03 14:05:06.1 +01:00)
+    );
+    assert!(OffsetDateTime::parse("Mon May  3 2:05:06 PM 2021 +01", &layout).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn parse_quarter_century_era() -> time::Result<()> {
+    assert_eq!(
+        Date::parse(
+            "44 BC-03-15",
+            &fd::parse("[year repr:of_era padding:none] [era]-[month]-[day]")?
+        )?,
+        date!(-43 - 03 - 15)
+    );
+    assert_eq!(
+        Date::parse(
+            "2021 CE 001",
+            &fd::parse("[year repr:of_era] [era repr:ce] [ordinal]")?
+        )?,
+        date!(2021 - 001)
+    );
+    assert!(Date::parse(
+        "0000 AD 001",
+        &fd::parse("[year repr:of_era] [era] [ordinal]")?
+    )
+    .is_err());
+    assert_eq!(
+        Date::parse(
+            "20 21-03-15",
+            &fd::parse("[century] [year repr:last_two]-[month]-[day]")?
+        )?,
+        date!(2021 - 03 - 15)
+    );
+    assert_eq!(
+        Date::parse(
+            "-00 50-03-15",
+            &fd::parse("[century] [year repr:last_two]-[month]-[day]")?
+        )?,
+        date!(-50 - 03 - 15)
+    );
+    assert_eq!(
+        Date::parse(
+            "March 15th, 2021",
+            &fd::parse("[month repr:long] [day padding:none ordinal_suffix:true], [year]")?
+        )?,
+        date!(2021 - 03 - 15)
+    );
+    assert!(Date::parse(
+        "March 15st, 2021",
+        &fd::parse("[month repr:long] [day padding:none ordinal_suffix:true], [year]")?
+    )
+    .is_err());
+
+    let mut parsed = Parsed::new().with_strict(true);
+    parsed.parse_items(
+        b"Q1 2021-03-15",
+        &fd::parse("[quarter repr:short] [year]-[month]-[day]")?,
+    )?;
+    assert_eq!(parsed.quarter(), NonZeroU8::new(1));
+    assert_eq!(Date::try_from(parsed), Ok(date!(2021 - 03 - 15)));
+    assert!(Date::try_from(parsed.with_quarter(NonZeroU8::new(2).unwrap())).is_err());
+    assert!(Date::try_from(parsed.with_era_is_bce(true)).is_err());
+    assert!(Date::try_from(parsed.with_century(19)).is_err());
+    assert!(Date::parse("Q5", &fd::parse("[quarter repr:short]")?).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn century_round_trip() -> time::Result<()> {
+    let format = fd::parse("[century][year repr:last_two]-[month]-[day]")?;
+    for &date in &[
+        date!(2021 - 05 - 03),
+        date!(-2021 - 05 - 03),
+        date!(-50 - 03 - 15),
+    ] {
+        let formatted = date.format(&format)?;
+        assert_eq!(Date::parse(&formatted, &format)?, date, "{}", formatted);
+    }
+    assert_eq!(date!(2021 - 05 - 03).format(&format)?, "2021-05-03");
+
+    if cfg!(feature = "large-dates") {
+        let format = fd::parse("[century width:4][year repr:last_two]-[month]-[day]")?;
+        for &year in &[123_456, 12_345, -12_345, 2021] {
+            let date = Date::from_calendar_date(year, Month::May, 3)?;
+            let formatted = date.format(&format)?;
+            assert_eq!(Date::parse(&formatted, &format)?, date, "{}", formatted);
+        }
+        // A century with more digits than its width is neither formatted nor parsed.
+        let date = Date::from_calendar_date(123_456, Month::May, 3)?;
+        assert!(date
+            .format(&fd::parse("[century][year repr:last_two]-[month]-[day]")?)
+            .is_err());
+        assert!(Date::parse(
+            "123456-05-03",
+            &fd::parse("[century][year repr:last_two]-[month]-[day]")?
+        )
+        .is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_locale() -> time::Result<()> {
+    assert_eq!(
+        Date::parse(
+            "lundi 3 février 2020",
+            &fd::parse(
+                "[weekday locale:fr] [day padding:none] [month repr:long locale:fr] [year]"
+            )?
+        )?,
+        date!(2020 - 02 - 03)
+    );
+    assert_eq!(
+        Date::parse(
+            "2020年2月3日",
+            &fd::parse("[year]年[month repr:long locale:ja][day padding:none]日")?
+        )?,
+        date!(2020 - 02 - 03)
+    );
+    assert_eq!(
+        Date::parse(
+            "3 февраля 2020",
+            &fd::parse("[day padding:none] [month repr:long locale:ru] [year]")?
+        )?,
+        date!(2020 - 02 - 03)
+    );
+    assert!(Date::parse(
+        "3 февраль 2020",
+        &fd::parse("[day padding:none] [month repr:long locale:ru] [year]")?
+    )
+    .is_err());
+    assert_eq!(
+        Date::parse(
+            "03 DEZ. 2020",
+            &fd::parse("[day] [month repr:short locale:de case_sensitive:false] [year]")?
+        )?,
+        date!(2020 - 12 - 03)
+    );
+    assert_eq!(
+        Time::parse(
+            "3:04 p.\u{a0}m.",
+            &fd::parse("[hour repr:12 padding:none]:[minute] [period locale:es]")?
+        )?,
+        time!(15:04)
+    );
+    assert_eq!(
+        Time::parse(
+            "午前3:04",
+            &fd::parse("[period locale:ja][hour repr:12 padding:none]:[minute]")?
+        )?,
+        time!(3:04)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_time_zone() -> time::Result<()> {
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse_items(
+        b"2021-03-04 10:00:00 PST [America/Los_Angeles] rest",
+        &fd::parse(
+            "[year]-[month]-[day] [hour]:[minute]:[second] [time_zone] [[[time_zone repr:name]]",
+        )?,
+    )?;
+    assert_eq!(remaining, b" rest");
+    assert_eq!(parsed.time_zone_abbreviation(), Some("PST"));
+    assert_eq!(parsed.time_zone_name(), Some("America/Los_Angeles"));
+    assert!(UtcOffset::try_from(parsed).is_err());
+
+    let format_description = fd::parse("[year]-[month]-[day] [hour]:[minute] [time_zone]")?;
+    assert_eq!(
+        OffsetDateTime::parse("2021-03-04 10:00 UTC", &format_description)?,
+        datetime!(2021-03-04 10:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-03-04 10:00 Z", &format_description)?,
+        datetime!(2021-03-04 10:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2021-03-04 10:00 Etc/GMT",
+            &fd::parse("[year]-[month]-[day] [hour]:[minute] [time_zone repr:name]")?
+        )?,
+        datetime!(2021-03-04 10:00 UTC)
+    );
+    assert!(OffsetDateTime::parse("2021-03-04 10:00 PST", &format_description).is_err());
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-03-04 10:00 +0530", &format_description)?,
+        datetime!(2021-03-04 10:00)
+    );
+    assert!(PrimitiveDateTime::parse("2021-03-04 10:00 +053", &format_description).is_err());
+    assert!(PrimitiveDateTime::parse("2021-03-04 10:00 LONGNAME", &format_description).is_err());
+
+    let format_description = fd::parse("[offset_hour]:[offset_minute] [time_zone]")?;
+    assert_eq!(
+        UtcOffset::parse("+00:00 GMT", &format_description)?,
+        UtcOffset::UTC
+    );
+    assert_eq!(
+        UtcOffset::parse("+01:00 GMT", &format_description)?,
+        offset!(+1)
+    );
+    let mut parsed = Parsed::new().with_strict(true);
+    parsed.parse_items(b"+01:00 GMT", &format_description)?;
+    assert!(UtcOffset::try_from(parsed).is_err());
+
+    let parsed = Parsed::new()
+        .with_time_zone_abbreviation("GMT")?
+        .with_time_zone_name("Europe/London")?;
+    assert_eq!(parsed.time_zone_abbreviation(), Some("GMT"));
+    assert_eq!(parsed.time_zone_name(), Some("Europe/London"));
+    assert_eq!(UtcOffset::try_from(parsed)?, UtcOffset::UTC);
+
+    let mut parsed = Parsed::new();
+    parsed.set_time_zone_abbreviation("+0530")?;
+    parsed.set_time_zone_name("Etc/GMT+5")?;
+    assert_eq!(parsed.time_zone_abbreviation(), Some("+0530"));
+    assert_eq!(parsed.time_zone_name(), Some("Etc/GMT+5"));
+    assert_eq!(
+        parsed.set_time_zone_abbreviation("TOOLONG"),
+        Err(time::error::ParseFromDescription::InvalidComponent(
+            "time zone"
+        ))
+    );
+    assert_eq!(
+        parsed.set_time_zone_name("Etc/GMT 5"),
+        Err(time::error::ParseFromDescription::InvalidComponent(
+            "time zone"
+        ))
+    );
+    assert!(parsed.set_time_zone_name("").is_err());
+    assert!(parsed.set_time_zone_name("5/Etc").is_err());
+    assert_eq!(parsed.time_zone_abbreviation(), Some("+0530"));
+    assert_eq!(parsed.time_zone_name(), Some("Etc/GMT+5"));
+
+    Ok(())
+}
+
+#[test]
+fn parse_nested_items() -> time::Result<()> {
+    let format = format_description!(version = 2, "[hour]:[minute][optional [:[second]]]");
+    assert_eq!(Time::parse("13:02", &format)?, time!(13:02));
+    assert_eq!(Time::parse("13:02:03", &format)?, time!(13:02:03));
+    assert!(Time::parse("13:02:", &format).is_err());
+
+    let format = fd::parse_owned(
+        fd::Version::V2,
+        "[year]-[first [[month]-[day]] [[ordinal]]][optional [ [hour]:[minute]]]",
+    )?;
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-03-04 05:06", &format)?,
+        datetime!(2021-03-04 05:06)
+    );
+    assert_eq!(Date::parse("2021-063", &format)?, date!(2021 - 063));
+    // The item is not consumed when only part of it matches.
+    assert!(Date::parse("2021-063 05", &format).is_err());
+
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse_items(
+        b"2021-x",
+        format_description!(version = 2, "[year]-[first [[month]] [[day]]]"),
+    );
+    assert!(remaining.is_err());
+    assert_eq!(parsed.year(), Some(2021));
+    assert_eq!(parsed.month(), None);
+    assert_eq!(parsed.day(), None);
+    Ok(())
+}
+
+#[test]
+fn parse_case_insensitive_literals() -> time::Result<()> {
+    let format = format_description!(
+        version = 2,
+        "[year]-[month]-[day][case_insensitive [T]][hour]:[minute][case_insensitive [z]]"
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-03-04t05:06Z", &format)?,
+        datetime!(2021-03-04 05:06)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-03-04T05:06z", &format)?,
+        datetime!(2021-03-04 05:06)
+    );
+    assert!(PrimitiveDateTime::parse("2021-03-04 05:06Z", &format).is_err());
+
+    let format = fd::parse_owned(
+        fd::Version::V2,
+        "[year]-[month]-[day][first [T] [t] [ ]][hour]:[minute]",
+    )?;
+    for input in &["2021-03-04T05:06", "2021-03-04t05:06", "2021-03-04 05:06"] {
+        assert_eq!(
+            PrimitiveDateTime::parse(input, &format)?,
+            datetime!(2021-03-04 05:06)
+        );
+    }
+    assert!(PrimitiveDateTime::parse("2021-03-04_05:06", &format).is_err());
+
+    let format = fd::parse_owned(fd::Version::V2, r"[hour][case_insensitive [h\]]][minute]")?;
+    assert_eq!(Time::parse("05H]06", &format)?, time!(05:06));
+    assert!(Time::parse("05H06", &format).is_err());
+    let format = fd::parse_owned(
+        fd::Version::V2,
+        "[ignore][case_insensitive [T]][hour]:[minute]",
+    )?;
+    assert_eq!(Time::parse("mont05:06", &format)?, time!(05:06));
+    assert_eq!(Time::parse("monT05:06", &format)?, time!(05:06));
+    assert!(Time::parse("mon05:06", &format).is_err());
+
+    let item = FormatItem::CaseInsensitiveLiteral(b"utc");
+    assert_eq!(Parsed::new().parse_item(b"UtCx", &item)?, b"x");
+    assert!(Parsed::new().parse_item(b"UT", &item).is_err());
+    Ok(())
+}
+
+#[test]
+fn parse_input_components() -> time::Result<()> {
+    let format_description = fd::parse("[year]-[month]-[day][whitespace][hour]:[minute]")?;
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-03-04 \t\n 10:00", &format_description)?,
+        datetime!(2021-03-04 10:00)
     );
+    assert!(PrimitiveDateTime::parse("2021-03-0410:00", &format_description).is_err());
+
+    assert_eq!(
+        Date::parse(
+            "Thu, 2021-03-04",
+            &fd::parse("[ignore count:5][year]-[month]-[day]")?
+        )?,
+        date!(2021 - 03 - 04)
+    );
+    assert!(Date::parse(
+        "2021-03-04",
+        &fd::parse("[year]-[month]-[day][ignore count:1]")?
+    )
+    .is_err());
+    assert_eq!(
+        Date::parse(
+            "id=12345, 2021-03-04",
+            &fd::parse("[ignore], [year]-[month]-[day]")?
+        )?,
+        date!(2021 - 03 - 04)
+    );
+    assert!(Date::parse(
+        "id=12345 2021-03-04",
+        &fd::parse("[ignore], [year]-[month]-[day]")?
+    )
+    .is_err());
+
+    let mut parsed = Parsed::new();
+    assert_eq!(
+        parsed.parse_items(
+            b"2021-03-04 anything",
+            &fd::parse("[year]-[month]-[day][ignore]")?
+        )?,
+        b""
+    );
+    let format_description = fd::parse("[year]-[month]-[day][end]")?;
+    assert_eq!(
+        Parsed::new().parse_items(b"2021-03-04", &format_description)?,
+        b""
+    );
+    assert!(Parsed::new()
+        .parse_items(b"2021-03-04 rest", &format_description)
+        .is_err());
+    assert!(Date::parse_prefix("2021-03-04 rest", &format_description).is_err());
 
     Ok(())
 }
@@ -487,3 +1214,476 @@ fn parse_components() -> time::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn parsed_accessors() {
+    let mut parsed = Parsed::new()
+        .with_year(2021)
+        .with_month(Month::January)
+        .with_hour_24(13);
+    assert_eq!(parsed.year(), Some(2021));
+    assert_eq!(parsed.month(), Some(Month::January));
+    assert_eq!(parsed.hour_24(), Some(13));
+    assert_eq!(parsed.day(), None);
+    assert_eq!(parsed.year, Some(2021));
+
+    parsed.set_day(NonZeroU8::new(2).unwrap());
+    parsed.set_minute(4);
+    assert_eq!(parsed.day(), NonZeroU8::new(2));
+    assert_eq!(
+        PrimitiveDateTime::try_from(parsed),
+        Ok(datetime!(2021-01-02 13:04))
+    );
+
+    assert!(!parsed.is_strict());
+    parsed.set_strict(true);
+    assert!(parsed.is_strict());
+    assert!(!parsed.with_strict(false).is_strict());
+}
+
+#[test]
+fn parsed_parse_items() -> time::Result<()> {
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse_items(b"2021-01-02 rest", &fd::parse("[year]-[month]-[day]")?)?;
+    assert_eq!(remaining, b" rest");
+    assert_eq!(Date::try_from(parsed)?, date!(2021 - 01 - 02));
+
+    let remaining = parsed.parse_item(
+        b"13:04",
+        &fd::FormatItem::Component(Component::Hour(modifier::Hour::default())),
+    )?;
+    assert_eq!(remaining, b":04");
+    assert_eq!(parsed.hour_24(), Some(13));
+
+    assert!(matches!(
+        parsed.parse_items(b"2021-01", &fd::parse("[year]/[month]")?),
+        Err(time::error::Parse::Located {
+            position: 4,
+            item_index: Some(1),
+            ..
+        })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parsed_strict() -> time::Result<()> {
+    use time::error::TryFromParsed::InconsistentInformation;
+
+    let parsed = Parsed::new()
+        .with_year(2021)
+        .with_month(Month::January)
+        .with_day(NonZeroU8::new(4).unwrap())
+        .with_hour_24(13)
+        .with_minute(0);
+
+    let consistent = parsed
+        .with_weekday(Weekday::Monday)
+        .with_ordinal(NonZeroU16::new(4).unwrap())
+        .with_year_last_two(21)
+        .with_iso_year(2021)
+        .with_iso_week_number(NonZeroU8::new(1).unwrap())
+        .with_sunday_week_number(1)
+        .with_monday_week_number(1)
+        .with_hour_12(NonZeroU8::new(1).unwrap())
+        .with_hour_12_is_pm(true)
+        .with_strict(true);
+    assert_eq!(
+        PrimitiveDateTime::try_from(consistent)?,
+        datetime!(2021-01-04 13:00)
+    );
+
+    let inconsistent = [
+        (parsed.with_weekday(Weekday::Tuesday), "weekday"),
+        // The year and ordinal take precedence when constructing the date.
+        (parsed.with_ordinal(NonZeroU16::new(5).unwrap()), "day"),
+        (parsed.with_year_last_two(20), "year"),
+        (parsed.with_iso_year(2020), "year"),
+        (parsed.with_iso_year_last_two(20), "year"),
+        (
+            parsed.with_iso_week_number(NonZeroU8::new(2).unwrap()),
+            "week number",
+        ),
+        (parsed.with_sunday_week_number(0), "week number"),
+        (parsed.with_monday_week_number(2), "week number"),
+        (parsed.with_hour_12(NonZeroU8::new(12).unwrap()), "hour"),
+        (parsed.with_hour_12_is_pm(false), "period"),
+    ];
+    for &(parsed, component) in &inconsistent {
+        assert!(PrimitiveDateTime::try_from(parsed).is_ok());
+        assert_eq!(
+            PrimitiveDateTime::try_from(parsed.with_strict(true)),
+            Err(InconsistentInformation { component })
+        );
+    }
+
+    // Redundant values are checked regardless of which values were used to construct the date.
+    let ordinal = Parsed::new()
+        .with_year(2021)
+        .with_ordinal(NonZeroU16::new(32).unwrap())
+        .with_strict(true);
+    assert_eq!(Date::try_from(ordinal)?, date!(2021 - 02 - 01));
+    assert_eq!(
+        Date::try_from(ordinal.with_month(Month::January)),
+        Err(InconsistentInformation { component: "month" })
+    );
+    assert_eq!(
+        Date::try_from(ordinal.with_day(NonZeroU8::new(2).unwrap())),
+        Err(InconsistentInformation { component: "day" })
+    );
+    assert_eq!(
+        Time::try_from(
+            Parsed::new()
+                .with_hour_12(NonZeroU8::new(12).unwrap())
+                .with_hour_12_is_pm(false)
+                .with_minute(0)
+                .with_strict(true)
+        )?,
+        time!(0:00)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_with_defaults() -> time::Result<()> {
+    let day_one = Parsed::new().with_day(NonZeroU8::new(1).unwrap());
+    assert_eq!(
+        Date::parse_with_defaults("2021-05", &fd::parse("[year]-[month]")?, day_one)?,
+        date!(2021 - 05 - 01)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_with_defaults(
+            "14:30",
+            &fd::parse("[hour]:[minute]")?,
+            date!(2021 - 05 - 03).into()
+        )?,
+        datetime!(2021-05-03 14:30)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_with_defaults(
+            "14:30",
+            &fd::parse("[hour]:[minute]")?,
+            datetime!(2021-05-03 01:02:03.4 +5).into()
+        )?,
+        datetime!(2021-05-03 14:30:03.4)
+    );
+    assert_eq!(
+        OffsetDateTime::parse_with_defaults(
+            "2021-05-03 14:30",
+            &fd::parse("[year]-[month]-[day] [hour]:[minute]")?,
+            datetime!(2000-01-01 00:00 -01:02:03).into()
+        )?,
+        datetime!(2021-05-03 14:30 -01:02:03)
+    );
+    assert_eq!(
+        OffsetDateTime::parse_with_defaults("2021-05-03T14:30:00Z", &Rfc3339, Parsed::new())?,
+        datetime!(2021-05-03 14:30 UTC)
+    );
+    assert_eq!(
+        Time::parse_with_defaults("14", &fd::parse("[hour]")?, Parsed::new().with_minute(0))?,
+        time!(14:00)
+    );
+    assert_eq!(
+        UtcOffset::parse_with_defaults("", &fd::parse("")?, offset!(-1:02).into())?,
+        offset!(-1:02)
+    );
+
+    // Defaults that could conflict with known values are not used.
+    let reference: Parsed = datetime!(2020-06-15 15:00 +3).into();
+    assert_eq!(
+        Date::parse_with_defaults("100", &fd::parse("[ordinal]")?, reference)?,
+        date!(2020 - 100)
+    );
+    assert_eq!(
+        Time::parse_with_defaults("02 AM", &fd::parse("[hour repr:12] [period]")?, reference)?,
+        time!(2:00)
+    );
+    assert_eq!(
+        UtcOffset::parse_with_defaults("+01", &fd::parse("[offset_hour]")?, reference)?,
+        offset!(+1)
+    );
+
+    assert!(matches!(
+        Date::parse_with_defaults("2021", &fd::parse("[year]")?, Parsed::new()),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+    assert!(matches!(
+        Date::parse_with_defaults(
+            "2021-05 Mon",
+            &fd::parse("[year]-[month] [weekday repr:short]")?,
+            day_one.with_strict(true)
+        ),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InconsistentInformation {
+                component: "weekday"
+            }
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_two_digit_year() -> time::Result<()> {
+    use time::parsing::TwoDigitYear;
+
+    let format = fd::parse("[year repr:last_two]-[month]-[day]")?;
+    assert!(matches!(
+        Date::parse("21-05-03", &format),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+
+    let resolutions = [
+        (TwoDigitYear::Century(20), "21-05-03", date!(2021 - 05 - 03)),
+        (TwoDigitYear::Century(19), "21-05-03", date!(1921 - 05 - 03)),
+        (TwoDigitYear::Pivot(1950), "49-05-03", date!(2049 - 05 - 03)),
+        (TwoDigitYear::Pivot(1950), "50-05-03", date!(1950 - 05 - 03)),
+        (TwoDigitYear::Pivot(1999), "98-05-03", date!(2098 - 05 - 03)),
+        (
+            TwoDigitYear::Window {
+                reference_year: 2021,
+                years_ahead: 20,
+            },
+            "41-05-03",
+            date!(2041 - 05 - 03),
+        ),
+        (
+            TwoDigitYear::Window {
+                reference_year: 2021,
+                years_ahead: 20,
+            },
+            "42-05-03",
+            date!(1942 - 05 - 03),
+        ),
+        (
+            TwoDigitYear::Window {
+                reference_year: 2021,
+                years_ahead: 255,
+            },
+            "21-05-03",
+            date!(2021 - 05 - 03),
+        ),
+    ];
+    for &(resolution, input, expected) in &resolutions {
+        let defaults = Parsed::new().with_two_digit_year(resolution);
+        assert_eq!(
+            Date::parse_with_defaults(input, &format, defaults)?,
+            expected
+        );
+    }
+
+    let pivot = Parsed::new()
+        .with_two_digit_year(TwoDigitYear::Pivot(1950))
+        .with_strict(true);
+    assert_eq!(
+        Date::parse_with_defaults(
+            "21-W01-1",
+            &fd::parse("[year repr:last_two base:iso_week]-W[week_number]-[weekday repr:monday]")?,
+            pivot
+        )?,
+        date!(2021 - 01 - 04)
+    );
+    // The full year takes precedence.
+    assert_eq!(
+        Date::parse_with_defaults(
+            "2021-05-03",
+            &fd::parse("[year]-[month]-[day]")?,
+            pivot.with_year_last_two(22).with_strict(false)
+        )?,
+        date!(2021 - 05 - 03)
+    );
+    assert_eq!(TwoDigitYear::Century(i32::MAX).resolve(0), i32::MAX);
+
+    Ok(())
+}
+
+#[test]
+fn parse_leap_second() -> time::Result<()> {
+    use time::parsing::LeapSecond;
+
+    let format = fd::parse("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond]")?;
+    let input = "2016-12-31 23:59:60.5";
+    assert!(matches!(
+        PrimitiveDateTime::parse(input, &format),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(component)
+        )) if component.name == "second"
+    ));
+
+    let config = |handling| Parsed::new().with_leap_second(handling);
+    assert_eq!(
+        PrimitiveDateTime::parse_with_defaults(input, &format, config(LeapSecond::Clamp))?,
+        datetime!(2016-12-31 23:59:59.5)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_with_defaults(input, &format, config(LeapSecond::Fold))?,
+        datetime!(2017-01-01 0:00:00.5)
+    );
+    let preserved =
+        PrimitiveDateTime::parse_with_defaults(input, &format, config(LeapSecond::Preserve))?;
+    assert_eq!(preserved.date(), date!(2016 - 12 - 31));
+    assert_eq!(preserved.time(), Time::leap_second(23, 59, 500_000_000)?);
+    assert_eq!(
+        Time::parse_with_defaults(
+            "23:59:60.5",
+            &fd::parse("[hour]:[minute]:[second].[subsecond]")?,
+            config(LeapSecond::Fold)
+        )?,
+        time!(0:00:00.5)
+    );
+    assert!(matches!(
+        PrimitiveDateTime::try_from(
+            Parsed::from(Date::MAX)
+                .with_hour_24(23)
+                .with_minute(59)
+                .with_second(60)
+                .with_leap_second(LeapSecond::Fold)
+        ),
+        Err(time::error::TryFromParsed::ComponentRange(
+            time::error::ComponentRange { name: "year", .. }
+        ))
+    ));
+
+    // RFC 3339 treats a leap second as the previous second unless configured otherwise.
+    let input = "2016-12-31T23:59:60Z";
+    assert_eq!(
+        OffsetDateTime::parse(input, &Rfc3339)?,
+        datetime!(2016-12-31 23:59:59 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse_with_defaults(input, &Rfc3339, config(LeapSecond::Fold))?,
+        datetime!(2017-01-01 0:00 UTC)
+    );
+    let preserved =
+        OffsetDateTime::parse_with_defaults(input, &Rfc3339, config(LeapSecond::Preserve))?;
+    assert!(preserved.time().is_leap_second());
+    assert_eq!(preserved.format(&Rfc3339)?, input);
+
+    Ok(())
+}
+
+#[test]
+fn parse_relative() -> time::Result<()> {
+    use time::error::{Parse, ParseFromDescription};
+
+    // a Wednesday
+    let anchor = datetime!(2021-05-12 15:30:45 +2);
+    let interval = |input| -> time::Result<_> {
+        let interval = Interval::parse_relative(input, anchor)?;
+        Ok((interval.start, interval.end))
+    };
+    let instant = |input| -> time::Result<_> {
+        let (start, end) = interval(input)?;
+        assert_eq!(start, end);
+        Ok(start)
+    };
+
+    assert_eq!(instant("now")?, anchor);
+    assert_eq!(
+        interval("today")?,
+        (datetime!(2021-05-12 0:00 +2), datetime!(2021-05-13 0:00 +2))
+    );
+    assert_eq!(
+        interval(" Yesterday ")?,
+        (datetime!(2021-05-11 0:00 +2), datetime!(2021-05-12 0:00 +2))
+    );
+    assert_eq!(instant("tomorrow at noon")?, datetime!(2021-05-13 12:00 +2));
+    assert_eq!(instant("3 days ago")?, datetime!(2021-05-09 15:30:45 +2));
+    assert_eq!(
+        instant("an hour from now")?,
+        datetime!(2021-05-12 16:30:45 +2)
+    );
+    assert_eq!(instant("in 10 minutes")?, datetime!(2021-05-12 15:40:45 +2));
+    assert_eq!(instant("in 2 weeks")?, datetime!(2021-05-26 15:30:45 +2));
+    assert_eq!(instant("1 year ago")?, datetime!(2020-05-12 15:30:45 +2));
+    assert_eq!(instant("9:00")?, datetime!(2021-05-12 9:00 +2));
+    assert_eq!(instant("9:15 pm")?, datetime!(2021-05-12 21:15 +2));
+    assert_eq!(instant("next friday 9am")?, datetime!(2021-05-14 9:00 +2));
+    assert_eq!(instant("last wed 12am")?, datetime!(2021-05-05 0:00 +2));
+    assert_eq!(
+        instant("monday at midnight")?,
+        datetime!(2021-05-10 0:00 +2)
+    );
+    assert_eq!(
+        instant("2 days ago at 17:30")?,
+        datetime!(2021-05-10 17:30 +2)
+    );
+    assert_eq!(
+        interval("next wednesday")?,
+        (datetime!(2021-05-19 0:00 +2), datetime!(2021-05-20 0:00 +2))
+    );
+    assert_eq!(
+        interval("last month")?,
+        (datetime!(2021-04-01 0:00 +2), datetime!(2021-05-01 0:00 +2))
+    );
+    assert_eq!(
+        interval("this week")?,
+        (datetime!(2021-05-10 0:00 +2), datetime!(2021-05-17 0:00 +2))
+    );
+    assert_eq!(
+        interval("next year")?,
+        (datetime!(2022-01-01 0:00 +2), datetime!(2023-01-01 0:00 +2))
+    );
+    assert_eq!(
+        interval("last hour")?,
+        (datetime!(2021-05-12 14:00 +2), datetime!(2021-05-12 15:00 +2))
+    );
+    assert_eq!(
+        interval("next day")?,
+        (datetime!(2021-05-13 0:00 +2), datetime!(2021-05-14 0:00 +2))
+    );
+    assert_eq!(
+        interval("last march")?,
+        (datetime!(2021-03-01 0:00 +2), datetime!(2021-04-01 0:00 +2))
+    );
+    assert_eq!(
+        interval("next May")?,
+        (datetime!(2022-05-01 0:00 +2), datetime!(2022-06-01 0:00 +2))
+    );
+
+    // Months are clamped to the last day.
+    assert_eq!(
+        OffsetDateTime::parse_relative("1 month ago", datetime!(2021-03-31 0:00 UTC))?,
+        datetime!(2021-02-28 0:00 UTC)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_relative("yesterday", datetime!(2021-03-01 12:00))?,
+        datetime!(2021-02-28 0:00)
+    );
+
+    let err = |input| Interval::parse_relative(input, anchor).unwrap_err();
+    assert_eq!(
+        err("").expected(),
+        Some(ParseFromDescription::InvalidComponent("relative date"))
+    );
+    assert_eq!(
+        err("next fortnight").expected(),
+        Some(ParseFromDescription::InvalidComponent("unit"))
+    );
+    assert_eq!(err("next fortnight").position(), Some(0));
+    assert!(matches!(
+        err("tomorrow whenever"),
+        Parse::Located {
+            error: ParseFromDescription::UnexpectedTrailingCharacters { .. },
+            position: 9,
+            ..
+        }
+    ));
+    assert_eq!(
+        err("last month at 9am").expected(),
+        Some(ParseFromDescription::InvalidComponent("time"))
+    );
+    assert_eq!(err("last month at 9am").position(), Some(11));
+    assert!(Interval::parse_relative("13pm", anchor).is_err());
+    assert!(Interval::parse_relative("3 days", anchor).is_err());
+    assert!(Interval::parse_relative("fridays", anchor).is_err());
+    assert!(OffsetDateTime::parse_relative("tomorrow", datetime!(+999999-12-31 0:00 UTC)).is_err());
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time};
-use time::{Month, PrimitiveDateTime, Weekday};
+use time::{Month, PrimitiveDateTime, Time, Weekday};
 
 #[test]
 fn new() {
@@ -415,6 +415,20 @@ fn sub_datetime() {
     );
 }
 
+#[test]
+fn leap_second() -> time::Result<()> {
+    let leap_second = date!(2016 - 12 - 31).with_time(Time::leap_second(23, 59, 0)?);
+    assert_eq!(leap_second + 1.seconds(), datetime!(2017-01-01 0:00:01));
+    assert_eq!(leap_second + 1.std_seconds(), datetime!(2017-01-01 0:00:01));
+    assert_eq!(leap_second - 1.seconds(), datetime!(2016-12-31 23:59:59));
+    assert_eq!(leap_second - 1.std_seconds(), datetime!(2016-12-31 23:59:59));
+    assert_eq!(datetime!(2017-01-01 0:00) - leap_second, 0.seconds());
+    assert_eq!(leap_second - datetime!(2016-12-31 23:59:59), 1.seconds());
+    assert!(leap_second > datetime!(2016-12-31 23:59:59.999));
+    assert!(leap_second < datetime!(2017-01-01 0:00));
+    Ok(())
+}
+
 #[test]
 fn ord() {
     use Ordering::*;
@@ -1,4 +1,8 @@
-use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Compact, Configure,
+    Readable, Token,
+};
+use time::ext::NumericalDuration;
 use time::macros::{date, datetime, offset, time};
 use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
@@ -46,6 +50,36 @@ fn time() {
         &time!(23:58:59.123_456_789).readable(),
         &[Token::BorrowedStr("23:58:59.123456789")],
     );
+    assert_ser_tokens(
+        &Time::leap_second(23, 59, 5).unwrap().compact(),
+        &[
+            Token::Tuple { len: 4 },
+            Token::U8(23),
+            Token::U8(59),
+            Token::U8(60),
+            Token::U32(5),
+            Token::TupleEnd,
+        ],
+    );
+    assert_de_tokens_error::<Compact<Time>>(
+        &[
+            Token::Tuple { len: 4 },
+            Token::U8(23),
+            Token::U8(59),
+            Token::U8(60),
+            Token::U32(5),
+            Token::TupleEnd,
+        ],
+        "invalid value: integer `60`, expected a value in the range 0..=59",
+    );
+    assert_ser_tokens(
+        &Time::leap_second(23, 59, 5).unwrap().readable(),
+        &[Token::BorrowedStr("23:59:60.000000005")],
+    );
+    assert_de_tokens_error::<Readable<Time>>(
+        &[Token::BorrowedStr("23:59:60.000000005")],
+        "invalid value: integer `60`, expected a value in the range 0..=59",
+    );
     assert_de_tokens_error::<Readable<Time>>(
         &[Token::BorrowedStr("24:00:00.0")],
         "invalid value: integer `24`, expected a value in the range 0..=23",
@@ -491,3 +525,123 @@ fn month() {
         "invalid type: boolean `false`, expected u8",
     );
 }
+
+#[test]
+fn human_duration() -> serde_json::Result<()> {
+    use time::serde::human_duration;
+
+    let mut output = Vec::new();
+    human_duration::serialize(
+        &(-90).minutes(),
+        &mut serde_json::Serializer::new(&mut output),
+    )?;
+    assert_eq!(output, br#""-1h30m""#);
+    assert_eq!(
+        human_duration::deserialize(&mut serde_json::Deserializer::from_str(r#""2d 4h""#))?,
+        52.hours()
+    );
+    assert_eq!(
+        human_duration::deserialize(&mut serde_json::Deserializer::from_str(r#""1x""#))
+            .map_err(|err| err.to_string()),
+        Err(r#"invalid value: string "1x", expected a duration such as `1h30m`"#.to_owned())
+    );
+
+    let mut output = Vec::new();
+    human_duration::option::serialize(
+        &Some(250.milliseconds()),
+        &mut serde_json::Serializer::new(&mut output),
+    )?;
+    assert_eq!(output, br#""250ms""#);
+    let mut output = Vec::new();
+    human_duration::option::serialize(&None, &mut serde_json::Serializer::new(&mut output))?;
+    assert_eq!(output, b"null");
+    assert_eq!(
+        human_duration::option::deserialize(&mut serde_json::Deserializer::from_str(r#""1s""#))?,
+        Some(1.seconds())
+    );
+    assert_eq!(
+        human_duration::option::deserialize(&mut serde_json::Deserializer::from_str("null"))?,
+        None
+    );
+
+    Ok(())
+}
+
+#[test]
+fn leap_second() -> serde_json::Result<()> {
+    use time::serde::leap_second;
+
+    let mut output = Vec::new();
+    leap_second::serialize(
+        &Time::leap_second(23, 59, 5).unwrap(),
+        &mut serde_json::Serializer::new(&mut output),
+    )?;
+    assert_eq!(output, br#""23:59:60.000000005""#);
+    assert_eq!(
+        leap_second::deserialize::<_, Time>(&mut serde_json::Deserializer::from_str(
+            r#""23:59:60.000000005""#
+        ))?,
+        Time::leap_second(23, 59, 5).unwrap()
+    );
+    assert_eq!(
+        leap_second::deserialize::<_, PrimitiveDateTime>(&mut serde_json::Deserializer::from_str(
+            r#""2016-12-31 23:59:60.0""#
+        ))?,
+        date!(2016 - 366).with_time(Time::leap_second(23, 59, 0).unwrap())
+    );
+    assert_eq!(
+        leap_second::deserialize::<_, OffsetDateTime>(&mut serde_json::Deserializer::from_str(
+            r#""2016-12-31 23:59:60.0 +00:00:00""#
+        ))?,
+        date!(2016 - 366)
+            .with_time(Time::leap_second(23, 59, 0).unwrap())
+            .assume_utc()
+    );
+    assert_eq!(
+        leap_second::deserialize::<_, Time>(&mut serde_json::Deserializer::from_str(
+            r#""23:59:61.0""#
+        ))
+        .map_err(|err| err.to_string()),
+        Err("invalid value: integer `61`, expected a value in the range 0..=59".to_owned())
+    );
+    // `serde_json` is always human-readable, so use a wrapper to check the compact representation.
+    #[derive(Debug, PartialEq)]
+    struct LeapSecondTime(Time);
+    impl<'a> serde::Deserialize<'a> for LeapSecondTime {
+        fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+            leap_second::deserialize(deserializer).map(Self)
+        }
+    }
+    assert_de_tokens(
+        &LeapSecondTime(Time::leap_second(23, 59, 5).unwrap()).compact(),
+        &[
+            Token::Tuple { len: 4 },
+            Token::U8(23),
+            Token::U8(59),
+            Token::U8(60),
+            Token::U32(5),
+            Token::TupleEnd,
+        ],
+    );
+
+    let mut output = Vec::new();
+    leap_second::option::serialize(
+        &Some(Time::leap_second(23, 59, 0).unwrap()),
+        &mut serde_json::Serializer::new(&mut output),
+    )?;
+    assert_eq!(output, br#""23:59:60.0""#);
+    assert_eq!(
+        leap_second::option::deserialize::<_, Time>(&mut serde_json::Deserializer::from_str(
+            r#""23:59:60.0""#
+        ))?,
+        Some(Time::leap_second(23, 59, 0).unwrap())
+    );
+    assert_eq!(
+        leap_second::option::deserialize::<_, Time>(&mut serde_json::Deserializer::from_str(
+            "null"
+        ))?,
+        None
+    );
+
+    Ok(())
+}
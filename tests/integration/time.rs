@@ -63,6 +63,29 @@ fn from_hms_nano() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn leap_second() -> Result<()> {
+    let time = Time::leap_second(23, 59, 5)?;
+    assert_eq!(time.as_hms_nano(), (23, 59, 60, 5));
+    assert!(time.is_leap_second());
+    assert!(!time!(23:59:59.999_999_999).is_leap_second());
+    assert!(time > time!(23:59:59.999_999_999));
+    assert_eq!(time.to_string(), "23:59:60.000000005");
+    assert_eq!(time + 0.seconds(), time!(0:00:00.000_000_005));
+    assert_eq!(time + 1.seconds(), time!(0:00:01.000_000_005));
+    assert_eq!(time - 1.seconds(), time!(23:59:59.000_000_005));
+    assert_eq!(time - time!(23:59:59.000_000_005), 1.seconds());
+    assert_eq!(time + 1.std_seconds(), time!(0:00:01.000_000_005));
+    assert_eq!(time - 1.std_seconds(), time!(23:59:59.000_000_005));
+    assert!(time < Time::leap_second(23, 59, 6)?);
+    assert!(Time::leap_second(0, 0, 0)? < time!(0:01));
+
+    assert!(Time::leap_second(24, 59, 0).is_err());
+    assert!(Time::leap_second(23, 60, 0).is_err());
+    assert!(Time::leap_second(23, 59, 1_000_000_000).is_err());
+    Ok(())
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(time!(1:02:03).as_hms(), (1, 2, 3));
@@ -0,0 +1,344 @@
+use std::mem;
+
+use proc_macro::Span;
+
+use crate::format_description::{modifier, Component, FormatItem};
+use crate::Error;
+
+#[derive(Clone, Copy)]
+pub(crate) enum TargetType {
+    Date,
+    Time,
+    UtcOffset,
+    PrimitiveDateTime,
+    OffsetDateTime,
+    ZonedDateTime,
+}
+
+impl TargetType {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Date" => Some(Self::Date),
+            "Time" => Some(Self::Time),
+            "UtcOffset" => Some(Self::UtcOffset),
+            "PrimitiveDateTime" => Some(Self::PrimitiveDateTime),
+            "OffsetDateTime" => Some(Self::OffsetDateTime),
+            "ZonedDateTime" => Some(Self::ZonedDateTime),
+            _ => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Date => "Date",
+            Self::Time => "Time",
+            Self::UtcOffset => "UtcOffset",
+            Self::PrimitiveDateTime => "PrimitiveDateTime",
+            Self::OffsetDateTime => "OffsetDateTime",
+            Self::ZonedDateTime => "ZonedDateTime",
+        }
+    }
+}
+
+mod field {
+    pub(super) const YEAR: u32 = 1 << 0;
+    pub(super) const YEAR_LAST_TWO: u32 = 1 << 1;
+    pub(super) const CENTURY: u32 = 1 << 2;
+    pub(super) const YEAR_OF_ERA: u32 = 1 << 3;
+    pub(super) const ERA: u32 = 1 << 4;
+    pub(super) const ISO_YEAR: u32 = 1 << 5;
+    pub(super) const ISO_YEAR_LAST_TWO: u32 = 1 << 6;
+    pub(super) const MONTH: u32 = 1 << 7;
+    pub(super) const QUARTER: u32 = 1 << 8;
+    pub(super) const SUNDAY_WEEK_NUMBER: u32 = 1 << 9;
+    pub(super) const MONDAY_WEEK_NUMBER: u32 = 1 << 10;
+    pub(super) const ISO_WEEK_NUMBER: u32 = 1 << 11;
+    pub(super) const WEEKDAY: u32 = 1 << 12;
+    pub(super) const ORDINAL: u32 = 1 << 13;
+    pub(super) const DAY: u32 = 1 << 14;
+    pub(super) const HOUR_24: u32 = 1 << 15;
+    pub(super) const HOUR_12: u32 = 1 << 16;
+    pub(super) const PERIOD: u32 = 1 << 17;
+    pub(super) const MINUTE: u32 = 1 << 18;
+    pub(super) const SECOND: u32 = 1 << 19;
+    pub(super) const SUBSECOND: u32 = 1 << 20;
+    pub(super) const OFFSET_HOUR: u32 = 1 << 21;
+    pub(super) const OFFSET_MINUTE: u32 = 1 << 22;
+    pub(super) const OFFSET_SECOND: u32 = 1 << 23;
+    pub(super) const TIME_ZONE_ABBREVIATION: u32 = 1 << 24;
+    pub(super) const TIME_ZONE_NAME: u32 = 1 << 25;
+}
+
+mod info {
+    pub(super) const DATE: u8 = 1 << 0;
+    pub(super) const TIME: u8 = 1 << 1;
+    pub(super) const OFFSET: u8 = 1 << 2;
+    pub(super) const ZONE: u8 = 1 << 3;
+}
+
+fn fields(component: &Component) -> u32 {
+    match component {
+        Component::Day(_) => field::DAY,
+        Component::Month(_) => field::MONTH,
+        Component::Ordinal(_) => field::ORDINAL,
+        Component::Weekday(_) => field::WEEKDAY,
+        Component::WeekNumber(modifier::WeekNumber { repr, .. }) => match repr {
+            modifier::WeekNumberRepr::Iso => field::ISO_WEEK_NUMBER,
+            modifier::WeekNumberRepr::Sunday => field::SUNDAY_WEEK_NUMBER,
+            modifier::WeekNumberRepr::Monday => field::MONDAY_WEEK_NUMBER,
+        },
+        Component::Year(modifier::Year {
+            repr,
+            iso_week_based,
+            ..
+        }) => match (iso_week_based, repr) {
+            (false, modifier::YearRepr::Full) => field::YEAR,
+            (false, modifier::YearRepr::LastTwo) => field::YEAR_LAST_TWO,
+            (true, modifier::YearRepr::Full) => field::ISO_YEAR,
+            (true, modifier::YearRepr::LastTwo) => field::ISO_YEAR_LAST_TWO,
+            (_, modifier::YearRepr::OfEra) => field::YEAR_OF_ERA,
+        },
+        Component::Quarter(_) => field::QUARTER,
+        Component::Century(_) => field::CENTURY,
+        Component::Era(_) => field::ERA,
+        Component::Hour(modifier::Hour {
+            is_12_hour_clock: true,
+            ..
+        }) => field::HOUR_12,
+        Component::Hour(_) => field::HOUR_24,
+        Component::Minute(_) => field::MINUTE,
+        Component::Period(_) => field::PERIOD,
+        Component::Second(_) => field::SECOND,
+        Component::Subsecond(_) => field::SUBSECOND,
+        Component::OffsetHour(_) => field::OFFSET_HOUR,
+        Component::OffsetMinute(_) => field::OFFSET_MINUTE,
+        Component::OffsetSecond(_) => field::OFFSET_SECOND,
+        Component::Offset(_) => field::OFFSET_HOUR | field::OFFSET_MINUTE | field::OFFSET_SECOND,
+        Component::TimeZone(modifier::TimeZone { repr }) => match repr {
+            modifier::TimeZoneRepr::Abbreviation => field::TIME_ZONE_ABBREVIATION,
+            modifier::TimeZoneRepr::Name => field::TIME_ZONE_NAME,
+        },
+        Component::Whitespace(_) | Component::Ignore(_) | Component::End(_) => 0,
+    }
+}
+
+fn required_info(component: &Component) -> u8 {
+    match component {
+        Component::Day(_)
+        | Component::Month(_)
+        | Component::Ordinal(_)
+        | Component::Weekday(_)
+        | Component::WeekNumber(_)
+        | Component::Year(_)
+        | Component::Quarter(_)
+        | Component::Century(_)
+        | Component::Era(_) => info::DATE,
+        Component::Hour(_)
+        | Component::Minute(_)
+        | Component::Period(_)
+        | Component::Second(_)
+        | Component::Subsecond(_) => info::TIME,
+        Component::OffsetHour(_)
+        | Component::OffsetMinute(_)
+        | Component::OffsetSecond(_)
+        | Component::Offset(_) => info::OFFSET,
+        Component::TimeZone(_) => info::ZONE,
+        Component::Whitespace(_) | Component::Ignore(_) | Component::End(_) => 0,
+    }
+}
+
+fn component_name(component: &Component) -> &'static str {
+    match component {
+        Component::Day(_) => "day",
+        Component::Month(_) => "month",
+        Component::Ordinal(_) => "ordinal",
+        Component::Weekday(_) => "weekday",
+        Component::WeekNumber(_) => "week_number",
+        Component::Year(_) => "year",
+        Component::Quarter(_) => "quarter",
+        Component::Century(_) => "century",
+        Component::Era(_) => "era",
+        Component::Hour(_) => "hour",
+        Component::Minute(_) => "minute",
+        Component::Period(_) => "period",
+        Component::Second(_) => "second",
+        Component::Subsecond(_) => "subsecond",
+        Component::OffsetHour(_) => "offset_hour",
+        Component::OffsetMinute(_) => "offset_minute",
+        Component::OffsetSecond(_) => "offset_second",
+        Component::Offset(_) => "offset",
+        Component::TimeZone(_) => "time_zone",
+        Component::Whitespace(_) => "whitespace",
+        Component::Ignore(_) => "ignore",
+        Component::End(_) => "end",
+    }
+}
+
+fn has(values: u32, expected: u32) -> bool {
+    values & expected == expected
+}
+
+fn has_date(values: u32) -> bool {
+    let has_year = has(values, field::YEAR)
+        || has(values, field::YEAR_OF_ERA | field::ERA)
+        || has(values, field::CENTURY | field::YEAR_LAST_TWO);
+
+    let has_iso_week_date = has(
+        values,
+        field::ISO_YEAR | field::ISO_WEEK_NUMBER | field::WEEKDAY,
+    );
+
+    has_iso_week_date
+        || (has_year
+            && (has(values, field::ORDINAL)
+                || has(values, field::MONTH | field::DAY)
+                || has(values, field::SUNDAY_WEEK_NUMBER | field::WEEKDAY)
+                || has(values, field::MONDAY_WEEK_NUMBER | field::WEEKDAY)))
+}
+
+fn has_time(values: u32) -> bool {
+    (has(values, field::HOUR_24) || has(values, field::HOUR_12 | field::PERIOD))
+        && has(values, field::MINUTE)
+}
+
+fn has_offset(values: u32) -> bool {
+    has(values, field::OFFSET_HOUR)
+}
+
+struct Analysis {
+    paths: Vec<u32>,
+    required: u8,
+    conflicting: Option<&'static str>,
+}
+
+impl Analysis {
+    fn items(&mut self, items: &[FormatItem<'_>]) {
+        for item in items {
+            self.item(item);
+        }
+    }
+
+    fn item(&mut self, item: &FormatItem<'_>) {
+        match item {
+            FormatItem::Literal(_) | FormatItem::CaseInsensitiveLiteral(_) => {}
+            FormatItem::Component(component) => {
+                let fields = fields(component);
+                if self.conflicting.is_none() && self.paths.iter().any(|&path| path & fields != 0) {
+                    self.conflicting = Some(component_name(component));
+                }
+                for path in &mut self.paths {
+                    *path |= fields;
+                }
+                self.required |= required_info(component);
+            }
+            FormatItem::Optional(items) => {
+                let paths = self.paths.clone();
+                self.items(items);
+                self.paths.extend(paths);
+            }
+            FormatItem::First(alternatives) => {
+                if alternatives.is_empty() {
+                    return;
+                }
+
+                let paths = mem::take(&mut self.paths);
+                let mut all_paths = Vec::new();
+                let mut required = self.required;
+
+                for (index, items) in alternatives.iter().enumerate() {
+                    self.paths.clone_from(&paths);
+                    self.items(items);
+                    all_paths.append(&mut self.paths);
+                    if index == 0 {
+                        required = self.required;
+                    }
+                }
+
+                self.paths = all_paths;
+                self.required = required;
+            }
+        }
+
+        self.paths.sort_unstable();
+        self.paths.dedup();
+    }
+}
+
+/// Ensure that the format description can be used to both format and parse a value of the target
+/// type, and that no component can replace a value set by an earlier one.
+pub(crate) fn validate(
+    items: &[FormatItem<'_>],
+    target: TargetType,
+    span: Span,
+) -> Result<(), Error> {
+    let mut analysis = Analysis {
+        paths: vec![0],
+        required: 0,
+        conflicting: None,
+    };
+    analysis.items(items);
+
+    let error = |message: String| Error::Custom {
+        message: message.into(),
+        span_start: Some(span),
+        span_end: Some(span),
+    };
+
+    if let Some(name) = analysis.conflicting {
+        return Err(error(format!(
+            "component `{}` may replace a value set by an earlier component",
+            name
+        )));
+    }
+
+    let (date, time, offset, zone) = match target {
+        TargetType::Date => (true, false, false, false),
+        TargetType::Time => (false, true, false, false),
+        TargetType::UtcOffset => (false, false, true, false),
+        TargetType::PrimitiveDateTime => (true, true, false, false),
+        TargetType::OffsetDateTime => (true, true, true, false),
+        TargetType::ZonedDateTime => (true, true, true, true),
+    };
+
+    let missing = [
+        (info::DATE, date, "a date"),
+        (info::TIME, time, "a time"),
+        (info::OFFSET, offset, "an offset"),
+        (info::ZONE, zone, "a time zone"),
+    ]
+    .iter()
+    .find(|&&(info, available, _)| analysis.required & info != 0 && !available)
+    .map(|&(_, _, what)| what);
+    if let Some(what) = missing {
+        return Err(error(format!(
+            "`{}` cannot be formatted with this format description, as it requires {}",
+            target.name(),
+            what
+        )));
+    }
+
+    // `ZonedDateTime` cannot be parsed, so only formatting is checked.
+    if matches!(target, TargetType::ZonedDateTime) {
+        return Ok(());
+    }
+
+    let all_paths = |has: fn(u32) -> bool| analysis.paths.iter().all(|&values| has(values));
+    let missing = [
+        (date, all_paths(has_date), "date"),
+        (time, all_paths(has_time), "time"),
+        (offset, all_paths(has_offset), "offset"),
+    ]
+    .iter()
+    .find(|&&(needed, present, _)| needed && !present)
+    .map(|&(_, _, what)| what);
+    if let Some(what) = missing {
+        return Err(error(format!(
+            "`{}` cannot be parsed with this format description, as it does not always contain \
+             a complete {}",
+            target.name(),
+            what
+        )));
+    }
+
+    Ok(())
+}
@@ -12,6 +12,9 @@ pub(crate) enum Component {
     Weekday(modifier::Weekday),
     WeekNumber(modifier::WeekNumber),
     Year(modifier::Year),
+    Quarter(modifier::Quarter),
+    Century(modifier::Century),
+    Era(modifier::Era),
     Hour(modifier::Hour),
     Minute(modifier::Minute),
     Period(modifier::Period),
@@ -20,6 +23,11 @@ pub(crate) enum Component {
     OffsetHour(modifier::OffsetHour),
     OffsetMinute(modifier::OffsetMinute),
     OffsetSecond(modifier::OffsetSecond),
+    Offset(modifier::Offset),
+    TimeZone(modifier::TimeZone),
+    Whitespace(modifier::Whitespace),
+    Ignore(modifier::Ignore),
+    End(modifier::End),
 }
 
 impl ToTokens for Component {
@@ -32,6 +40,9 @@ impl ToTokens for Component {
                 Self::Weekday(modifier) => quote! { Weekday(#(modifier)) },
                 Self::WeekNumber(modifier) => quote! { WeekNumber(#(modifier)) },
                 Self::Year(modifier) => quote! { Year(#(modifier)) },
+                Self::Quarter(modifier) => quote! { Quarter(#(modifier)) },
+                Self::Century(modifier) => quote! { Century(#(modifier)) },
+                Self::Era(modifier) => quote! { Era(#(modifier)) },
                 Self::Hour(modifier) => quote! { Hour(#(modifier)) },
                 Self::Minute(modifier) => quote! { Minute(#(modifier)) },
                 Self::Period(modifier) => quote! { Period(#(modifier)) },
@@ -40,6 +51,11 @@ impl ToTokens for Component {
                 Self::OffsetHour(modifier) => quote! { OffsetHour(#(modifier)) },
                 Self::OffsetMinute(modifier) => quote! { OffsetMinute(#(modifier)) },
                 Self::OffsetSecond(modifier) => quote! { OffsetSecond(#(modifier)) },
+                Self::Offset(modifier) => quote! { Offset(#(modifier)) },
+                Self::TimeZone(modifier) => quote! { TimeZone(#(modifier)) },
+                Self::Whitespace(modifier) => quote! { Whitespace(#(modifier)) },
+                Self::Ignore(modifier) => quote! { Ignore(#(modifier)) },
+                Self::End(modifier) => quote! { End(#(modifier)) },
             })
         }
     }
@@ -52,6 +68,9 @@ pub(crate) enum NakedComponent {
     Weekday,
     WeekNumber,
     Year,
+    Quarter,
+    Century,
+    Era,
     Hour,
     Minute,
     Period,
@@ -60,6 +79,11 @@ pub(crate) enum NakedComponent {
     OffsetHour,
     OffsetMinute,
     OffsetSecond,
+    Offset,
+    TimeZone,
+    Whitespace,
+    Ignore,
+    End,
 }
 
 impl NakedComponent {
@@ -74,6 +98,9 @@ impl NakedComponent {
             b"weekday" => Ok(Self::Weekday),
             b"week_number" => Ok(Self::WeekNumber),
             b"year" => Ok(Self::Year),
+            b"quarter" => Ok(Self::Quarter),
+            b"century" => Ok(Self::Century),
+            b"era" => Ok(Self::Era),
             b"hour" => Ok(Self::Hour),
             b"minute" => Ok(Self::Minute),
             b"period" => Ok(Self::Period),
@@ -82,6 +109,11 @@ impl NakedComponent {
             b"offset_hour" => Ok(Self::OffsetHour),
             b"offset_minute" => Ok(Self::OffsetMinute),
             b"offset_second" => Ok(Self::OffsetSecond),
+            b"offset" => Ok(Self::Offset),
+            b"time_zone" => Ok(Self::TimeZone),
+            b"whitespace" => Ok(Self::Whitespace),
+            b"ignore" => Ok(Self::Ignore),
+            b"end" => Ok(Self::End),
             b"" => Err(InvalidFormatDescription::MissingComponentName {
                 index: component_index,
             }),
@@ -96,10 +128,13 @@ impl NakedComponent {
         match self {
             Self::Day => Component::Day(modifier::Day {
                 padding: modifiers.padding.unwrap_or_default(),
+                ordinal_suffix: modifiers.day_has_ordinal_suffix.unwrap_or_default(),
             }),
             Self::Month => Component::Month(modifier::Month {
                 padding: modifiers.padding.unwrap_or_default(),
                 repr: modifiers.month_repr.unwrap_or_default(),
+                locale: modifiers.locale.unwrap_or(modifier::Locale::En),
+                standalone: modifiers.month_is_standalone.unwrap_or_default(),
             }),
             Self::Ordinal => Component::Ordinal(modifier::Ordinal {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -107,6 +142,7 @@ impl NakedComponent {
             Self::Weekday => Component::Weekday(modifier::Weekday {
                 repr: modifiers.weekday_repr.unwrap_or_default(),
                 one_indexed: modifiers.weekday_is_one_indexed.unwrap_or(true),
+                locale: modifiers.locale.unwrap_or(modifier::Locale::En),
             }),
             Self::WeekNumber => Component::WeekNumber(modifier::WeekNumber {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -117,6 +153,18 @@ impl NakedComponent {
                 repr: modifiers.year_repr.unwrap_or_default(),
                 iso_week_based: modifiers.year_is_iso_week_based.unwrap_or_default(),
                 sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+                width: modifiers.width,
+            }),
+            Self::Quarter => Component::Quarter(modifier::Quarter {
+                repr: modifiers.quarter_repr.unwrap_or_default(),
+            }),
+            Self::Century => Component::Century(modifier::Century {
+                padding: modifiers.padding.unwrap_or_default(),
+                sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+                width: modifiers.width,
+            }),
+            Self::Era => Component::Era(modifier::Era {
+                repr: modifiers.era_repr.unwrap_or_default(),
             }),
             Self::Hour => Component::Hour(modifier::Hour {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -127,16 +175,21 @@ impl NakedComponent {
             }),
             Self::Period => Component::Period(modifier::Period {
                 is_uppercase: modifiers.period_is_uppercase.unwrap_or(true),
+                locale: modifiers.locale.unwrap_or(modifier::Locale::En),
             }),
             Self::Second => Component::Second(modifier::Second {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
             Self::Subsecond => Component::Subsecond(modifier::Subsecond {
                 digits: modifiers.subsecond_digits.unwrap_or_default(),
+                trim_trailing_zeros: modifiers.subsecond_trim_trailing_zeros.unwrap_or_default(),
+                rounding: modifiers.subsecond_rounding.unwrap_or_default(),
             }),
             Self::OffsetHour => Component::OffsetHour(modifier::OffsetHour {
                 sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
                 padding: modifiers.padding.unwrap_or_default(),
+                z_for_utc: modifiers.z_for_utc.unwrap_or_default(),
+                width: modifiers.width,
             }),
             Self::OffsetMinute => Component::OffsetMinute(modifier::OffsetMinute {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -144,6 +197,22 @@ impl NakedComponent {
             Self::OffsetSecond => Component::OffsetSecond(modifier::OffsetSecond {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
+            Self::Offset => Component::Offset(modifier::Offset {
+                z_for_utc: modifiers.z_for_utc.unwrap_or(true),
+                colon_separated: modifiers.offset_colon_separated.unwrap_or(true),
+                minute: modifiers.offset_minute.unwrap_or_default(),
+                second: modifiers
+                    .offset_second
+                    .unwrap_or(modifier::Presence::Optional),
+            }),
+            Self::TimeZone => Component::TimeZone(modifier::TimeZone {
+                repr: modifiers.time_zone_repr.unwrap_or_default(),
+            }),
+            Self::Whitespace => Component::Whitespace(modifier::Whitespace),
+            Self::Ignore => Component::Ignore(modifier::Ignore {
+                count: modifiers.ignore_count,
+            }),
+            Self::End => Component::End(modifier::End),
         }
     }
 }
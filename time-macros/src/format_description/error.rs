@@ -2,6 +2,8 @@ use std::fmt;
 
 pub(crate) enum InvalidFormatDescription {
     UnclosedOpeningBracket { index: usize },
+    UnexpectedClosingBracket { index: usize },
+    Expected { what: &'static str, index: usize },
     InvalidComponentName { name: String, index: usize },
     InvalidModifier { value: String, index: usize },
     MissingComponentName { index: usize },
@@ -15,6 +17,10 @@ impl fmt::Display for InvalidFormatDescription {
             UnclosedOpeningBracket { index } => {
                 write!(f, "unclosed opening bracket at byte index {}", index)
             }
+            UnexpectedClosingBracket { index } => {
+                write!(f, "unexpected closing bracket at byte index {}", index)
+            }
+            Expected { what, index } => write!(f, "expected {} at byte index {}", what, index),
             InvalidComponentName { name, index } => write!(
                 f,
                 "invalid component name `{}` at byte index {}",
@@ -1,3 +1,4 @@
+mod analysis;
 mod component;
 pub(crate) mod error;
 pub(crate) mod modifier;
@@ -5,6 +6,7 @@ pub(crate) mod parse;
 
 use proc_macro::{Literal, TokenStream};
 
+pub(crate) use self::analysis::{validate, TargetType};
 pub(crate) use self::component::Component;
 pub(crate) use self::parse::parse;
 use crate::to_tokens::ToTokens;
@@ -25,7 +27,27 @@ mod helper {
 #[allow(variant_size_differences)]
 pub(crate) enum FormatItem<'a> {
     Literal(&'a [u8]),
+    CaseInsensitiveLiteral(Vec<u8>),
     Component(Component),
+    Optional(Vec<Self>),
+    First(Vec<Vec<Self>>),
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Version {
+    V1,
+    V2,
+}
+
+fn compound(items: Vec<FormatItem<'_>>) -> TokenStream {
+    quote! {
+        ::time::format_description::FormatItem::Compound(&[#(
+            items
+                .into_iter()
+                .map(|item| quote! { #(item), })
+                .collect::<TokenStream>()
+        )])
+    }
 }
 
 impl ToTokens for FormatItem<'_> {
@@ -33,7 +55,19 @@ impl ToTokens for FormatItem<'_> {
         quote! {
             ::time::format_description::FormatItem::#(match self {
                 FormatItem::Literal(bytes) => quote! { Literal(#(Literal::byte_string(bytes))) },
+                FormatItem::CaseInsensitiveLiteral(bytes) => quote! {
+                    CaseInsensitiveLiteral(#(Literal::byte_string(&bytes)))
+                },
                 FormatItem::Component(component) => quote! { Component(#(component)) },
+                FormatItem::Optional(items) => quote! { Optional(&#(compound(items))) },
+                FormatItem::First(alternatives) => quote! {
+                    First(&[#(
+                        alternatives
+                            .into_iter()
+                            .map(|items| quote! { #(compound(items)), })
+                            .collect::<TokenStream>()
+                    )])
+                },
             })
         }
     }
@@ -31,6 +31,22 @@ macro_rules! to_tokens {
         }
     };
 
+    (
+        $(#[$struct_attr:meta])*
+        $struct_vis:vis struct $struct_name:ident;
+    ) => {
+        $(#[$struct_attr])*
+        $struct_vis struct $struct_name;
+
+        impl ToTokens for $struct_name {
+            fn into_token_stream(self) -> TokenStream {
+                quote! {
+                    ::time::format_description::modifier::$struct_name::default()
+                }
+            }
+        }
+    };
+
     (
         $(#[$enum_attr:meta])*
         $enum_vis:vis enum $enum_name:ident {$(
@@ -59,6 +75,7 @@ macro_rules! to_tokens {
 to_tokens! {
     pub(crate) struct Day {
         pub(crate) padding: Padding,
+        pub(crate) ordinal_suffix: bool,
     }
 }
 
@@ -74,6 +91,8 @@ to_tokens! {
     pub(crate) struct Month {
         pub(crate) padding: Padding,
         pub(crate) repr: MonthRepr,
+        pub(crate) locale: Locale,
+        pub(crate) standalone: bool,
     }
 }
 
@@ -96,6 +115,7 @@ to_tokens! {
     pub(crate) struct Weekday {
         pub(crate) repr: WeekdayRepr,
         pub(crate) one_indexed: bool,
+        pub(crate) locale: Locale,
     }
 }
 
@@ -118,6 +138,7 @@ to_tokens! {
     pub(crate) enum YearRepr {
         Full,
         LastTwo,
+        OfEra,
     }
 }
 
@@ -127,6 +148,41 @@ to_tokens! {
         pub(crate) repr: YearRepr,
         pub(crate) iso_week_based: bool,
         pub(crate) sign_is_mandatory: bool,
+        pub(crate) width: Option<u8>,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum QuarterRepr {
+        Numerical,
+        Short,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Quarter {
+        pub(crate) repr: QuarterRepr,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Century {
+        pub(crate) padding: Padding,
+        pub(crate) sign_is_mandatory: bool,
+        pub(crate) width: Option<u8>,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum EraRepr {
+        Ad,
+        Ce,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Era {
+        pub(crate) repr: EraRepr,
     }
 }
 
@@ -146,6 +202,7 @@ to_tokens! {
 to_tokens! {
     pub(crate) struct Period {
         pub(crate) is_uppercase: bool,
+        pub(crate) locale: Locale,
     }
 }
 
@@ -170,9 +227,18 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) enum SubsecondRounding {
+        Truncate,
+        NearestSaturating,
+    }
+}
+
 to_tokens! {
     pub(crate) struct Subsecond {
         pub(crate) digits: SubsecondDigits,
+        pub(crate) trim_trailing_zeros: bool,
+        pub(crate) rounding: SubsecondRounding,
     }
 }
 
@@ -180,6 +246,8 @@ to_tokens! {
     pub(crate) struct OffsetHour {
         pub(crate) sign_is_mandatory: bool,
         pub(crate) padding: Padding,
+        pub(crate) z_for_utc: bool,
+        pub(crate) width: Option<u8>,
     }
 }
 
@@ -195,6 +263,84 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) enum Presence {
+        Mandatory,
+        Optional,
+        Omitted,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Offset {
+        pub(crate) z_for_utc: bool,
+        pub(crate) colon_separated: bool,
+        pub(crate) minute: Presence,
+        pub(crate) second: Presence,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum TimeZoneRepr {
+        Abbreviation,
+        Name,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct TimeZone {
+        pub(crate) repr: TimeZoneRepr,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Whitespace;
+}
+
+to_tokens! {
+    pub(crate) struct Ignore {
+        pub(crate) count: Option<u16>,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct End;
+}
+
+to_tokens! {
+    #[cfg_attr(not(feature = "locale"), allow(dead_code))]
+    pub(crate) enum Locale {
+        En,
+        De,
+        Es,
+        Fr,
+        It,
+        Ja,
+        Ru,
+    }
+}
+
+impl Locale {
+    fn from_code(code: &[u8]) -> Option<Self> {
+        match code {
+            b"en" => Some(Self::En),
+            #[cfg(feature = "locale")]
+            b"de" => Some(Self::De),
+            #[cfg(feature = "locale")]
+            b"es" => Some(Self::Es),
+            #[cfg(feature = "locale")]
+            b"fr" => Some(Self::Fr),
+            #[cfg(feature = "locale")]
+            b"it" => Some(Self::It),
+            #[cfg(feature = "locale")]
+            b"ja" => Some(Self::Ja),
+            #[cfg(feature = "locale")]
+            b"ru" => Some(Self::Ru),
+            _ => None,
+        }
+    }
+}
+
 to_tokens! {
     pub(crate) enum Padding {
         Space,
@@ -214,17 +360,23 @@ macro_rules! impl_default {
 }
 
 impl_default! {
-    Day => Self { padding: Padding::default() };
+    Day => Self {
+        padding: Padding::default(),
+        ordinal_suffix: false,
+    };
     MonthRepr => Self::Numerical;
     Month => Self {
         padding: Padding::default(),
         repr: MonthRepr::default(),
+        locale: Locale::En,
+        standalone: false,
     };
     Ordinal => Self { padding: Padding::default() };
     WeekdayRepr => Self::Long;
     Weekday => Self {
         repr: WeekdayRepr::default(),
         one_indexed: true,
+        locale: Locale::En,
     };
     WeekNumberRepr => Self::Iso;
     WeekNumber => Self {
@@ -237,22 +389,52 @@ impl_default! {
         repr: YearRepr::default(),
         iso_week_based: false,
         sign_is_mandatory: false,
+        width: None,
+    };
+    QuarterRepr => Self::Numerical;
+    Quarter => Self {
+        repr: QuarterRepr::default(),
+    };
+    Century => Self {
+        padding: Padding::default(),
+        sign_is_mandatory: false,
+        width: None,
+    };
+    EraRepr => Self::Ad;
+    Era => Self {
+        repr: EraRepr::default(),
     };
     Hour => Self {
         padding: Padding::default(),
         is_12_hour_clock: false,
     };
     Minute => Self { padding: Padding::default() };
-    Period => Self { is_uppercase: true };
+    Period => Self {
+        is_uppercase: true,
+        locale: Locale::En,
+    };
     Second => Self { padding: Padding::default() };
     SubsecondDigits => Self::OneOrMore;
-    Subsecond => Self { digits: SubsecondDigits::default() };
+    SubsecondRounding => Self::Truncate;
+    Subsecond => Self {
+        digits: SubsecondDigits::default(),
+        trim_trailing_zeros: false,
+        rounding: SubsecondRounding::default(),
+    };
     OffsetHour => Self {
         sign_is_mandatory: true,
         padding: Padding::default(),
+        z_for_utc: false,
+        width: None,
     };
     OffsetMinute => Self { padding: Padding::default() };
     OffsetSecond => Self { padding: Padding::default() };
+    Presence => Self::Mandatory;
+    TimeZoneRepr => Self::Abbreviation;
+    TimeZone => Self {
+        repr: TimeZoneRepr::default(),
+    };
+    Ignore => Self { count: None };
     Padding => Self::Zero;
 }
 
@@ -269,6 +451,20 @@ pub(crate) struct Modifiers {
     pub(crate) year_repr: Option<YearRepr>,
     pub(crate) year_is_iso_week_based: Option<bool>,
     pub(crate) sign_is_mandatory: Option<bool>,
+    pub(crate) subsecond_trim_trailing_zeros: Option<bool>,
+    pub(crate) subsecond_rounding: Option<SubsecondRounding>,
+    pub(crate) width: Option<u8>,
+    pub(crate) z_for_utc: Option<bool>,
+    pub(crate) offset_colon_separated: Option<bool>,
+    pub(crate) offset_minute: Option<Presence>,
+    pub(crate) offset_second: Option<Presence>,
+    pub(crate) locale: Option<Locale>,
+    pub(crate) month_is_standalone: Option<bool>,
+    pub(crate) day_has_ordinal_suffix: Option<bool>,
+    pub(crate) quarter_repr: Option<QuarterRepr>,
+    pub(crate) era_repr: Option<EraRepr>,
+    pub(crate) time_zone_repr: Option<TimeZoneRepr>,
+    pub(crate) ignore_count: Option<u16>,
 }
 
 impl Modifiers {
@@ -299,7 +495,8 @@ impl Modifiers {
 
             #[allow(clippy::unnested_or_patterns)]
             match (component_name, modifier) {
-                (b"day", b"padding:space")
+                (b"century", b"padding:space")
+                | (b"day", b"padding:space")
                 | (b"hour", b"padding:space")
                 | (b"minute", b"padding:space")
                 | (b"month", b"padding:space")
@@ -310,7 +507,8 @@ impl Modifiers {
                 | (b"second", b"padding:space")
                 | (b"week_number", b"padding:space")
                 | (b"year", b"padding:space") => modifiers.padding = Some(Padding::Space),
-                (b"day", b"padding:zero")
+                (b"century", b"padding:zero")
+                | (b"day", b"padding:zero")
                 | (b"hour", b"padding:zero")
                 | (b"minute", b"padding:zero")
                 | (b"month", b"padding:zero")
@@ -321,7 +519,8 @@ impl Modifiers {
                 | (b"second", b"padding:zero")
                 | (b"week_number", b"padding:zero")
                 | (b"year", b"padding:zero") => modifiers.padding = Some(Padding::Zero),
-                (b"day", b"padding:none")
+                (b"century", b"padding:none")
+                | (b"day", b"padding:none")
                 | (b"hour", b"padding:none")
                 | (b"minute", b"padding:none")
                 | (b"month", b"padding:none")
@@ -332,17 +531,55 @@ impl Modifiers {
                 | (b"second", b"padding:none")
                 | (b"week_number", b"padding:none")
                 | (b"year", b"padding:none") => modifiers.padding = Some(Padding::None),
+                (b"day", b"ordinal_suffix:true") => modifiers.day_has_ordinal_suffix = Some(true),
+                (b"day", b"ordinal_suffix:false") => modifiers.day_has_ordinal_suffix = Some(false),
+                (b"era", b"repr:ad") => modifiers.era_repr = Some(EraRepr::Ad),
+                (b"era", b"repr:ce") => modifiers.era_repr = Some(EraRepr::Ce),
                 (b"hour", b"repr:24") => modifiers.hour_is_12_hour_clock = Some(false),
                 (b"hour", b"repr:12") => modifiers.hour_is_12_hour_clock = Some(true),
                 (b"month", b"repr:numerical") => modifiers.month_repr = Some(MonthRepr::Numerical),
                 (b"month", b"repr:long") => modifiers.month_repr = Some(MonthRepr::Long),
                 (b"month", b"repr:short") => modifiers.month_repr = Some(MonthRepr::Short),
-                (b"offset_hour", b"sign:automatic") | (b"year", b"sign:automatic") => {
-                    modifiers.sign_is_mandatory = Some(false)
+                (b"month", b"standalone:true") => modifiers.month_is_standalone = Some(true),
+                (b"month", b"standalone:false") => modifiers.month_is_standalone = Some(false),
+                (b"month", _) | (b"period", _) | (b"weekday", _)
+                    if modifier.starts_with(b"locale:")
+                        && Locale::from_code(&modifier[7..]).is_some() =>
+                {
+                    modifiers.locale = Locale::from_code(&modifier[7..]);
+                }
+                (b"century", b"sign:automatic")
+                | (b"offset_hour", b"sign:automatic")
+                | (b"year", b"sign:automatic") => modifiers.sign_is_mandatory = Some(false),
+                (b"century", b"sign:mandatory")
+                | (b"offset_hour", b"sign:mandatory")
+                | (b"year", b"sign:mandatory") => modifiers.sign_is_mandatory = Some(true),
+                (b"offset", b"z_for_utc:true") | (b"offset_hour", b"z_for_utc:true") => {
+                    modifiers.z_for_utc = Some(true)
+                }
+                (b"offset", b"z_for_utc:false") | (b"offset_hour", b"z_for_utc:false") => {
+                    modifiers.z_for_utc = Some(false)
+                }
+                (b"offset", b"colon:true") => modifiers.offset_colon_separated = Some(true),
+                (b"offset", b"colon:false") => modifiers.offset_colon_separated = Some(false),
+                (b"offset", b"minute:mandatory") => {
+                    modifiers.offset_minute = Some(Presence::Mandatory)
+                }
+                (b"offset", b"minute:optional") => {
+                    modifiers.offset_minute = Some(Presence::Optional)
+                }
+                (b"offset", b"minute:omitted") => modifiers.offset_minute = Some(Presence::Omitted),
+                (b"offset", b"second:mandatory") => {
+                    modifiers.offset_second = Some(Presence::Mandatory)
+                }
+                (b"offset", b"second:optional") => {
+                    modifiers.offset_second = Some(Presence::Optional)
                 }
-                (b"offset_hour", b"sign:mandatory") | (b"year", b"sign:mandatory") => {
-                    modifiers.sign_is_mandatory = Some(true)
+                (b"offset", b"second:omitted") => modifiers.offset_second = Some(Presence::Omitted),
+                (b"quarter", b"repr:numerical") => {
+                    modifiers.quarter_repr = Some(QuarterRepr::Numerical)
                 }
+                (b"quarter", b"repr:short") => modifiers.quarter_repr = Some(QuarterRepr::Short),
                 (b"period", b"case:upper") => modifiers.period_is_uppercase = Some(true),
                 (b"period", b"case:lower") => modifiers.period_is_uppercase = Some(false),
                 (b"subsecond", b"digits:1") => {
@@ -375,6 +612,22 @@ impl Modifiers {
                 (b"subsecond", b"digits:1+") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::OneOrMore)
                 }
+                (b"subsecond", b"trim_trailing_zeros:true") => {
+                    modifiers.subsecond_trim_trailing_zeros = Some(true)
+                }
+                (b"subsecond", b"trim_trailing_zeros:false") => {
+                    modifiers.subsecond_trim_trailing_zeros = Some(false)
+                }
+                (b"subsecond", b"rounding:truncate") => {
+                    modifiers.subsecond_rounding = Some(SubsecondRounding::Truncate)
+                }
+                (b"subsecond", b"rounding:nearest_saturating") => {
+                    modifiers.subsecond_rounding = Some(SubsecondRounding::NearestSaturating)
+                }
+                (b"time_zone", b"repr:abbreviation") => {
+                    modifiers.time_zone_repr = Some(TimeZoneRepr::Abbreviation)
+                }
+                (b"time_zone", b"repr:name") => modifiers.time_zone_repr = Some(TimeZoneRepr::Name),
                 (b"weekday", b"repr:short") => modifiers.weekday_repr = Some(WeekdayRepr::Short),
                 (b"weekday", b"repr:long") => modifiers.weekday_repr = Some(WeekdayRepr::Long),
                 (b"weekday", b"repr:sunday") => modifiers.weekday_repr = Some(WeekdayRepr::Sunday),
@@ -392,10 +645,21 @@ impl Modifiers {
                 (b"week_number", b"repr:monday") => {
                     modifiers.week_number_repr = Some(WeekNumberRepr::Monday)
                 }
+                (b"ignore", _)
+                    if modifier.starts_with(b"count:") && parse_count(&modifier[6..]).is_some() =>
+                {
+                    modifiers.ignore_count = parse_count(&modifier[6..]);
+                }
                 (b"year", b"repr:full") => modifiers.year_repr = Some(YearRepr::Full),
                 (b"year", b"repr:last_two") => modifiers.year_repr = Some(YearRepr::LastTwo),
+                (b"year", b"repr:of_era") => modifiers.year_repr = Some(YearRepr::OfEra),
                 (b"year", b"base:calendar") => modifiers.year_is_iso_week_based = Some(false),
                 (b"year", b"base:iso_week") => modifiers.year_is_iso_week_based = Some(true),
+                (b"century", _) | (b"offset_hour", _) | (b"year", _)
+                    if modifier.starts_with(b"width:") && parse_width(&modifier[6..]).is_some() =>
+                {
+                    modifiers.width = parse_width(&modifier[6..]);
+                }
                 _ => {
                     return Err(InvalidFormatDescription::InvalidModifier {
                         value: String::from_utf8_lossy(modifier).into_owned(),
@@ -408,3 +672,17 @@ impl Modifiers {
         Ok(modifiers)
     }
 }
+
+fn parse_count(bytes: &[u8]) -> Option<u16> {
+    match std::str::from_utf8(bytes).ok()?.parse() {
+        Ok(0) | Err(_) => None,
+        Ok(count) => Some(count),
+    }
+}
+
+fn parse_width(bytes: &[u8]) -> Option<u8> {
+    match bytes {
+        &[digit @ b'1'..=b'9'] => Some(digit - b'0'),
+        _ => None,
+    }
+}
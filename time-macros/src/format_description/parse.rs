@@ -2,7 +2,7 @@ use proc_macro::Span;
 
 use crate::format_description::component::{Component, NakedComponent};
 use crate::format_description::error::InvalidFormatDescription;
-use crate::format_description::{helper, modifier, FormatItem};
+use crate::format_description::{helper, modifier, FormatItem, Version};
 use crate::Error;
 
 struct ParsedItem<'a> {
@@ -43,12 +43,12 @@ fn parse_item<'a>(
     if let [b'[', b'[', remaining @ ..] = s {
         *index += 2;
         return Ok(ParsedItem {
-            item: FormatItem::Literal(&[b'[']),
+            item: FormatItem::Literal(b"["),
             remaining,
         });
-    };
+    }
 
-    if s.starts_with(&[b'[']) {
+    if s.starts_with(b"[") {
         if let Some(bracket_index) = s.iter().position(|&c| c == b']') {
             *index += 1; // opening bracket
             let ret_val = ParsedItem {
@@ -65,17 +65,205 @@ fn parse_item<'a>(
     }
 }
 
-pub(crate) fn parse(mut s: &[u8], span: Span) -> Result<Vec<FormatItem<'_>>, Error> {
+#[allow(clippy::unnested_or_patterns)]
+fn parse_nested_items<'a>(
+    mut s: &'a [u8],
+    index: &mut usize,
+    is_nested: bool,
+    literals_only: bool,
+) -> Result<(Vec<FormatItem<'a>>, &'a [u8]), InvalidFormatDescription> {
+    let mut items = Vec::new();
+
+    loop {
+        match s {
+            [] => return Ok((items, s)),
+            [b']', ..] if is_nested => return Ok((items, s)),
+            [b']', ..] => {
+                return Err(InvalidFormatDescription::UnexpectedClosingBracket { index: *index });
+            }
+            [b'\\', escaped @ b'\\', ..]
+            | [b'\\', escaped @ b'[', ..]
+            | [b'\\', escaped @ b']', ..] => {
+                items.push(FormatItem::Literal(std::slice::from_ref(escaped)));
+                s = &s[2..];
+                *index += 2;
+            }
+            [b'\\', ..] => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "valid escape sequence",
+                    index: *index,
+                });
+            }
+            [b'[', ..] if literals_only => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "literal",
+                    index: *index,
+                });
+            }
+            [b'[', ..] => {
+                let (item, remaining) = parse_bracketed_item(s, index)?;
+                items.push(item);
+                s = remaining;
+            }
+            _ => {
+                let loc = s
+                    .iter()
+                    .position(|&c| matches!(c, b'[' | b']' | b'\\'))
+                    .unwrap_or(s.len());
+                items.push(FormatItem::Literal(&s[..loc]));
+                s = &s[loc..];
+                *index += loc;
+            }
+        }
+    }
+}
+
+fn parse_alternatives<'a>(
+    mut s: &'a [u8],
+    index: &mut usize,
+    opening_bracket_index: usize,
+    allow_multiple: bool,
+    literals_only: bool,
+) -> Result<(Vec<Vec<FormatItem<'a>>>, &'a [u8]), InvalidFormatDescription> {
+    let mut alternatives = Vec::new();
+
+    loop {
+        s = helper::consume_whitespace(s, index);
+        match s {
+            [] => {
+                return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: opening_bracket_index,
+                });
+            }
+            [b']', remaining @ ..] if !alternatives.is_empty() => {
+                *index += 1;
+                return Ok((alternatives, remaining));
+            }
+            [b'[', ..] if !allow_multiple && !alternatives.is_empty() => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "closing bracket",
+                    index: *index,
+                });
+            }
+            [b'[', remaining @ ..] => {
+                let nested_index = *index;
+                *index += 1;
+                let (items, remaining) = parse_nested_items(remaining, index, true, literals_only)?;
+                match remaining {
+                    [b']', remaining @ ..] => {
+                        *index += 1;
+                        s = remaining;
+                    }
+                    _ => {
+                        return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                            index: nested_index,
+                        });
+                    }
+                }
+                alternatives.push(items);
+            }
+            _ => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "opening bracket",
+                    index: *index,
+                });
+            }
+        }
+    }
+}
+
+fn parse_bracketed_item<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+) -> Result<(FormatItem<'a>, &'a [u8]), InvalidFormatDescription> {
+    let opening_bracket_index = *index;
+    let mut name_index = *index + 1;
+    let after_whitespace = helper::consume_whitespace(&s[1..], &mut name_index);
+    let name_len = after_whitespace
+        .iter()
+        .position(|&c| c.is_ascii_whitespace() || c == b'[' || c == b']')
+        .unwrap_or(after_whitespace.len());
+
+    match &after_whitespace[..name_len] {
+        b"optional" => {
+            *index = name_index + name_len;
+            let (mut alternatives, remaining) = parse_alternatives(
+                &after_whitespace[name_len..],
+                index,
+                opening_bracket_index,
+                false,
+                false,
+            )?;
+            Ok((FormatItem::Optional(alternatives.remove(0)), remaining))
+        }
+        b"first" => {
+            *index = name_index + name_len;
+            let (alternatives, remaining) = parse_alternatives(
+                &after_whitespace[name_len..],
+                index,
+                opening_bracket_index,
+                true,
+                false,
+            )?;
+            Ok((FormatItem::First(alternatives), remaining))
+        }
+        b"case_insensitive" => {
+            *index = name_index + name_len;
+            let (mut alternatives, remaining) = parse_alternatives(
+                &after_whitespace[name_len..],
+                index,
+                opening_bracket_index,
+                false,
+                true,
+            )?;
+            let bytes = alternatives
+                .remove(0)
+                .into_iter()
+                .filter_map(|item| match item {
+                    FormatItem::Literal(literal) => Some(literal),
+                    _ => None,
+                })
+                .flatten()
+                .copied()
+                .collect();
+            Ok((FormatItem::CaseInsensitiveLiteral(bytes), remaining))
+        }
+        _ => {
+            let bracket_index = s.iter().position(|&c| c == b']').ok_or(
+                InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: opening_bracket_index,
+                },
+            )?;
+            *index += 1; // opening bracket
+            let component = parse_component(&s[1..bracket_index], index)?;
+            *index = opening_bracket_index + bracket_index + 1;
+            Ok((FormatItem::Component(component), &s[bracket_index + 1..]))
+        }
+    }
+}
+
+pub(crate) fn parse(
+    version: Version,
+    mut s: &[u8],
+    span: Span,
+) -> Result<Vec<FormatItem<'_>>, Error> {
+    let to_error = |error| Error::InvalidFormatDescription {
+        error,
+        span_start: Some(span),
+        span_end: Some(span),
+    };
+
+    if matches!(version, Version::V2) {
+        return parse_nested_items(s, &mut 0, false, false)
+            .map(|(items, _)| items)
+            .map_err(to_error);
+    }
+
     let mut compound = Vec::new();
     let mut loc = 0;
 
     while !s.is_empty() {
-        let ParsedItem { item, remaining } =
-            parse_item(s, &mut loc).map_err(|error| Error::InvalidFormatDescription {
-                error,
-                span_start: Some(span),
-                span_end: Some(span),
-            })?;
+        let ParsedItem { item, remaining } = parse_item(s, &mut loc).map_err(to_error)?;
         s = remaining;
         compound.push(item);
     }
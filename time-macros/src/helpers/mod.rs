@@ -5,6 +5,7 @@ use std::str::FromStr;
 
 use proc_macro::{token_stream, Span, TokenStream, TokenTree};
 
+use crate::format_description::{TargetType, Version};
 use crate::Error;
 
 pub(crate) fn get_string_literal(tokens: TokenStream) -> Result<(Span, Vec<u8>), Error> {
@@ -24,6 +25,54 @@ pub(crate) fn get_string_literal(tokens: TokenStream) -> Result<(Span, Vec<u8>),
     }
 }
 
+pub(crate) fn consume_format_description_version(
+    tokens: &mut Peekable<token_stream::IntoIter>,
+) -> Result<Version, Error> {
+    match tokens.peek() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "version" => drop(tokens.next()),
+        _ => return Ok(Version::V1),
+    }
+    consume_punct('=', tokens)?;
+    let (span, version) = consume_number::<u8>("version", tokens)?;
+    consume_punct(',', tokens)?;
+
+    match version {
+        1 => Ok(Version::V1),
+        2 => Ok(Version::V2),
+        _ => Err(Error::InvalidComponent {
+            name: "version",
+            value: version.to_string(),
+            span_start: Some(span),
+            span_end: Some(span),
+        }),
+    }
+}
+
+pub(crate) fn consume_format_description_target(
+    tokens: &mut Peekable<token_stream::IntoIter>,
+) -> Result<Option<TargetType>, Error> {
+    match tokens.peek() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "target" => drop(tokens.next()),
+        _ => return Ok(None),
+    }
+    consume_punct('=', tokens)?;
+    let (span, name) = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => (ident.span(), ident.to_string()),
+        Some(tree) => return Err(Error::UnexpectedToken { tree }),
+        None => return Err(Error::UnexpectedEndOfInput),
+    };
+    consume_punct(',', tokens)?;
+
+    TargetType::from_name(&name)
+        .map(Some)
+        .ok_or_else(|| Error::InvalidComponent {
+            name: "target",
+            value: name,
+            span_start: Some(span),
+            span_end: Some(span),
+        })
+}
+
 pub(crate) fn consume_number<T: FromStr>(
     component_name: &'static str,
     chars: &mut Peekable<token_stream::IntoIter>,
@@ -86,16 +86,32 @@ impl_macros! {
 // features land.
 #[proc_macro]
 pub fn format_description(input: TokenStream) -> TokenStream {
-    let (span, string) = match helpers::get_string_literal(input) {
+    let mut tokens = input.into_iter().peekable();
+    let version = match helpers::consume_format_description_version(&mut tokens) {
+        Ok(version) => version,
+        Err(err) => return err.to_compile_error(),
+    };
+    let target = match helpers::consume_format_description_target(&mut tokens) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let (span, string) = match helpers::get_string_literal(tokens.collect()) {
         Ok(val) => val,
         Err(err) => return err.to_compile_error(),
     };
 
-    let items = match format_description::parse(&string, span) {
+    let items = match format_description::parse(version, &string, span) {
         Ok(items) => items,
         Err(err) => return err.to_compile_error(),
     };
 
+    if let Some(target) = target {
+        if let Err(err) = format_description::validate(&items, target, span) {
+            return err.to_compile_error();
+        }
+    }
+
     quote! {{
         const DESCRIPTION: &[::time::format_description::FormatItem<'_>] = &[#(
             items
@@ -10,6 +10,15 @@ impl ToTokens for bool {
     }
 }
 
+impl<T: ToTokens> ToTokens for Option<T> {
+    fn into_token_stream(self) -> TokenStream {
+        self.map_or_else(
+            || quote!(::core::option::Option::None),
+            |value| quote!(::core::option::Option::Some(#(value))),
+        )
+    }
+}
+
 impl ToTokens for TokenStream {
     fn into_token_stream(self) -> TokenStream {
         self